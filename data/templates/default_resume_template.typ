@@ -1,3 +1,6 @@
+// @name: Classic
+// @description: Single column, ATS-friendly layout
+
 // 1. DATA & CONFIG
 #import sys: inputs
 
@@ -56,6 +59,23 @@
       tech_stack: ("Rust", "Libp2p", "Tokio", "Protobuf"),
       url: "github.com/ariver/rust-p2p"
     )
+  ),
+  skills: (
+    (
+      category: "Languages",
+      skills: (
+        (name: "Go", proficiency: "Advanced", years: 5),
+        (name: "Rust", proficiency: "Intermediate", years: 2),
+        (name: "TypeScript", proficiency: none, years: none),
+      )
+    ),
+    (
+      category: "Infrastructure",
+      skills: (
+        (name: "Kubernetes", proficiency: none, years: 3),
+        (name: "PostgreSQL", proficiency: none, years: none),
+      )
+    ),
   )
 )
 
@@ -78,6 +98,10 @@
 
 // 2. COMPONENTS
 
+// Links may be written with or without a scheme; https:// is assumed when missing
+#let href(url) = if url.contains("://") or url.starts-with("mailto:") { url } else { "https://" + url }
+#let display_url(url) = url.replace(regex("^[a-z]+://"), "")
+
 #let header_component(profile) = {
   align(center)[
     #text(size: 16pt, weight: "bold")[#profile.name]
@@ -86,8 +110,8 @@
       #let contact_items = (
         if profile.phone != "" { profile.phone },
         if profile.email != "" { link("mailto:" + profile.email)[#profile.email] },
-        if profile.url != "" { link("https://" + profile.url)[Linked In] },
-        if profile.website != "" { link("https://" + profile.website)[#profile.website] },
+        if profile.url != "" { link(href(profile.url))[Linked In] },
+        if profile.website != "" { link(href(profile.website))[#display_url(profile.website)] },
       ).filter(it => it != none)
       #contact_items.join(" | ") \
       #profile.citizenship #sym.bullet #profile.location
@@ -132,13 +156,45 @@
   v(6pt)
 }
 
+#let dated_item(title, subtitle, date, url: none) = {
+  grid(
+    columns: (1fr, auto),
+    column-gutter: 2em,
+    [
+      #strong(title) #if subtitle != "" [| #text(style: "italic")[#subtitle]]
+      #if url != none [ | #link(href(url))[Credential] ]
+    ],
+    text(style: "italic")[#date]
+  )
+  v(4pt)
+}
+
+#let skill_label(skill) = {
+  let details = ()
+  if skill.at("proficiency", default: none) != none { details.push(skill.proficiency) }
+  if skill.at("years", default: none) != none { details.push(str(skill.years) + if skill.years == 1 { " yr" } else { " yrs" }) }
+  if details.len() > 0 [#skill.name (#details.join(", "))] else [#skill.name]
+}
+
+#let skills_component(categories) = {
+  grid(
+    columns: (auto, 1fr),
+    column-gutter: 1em,
+    row-gutter: 0.5em,
+    ..categories.map(cat => (
+      strong(cat.category),
+      cat.skills.map(skill_label).join(", "),
+    )).flatten()
+  )
+}
+
 #let work_item(role, company, location, date, summary, highlights, url: none) = {
   grid(
     columns: (1fr, auto),
     column-gutter: 2em, // Ensures text doesn't hit the date
     [
       #strong(role) #if company != "" [| #text(style: "italic")[#company, #location]]
-      #if url != none [ | #link("https://" + url)[#url] ]
+      #if url != none [ | #link(href(url))[#display_url(url)] ]
     ],
     text(style: "italic")[#date]
   )
@@ -168,7 +224,8 @@
 
 #section_title("Education")
 #for edu in resume_data.education [
-  #edu_item(edu.degree, edu.school, edu.status)
+  // `date` is the formatted start/end (or free text); show it beside the status
+  #edu_item(edu.degree, edu.school, (edu.status, edu.at("date", default: "")).filter(s => s != "").join(", "))
 ]
 
 #section_title("Work Experience")
@@ -196,4 +253,96 @@
       url: proj.at("url", default: none)
     )
   ]
-]
\ No newline at end of file
+]
+
+#if resume_data.at("skills", default: ()) != () [
+  #section_title("Skills")
+  #skills_component(resume_data.skills)
+]
+
+#let certifications = resume_data.at("certifications", default: ())
+#if certifications != () [
+  #section_title("Certifications")
+  #for cert in certifications [
+    #dated_item(cert.name, cert.issuer, cert.date, url: cert.at("credential_url", default: none))
+  ]
+]
+
+#let awards = resume_data.at("awards", default: ())
+#if awards != () [
+  #section_title("Awards")
+  #for award in awards [
+    #dated_item(award.title, award.issuer, award.date)
+    #if award.description != "" [#award.description #v(4pt)]
+  ]
+]
+
+#let publications = resume_data.at("publications", default: ())
+#if publications != () [
+  #section_title("Publications")
+  #for pub in publications [
+    #dated_item(pub.title, pub.venue, pub.date)
+    #if pub.authors != () [#pub.authors.join(", ")]
+    #if pub.at("doi", default: none) != none [ | DOI: #link("https://doi.org/" + pub.doi)[#pub.doi]]
+    #v(4pt)
+  ]
+]
+
+#let languages = resume_data.at("languages", default: ())
+#if languages != () [
+  #section_title("Languages")
+  #languages.map(l => [#strong(l.language) (#l.proficiency)]).join([ #sym.bullet ])
+]
+
+#let volunteering = resume_data.at("volunteering", default: ())
+#if volunteering != () [
+  #section_title("Volunteering")
+  #for vol in volunteering [
+    #work_item(
+      vol.role,
+      vol.organization,
+      vol.location,
+      vol.date,
+      vol.summary,
+      vol.bullets
+    )
+  ]
+]
+
+// User-defined sections from data/sections/. Each entry is a dict of free-form
+// string fields plus `id` and `bullets`; "title"/"name", "date", "url" and
+// "description" get special placement, everything else joins the subtitle.
+#let custom_entry(entry) = {
+  let reserved = ("id", "bullets", "title", "name", "date", "url", "description")
+  let heading = entry.at("title", default: entry.at("name", default: ""))
+  let details = entry.pairs().filter(p => p.at(0) not in reserved and p.at(1) != "").map(p => p.at(1))
+  let url = entry.at("url", default: "")
+  grid(
+    columns: (1fr, auto),
+    column-gutter: 2em,
+    [
+      #strong(heading) #if details != () [| #text(style: "italic")[#details.join(", ")]]
+      #if url != "" [ | #link(href(url))[Link] ]
+    ],
+    text(style: "italic")[#entry.at("date", default: "")]
+  )
+  let description = entry.at("description", default: "")
+  if description != "" {
+    v(2pt)
+    description
+  }
+  if entry.bullets != () {
+    set list(indent: 1.2em, body-indent: 0.5em, spacing: 0.4em)
+    for point in entry.bullets {
+      list.item[#point]
+    }
+  }
+  v(4pt)
+}
+
+#for section in resume_data.at("custom_sections", default: ()) [
+  #section_title(section.title)
+  #for entry in section.entries [
+    #custom_entry(entry)
+  ]
+]
@@ -1,58 +1,442 @@
-use crate::models::ResumeData;
-use crate::pdf::generate_pdf;
+use crate::data_dir::DataDir;
+use crate::models::diagnostics::{Diagnostic, summary};
+use crate::models::preset::Preset;
+use crate::models::{ResumeData, Section};
+use crate::page_budget::{Cut, apply_cuts, suggest_cuts};
+use crate::pdf::{CompileError, OutputFormat, compile, export};
+use crate::preview::Preview;
+use crate::templates::{self, Template};
 use crossterm::event::KeyCode;
 use ratatui::widgets::ListState;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use typst::foundations::Dict;
 
 // 1. STATE MANAGEMENT
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub enum CurrentScreen {
+    #[default]
     Welcome,
+    Diagnostics,
+    PresetSelection,
     ProfileView,
     JobTitleSelection,
     EducationSelection,
     ExperienceSelection,
     ExperienceBulletSelection,
     ProjectsSelection,
+    SkillsSelection,
+    SkillItemSelection,
+    SectionSelection(Section),
+    SavePreset,
+    TemplateSelection,
+    PageBudget,
     Generating,
     Success(String), // Contains the output path
     Error(String),
     Exiting,
 }
 
-impl Default for CurrentScreen {
-    fn default() -> Self {
-        CurrentScreen::Welcome
-    }
-}
-
 // The main application state
 #[derive(Debug, Default)]
 pub struct App {
     pub current_screen: CurrentScreen,
     pub data: ResumeData,
+    pub data_dir: DataDir,
     // UI STATE for scrolling through lists
     pub education_list_state: ListState,
     pub experience_list_state: ListState,
     pub experience_bullet_list_state: ListState, // New state for bullet selection
     pub projects_list_state: ListState,
     pub job_title_list_state: ListState,
+    pub skills_list_state: ListState,
+    pub skill_item_list_state: ListState,
+    // Shared by all SectionSelection screens; reset when entering one
+    pub section_list_state: ListState,
+    pub preset_list_state: ListState,
+    pub template_list_state: ListState,
+    // Typst templates found in data/templates/
+    pub templates: Vec<Template>,
+    // Saved presets available in data/presets/
+    pub presets: Vec<String>,
+    // Text typed on the SavePreset screen, and where to go back to afterwards
+    pub preset_name_input: String,
+    pub preset_return_screen: CurrentScreen,
+    // Name the user was warned already exists; saving it again replaces it
+    pub preset_replace: Option<String>,
+    // One-off message shown after loading/saving a preset
+    pub notice: Option<String>,
+    // Problems found while loading, shown on the Diagnostics screen
+    pub diagnostics: Vec<Diagnostic>,
+    pub diagnostics_scroll: u16,
+    // Typst errors and warnings from the last PDF build, shown on the
+    // Success/Error screen
+    pub output_diagnostics: Vec<Diagnostic>,
+    pub output_scroll: u16,
+    // Other formats written from the Success screen, or why they failed
+    pub exports: Vec<Result<String, String>>,
+    // Live preview pane on the selection screens, toggled with 'v'
+    pub preview_enabled: bool,
+    // Last compile: the text preview, or why it failed. Also the source of
+    // the page count in the status bar.
+    pub preview: Option<Result<Preview, String>>,
+    pub preview_scroll: u16,
+    // Inputs of the last compile, to skip recompiling when nothing changed
+    pub preview_inputs: Option<(Dict, Option<String>)>,
+    // Compile running in the background. Only one runs at a time; changes
+    // made meanwhile set `preview_queued` and are compiled once it finishes.
+    pub pending_compile: Option<Receiver<Compiled>>,
+    pub preview_queued: bool,
+    // Suggested cuts shown on the PageBudget screen (None while they are
+    // worked out by the next compile), and where to go back to
+    pub cuts: Option<Result<Vec<Cut>, String>>,
+    pub cuts_requested: bool,
+    pub cuts_return_screen: CurrentScreen,
+}
+
+// A background compile: the preview, and the suggested cuts when the
+// PageBudget screen asked for them
+#[derive(Debug)]
+pub struct Compiled {
+    pub preview: Result<Preview, String>,
+    pub cuts: Option<Result<Vec<Cut>, String>>,
 }
 
 impl App {
-    pub fn new() -> Self {
-        let data = ResumeData::load_from_dir().unwrap_or_else(|e| {
-            eprintln!("Failed to load data: {}", e);
-            ResumeData::default()
-        });
+    pub fn new(data_dir: DataDir) -> Self {
+        let (data, diagnostics) = ResumeData::load(&data_dir);
+
+        let presets = Preset::list(&data_dir);
+        let templates = templates::discover(&data_dir);
 
         Self {
-            current_screen: CurrentScreen::Welcome,
+            // Start on the diagnostics panel when there is something to report
+            current_screen: if diagnostics.is_empty() {
+                CurrentScreen::Welcome
+            } else {
+                CurrentScreen::Diagnostics
+            },
             data,
+            data_dir,
             education_list_state: ListState::default(),
             experience_list_state: ListState::default(),
             experience_bullet_list_state: ListState::default(),
             projects_list_state: ListState::default(),
             job_title_list_state: ListState::default(),
+            skills_list_state: ListState::default(),
+            skill_item_list_state: ListState::default(),
+            section_list_state: ListState::default(),
+            preset_list_state: ListState::default(),
+            template_list_state: ListState::default(),
+            templates,
+            presets,
+            preset_name_input: String::new(),
+            preset_return_screen: CurrentScreen::ProjectsSelection,
+            preset_replace: None,
+            notice: None,
+            diagnostics,
+            diagnostics_scroll: 0,
+            output_diagnostics: Vec::new(),
+            output_scroll: 0,
+            exports: Vec::new(),
+            preview_enabled: false,
+            preview: None,
+            preview_scroll: 0,
+            preview_inputs: None,
+            pending_compile: None,
+            preview_queued: false,
+            cuts: None,
+            cuts_requested: false,
+            cuts_return_screen: CurrentScreen::ProfileView,
+        }
+    }
+
+    // Leave the Welcome screen, pre-selecting the current job title if any
+    pub fn start_builder(&mut self) {
+        // Skip if no job titles exist
+        if self.data.job_titles.is_empty() {
+            self.current_screen = CurrentScreen::ProfileView;
+        } else {
+            let selected = self
+                .data
+                .job_title
+                .as_ref()
+                .and_then(|title| {
+                    self.data
+                        .job_titles
+                        .iter()
+                        .position(|jt| &jt.title == title)
+                })
+                .unwrap_or(0);
+            self.current_screen = CurrentScreen::JobTitleSelection;
+            self.job_title_list_state.select(Some(selected));
+        }
+    }
+
+    // Navigation helpers for Presets
+    pub fn next_preset(&mut self) {
+        let len = self.presets.len();
+        if len == 0 {
+            return;
+        }
+
+        let i = match self.preset_list_state.selected() {
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0,
+        };
+
+        self.preset_list_state.select(Some(i));
+    }
+
+    pub fn previous_preset(&mut self) {
+        let len = self.presets.len();
+        if len == 0 {
+            return;
+        }
+
+        let i = match self.preset_list_state.selected() {
+            Some(0) | None => len - 1,
+            Some(i) => i - 1,
+        };
+
+        self.preset_list_state.select(Some(i));
+    }
+
+    pub fn load_selected_preset(&mut self) {
+        let Some(name) = self
+            .preset_list_state
+            .selected()
+            .and_then(|i| self.presets.get(i))
+        else {
+            return;
+        };
+
+        match Preset::load(&self.data_dir, name) {
+            Ok(preset) => {
+                let warnings = preset.apply(&mut self.data);
+                self.notice = Some(if warnings.is_empty() {
+                    format!("Loaded preset '{}'", name)
+                } else {
+                    format!("Loaded preset '{}': {}", name, warnings.join("; "))
+                });
+                self.start_builder();
+            }
+            Err(e) => self.notice = Some(format!("{}", e)),
+        }
+    }
+
+    pub fn save_preset(&mut self) {
+        let name = self.preset_name_input.trim().to_string();
+        // A second <Enter> on the same name replaces the existing preset
+        let replace = self.preset_replace.as_deref() == Some(name.as_str());
+        if !replace && Preset::exists(&self.data_dir, &name) {
+            self.notice = Some(format!(
+                "Preset '{}' already exists; press Enter again to replace it",
+                name
+            ));
+            self.preset_replace = Some(name);
+            return;
+        }
+        match Preset::capture(&self.data).save(&self.data_dir, &name, replace) {
+            Ok(path) => {
+                self.notice = Some(format!("Saved preset to {}", path.display()));
+                self.presets = Preset::list(&self.data_dir);
+                self.current_screen = self.preset_return_screen.clone();
+            }
+            Err(e) => self.notice = Some(format!("{}", e)),
+        }
+    }
+
+    pub fn open_save_preset(&mut self) {
+        self.preset_name_input.clear();
+        self.preset_replace = None;
+        self.notice = None;
+        self.preset_return_screen = self.current_screen.clone();
+        self.current_screen = CurrentScreen::SavePreset;
+    }
+
+    // Projects plus the optional screens after it, skipping sections without data
+    fn selection_steps(&self) -> Vec<CurrentScreen> {
+        let mut steps = vec![CurrentScreen::ProjectsSelection];
+        if !self.data.skills.is_empty() {
+            steps.push(CurrentScreen::SkillsSelection);
+        }
+        for section in self.data.sections() {
+            if !self.data.section_entries(section).is_empty() {
+                steps.push(CurrentScreen::SectionSelection(section));
+            }
+        }
+        steps
+    }
+
+    /// True when <Enter> on the current screen generates the PDF
+    pub fn is_last_step(&self) -> bool {
+        self.selection_steps().last() == Some(&self.current_screen)
+    }
+
+    // Enter the next selection screen, or generate from the last one
+    pub fn advance(&mut self) {
+        let steps = self.selection_steps();
+        let next = steps
+            .iter()
+            .position(|s| s == &self.current_screen)
+            .and_then(|i| steps.get(i + 1));
+
+        match next {
+            Some(CurrentScreen::SkillsSelection) => {
+                self.current_screen = CurrentScreen::SkillsSelection;
+                self.skills_list_state.select(Some(0));
+            }
+            Some(screen) => {
+                self.current_screen = screen.clone();
+                self.section_list_state.select(Some(0));
+            }
+            None => self.choose_template(),
+        }
+    }
+
+    // Show the picker when there is a choice to make, otherwise generate.
+    // The previous pick stays selected for the rest of the run.
+    pub fn choose_template(&mut self) {
+        if self.templates.len() < 2 {
+            if let [only] = self.templates.as_slice() {
+                self.data.template = Some(only.file.clone());
+            }
+            self.generate();
+            return;
+        }
+        let selected = self
+            .data
+            .template
+            .as_ref()
+            .and_then(|file| self.templates.iter().position(|t| &t.file == file))
+            .unwrap_or(0);
+        self.template_list_state.select(Some(selected));
+        self.current_screen = CurrentScreen::TemplateSelection;
+    }
+
+    pub fn next_template(&mut self) {
+        let len = self.templates.len();
+        if len == 0 {
+            return;
+        }
+
+        let i = match self.template_list_state.selected() {
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0,
+        };
+
+        self.template_list_state.select(Some(i));
+    }
+
+    pub fn previous_template(&mut self) {
+        let len = self.templates.len();
+        if len == 0 {
+            return;
+        }
+
+        let i = match self.template_list_state.selected() {
+            Some(0) | None => len - 1,
+            Some(i) => i - 1,
+        };
+
+        self.template_list_state.select(Some(i));
+    }
+
+    pub fn generate_with_selected_template(&mut self) {
+        if let Some(template) = self
+            .template_list_state
+            .selected()
+            .and_then(|i| self.templates.get(i))
+        {
+            self.data.template = Some(template.file.clone());
+        }
+        self.generate();
+    }
+
+    fn choose_template_or_return(&mut self) {
+        if self.templates.len() < 2 {
+            self.return_to_last_step();
+        } else {
+            self.choose_template();
+        }
+    }
+
+    // Back from the picker (or a result) to the last selection screen
+    pub fn return_to_last_step(&mut self) {
+        if let Some(last) = self.selection_steps().pop() {
+            self.current_screen = last;
+        }
+    }
+
+    // Return to the previous selection screen
+    pub fn go_back(&mut self) {
+        let steps = self.selection_steps();
+        let previous = steps
+            .iter()
+            .position(|s| s == &self.current_screen)
+            .filter(|&i| i > 0)
+            .map(|i| steps[i - 1].clone());
+
+        match previous {
+            Some(CurrentScreen::SkillsSelection) => {
+                self.current_screen = CurrentScreen::SkillsSelection;
+            }
+            Some(screen) => {
+                self.current_screen = screen;
+                self.section_list_state.select(Some(0));
+            }
+            None => self.current_screen = CurrentScreen::ProjectsSelection,
+        }
+    }
+
+    // Reorder education and experience newest-first, starting again at the top
+    pub fn sort_by_date(&mut self) {
+        self.data.sort_by_date();
+        self.education_list_state.select(Some(0));
+        self.experience_list_state.select(Some(0));
+    }
+
+    // Write the same selection in another format after a successful build
+    pub fn export_as(&mut self, format: OutputFormat) {
+        match export(&self.data, &self.data_dir, &[format]) {
+            Ok(output) => {
+                self.exports.extend(output.paths.into_iter().map(Ok));
+                // Formats that compile again repeat the PDF's warnings
+                for warning in output.warnings {
+                    if !self.output_diagnostics.contains(&warning) {
+                        self.output_diagnostics.push(warning);
+                    }
+                }
+            }
+            Err(e) => self.exports.push(Err(format!("{}", e))),
+        }
+    }
+
+    // Runs the pipeline and moves to Success/Error
+    pub fn generate(&mut self) {
+        self.current_screen = CurrentScreen::Generating;
+        self.output_scroll = 0;
+        self.exports.clear();
+        match export(&self.data, &self.data_dir, &[OutputFormat::Pdf]) {
+            Ok(output) => {
+                self.output_diagnostics = output.warnings;
+                let path = output.paths.into_iter().next().unwrap_or_default();
+                self.current_screen = CurrentScreen::Success(path);
+            }
+            Err(e) => match e.downcast::<CompileError>() {
+                Ok(err) => {
+                    self.current_screen = CurrentScreen::Error(format!(
+                        "Template failed to compile ({})",
+                        summary(&err.diagnostics)
+                    ));
+                    self.output_diagnostics = err.diagnostics;
+                }
+                Err(e) => {
+                    self.output_diagnostics.clear();
+                    self.current_screen = CurrentScreen::Error(format!("{}", e));
+                }
+            },
         }
     }
 
@@ -92,10 +476,10 @@ impl App {
     }
 
     pub fn toggle_education(&mut self) {
-        if let Some(i) = self.education_list_state.selected() {
-            if let Some(item) = self.data.education.get_mut(i) {
-                item.is_visible = !item.is_visible;
-            }
+        if let Some(i) = self.education_list_state.selected()
+            && let Some(item) = self.data.education.get_mut(i)
+        {
+            item.is_visible = !item.is_visible;
         }
     }
 
@@ -129,8 +513,6 @@ impl App {
         self.job_title_list_state.select(Some(i));
     }
 
-
-
     // Navigation helpers for Experience
     pub fn next_experience(&mut self) {
         if self.data.experience.is_empty() {
@@ -167,67 +549,62 @@ impl App {
     }
 
     pub fn toggle_experience(&mut self) {
-        if let Some(i) = self.experience_list_state.selected() {
-            if let Some(item) = self.data.experience.get_mut(i) {
-                item.is_visible = !item.is_visible;
-            }
+        if let Some(i) = self.experience_list_state.selected()
+            && let Some(item) = self.data.experience.get_mut(i)
+        {
+            item.is_visible = !item.is_visible;
         }
     }
 
     // Navigation helpers for Experience Bullets
     pub fn next_experience_bullet(&mut self) {
-        if let Some(job_index) = self.experience_list_state.selected() {
-            if let Some(job) = self.data.experience.get(job_index) {
-                if job.bullets.is_empty() {
-                    return;
-                }
-                let i = match self.experience_bullet_list_state.selected() {
-                    Some(i) => {
-                        if i >= job.bullets.len() - 1 {
-                            0
-                        } else {
-                            i + 1
-                        }
-                    }
-                    None => 0,
-                };
-                self.experience_bullet_list_state.select(Some(i));
+        if let Some(job_index) = self.experience_list_state.selected()
+            && let Some(job) = self.data.experience.get(job_index)
+        {
+            if job.bullets.is_empty() {
+                return;
             }
+            let i = match self.experience_bullet_list_state.selected() {
+                Some(i) => {
+                    if i >= job.bullets.len() - 1 {
+                        0
+                    } else {
+                        i + 1
+                    }
+                }
+                None => 0,
+            };
+            self.experience_bullet_list_state.select(Some(i));
         }
     }
 
     pub fn previous_experience_bullet(&mut self) {
-        if let Some(job_index) = self.experience_list_state.selected() {
-            if let Some(job) = self.data.experience.get(job_index) {
-                if job.bullets.is_empty() {
-                    return;
-                }
-                let i = match self.experience_bullet_list_state.selected() {
-                    Some(i) => {
-                        if i == 0 {
-                            job.bullets.len() - 1
-                        } else {
-                            i - 1
-                        }
-                    }
-                    None => 0,
-                };
-                self.experience_bullet_list_state.select(Some(i));
+        if let Some(job_index) = self.experience_list_state.selected()
+            && let Some(job) = self.data.experience.get(job_index)
+        {
+            if job.bullets.is_empty() {
+                return;
             }
+            let i = match self.experience_bullet_list_state.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        job.bullets.len() - 1
+                    } else {
+                        i - 1
+                    }
+                }
+                None => 0,
+            };
+            self.experience_bullet_list_state.select(Some(i));
         }
     }
 
     pub fn toggle_experience_bullet(&mut self) {
-        if let Some(job_index) = self.experience_list_state.selected() {
-            if let Some(bullet_index) = self.experience_bullet_list_state.selected() {
-                if let Some(job) = self.data.experience.get_mut(job_index) {
-                    if job.hidden_bullets.contains(&bullet_index) {
-                        job.hidden_bullets.retain(|&x| x != bullet_index);
-                    } else {
-                        job.hidden_bullets.push(bullet_index);
-                    }
-                }
-            }
+        if let Some(job_index) = self.experience_list_state.selected()
+            && let Some(bullet_index) = self.experience_bullet_list_state.selected()
+            && let Some(job) = self.data.experience.get_mut(job_index)
+        {
+            job.toggle_bullet(bullet_index);
         }
     }
 
@@ -267,28 +644,357 @@ impl App {
     }
 
     pub fn toggle_project(&mut self) {
-        if let Some(i) = self.projects_list_state.selected() {
-            if let Some(item) = self.data.projects.get_mut(i) {
-                item.is_visible = !item.is_visible;
+        if let Some(i) = self.projects_list_state.selected()
+            && let Some(item) = self.data.projects.get_mut(i)
+        {
+            item.is_visible = !item.is_visible;
+        }
+    }
+
+    // Navigation helpers for Skill categories
+    pub fn next_skill_category(&mut self) {
+        if self.data.skills.is_empty() {
+            return;
+        }
+        let i = match self.skills_list_state.selected() {
+            Some(i) => {
+                if i >= self.data.skills.len() - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.skills_list_state.select(Some(i));
+    }
+
+    pub fn previous_skill_category(&mut self) {
+        if self.data.skills.is_empty() {
+            return;
+        }
+        let i = match self.skills_list_state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    self.data.skills.len() - 1
+                } else {
+                    i - 1
+                }
             }
+            None => 0,
+        };
+        self.skills_list_state.select(Some(i));
+    }
+
+    pub fn toggle_skill_category(&mut self) {
+        if let Some(i) = self.skills_list_state.selected()
+            && let Some(item) = self.data.skills.get_mut(i)
+        {
+            item.is_visible = !item.is_visible;
         }
     }
 
+    // Navigation helpers for Skills within the selected category
+    pub fn next_skill(&mut self) {
+        if let Some(category_index) = self.skills_list_state.selected()
+            && let Some(category) = self.data.skills.get(category_index)
+        {
+            if category.skills.is_empty() {
+                return;
+            }
+            let i = match self.skill_item_list_state.selected() {
+                Some(i) => {
+                    if i >= category.skills.len() - 1 {
+                        0
+                    } else {
+                        i + 1
+                    }
+                }
+                None => 0,
+            };
+            self.skill_item_list_state.select(Some(i));
+        }
+    }
+
+    pub fn previous_skill(&mut self) {
+        if let Some(category_index) = self.skills_list_state.selected()
+            && let Some(category) = self.data.skills.get(category_index)
+        {
+            if category.skills.is_empty() {
+                return;
+            }
+            let i = match self.skill_item_list_state.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        category.skills.len() - 1
+                    } else {
+                        i - 1
+                    }
+                }
+                None => 0,
+            };
+            self.skill_item_list_state.select(Some(i));
+        }
+    }
+
+    pub fn toggle_skill(&mut self) {
+        if let Some(category_index) = self.skills_list_state.selected()
+            && let Some(skill_index) = self.skill_item_list_state.selected()
+            && let Some(category) = self.data.skills.get_mut(category_index)
+        {
+            category.toggle_skill(skill_index);
+        }
+    }
+
+    // Navigation helpers for the optional sections
+    pub fn next_section_entry(&mut self, section: Section) {
+        let len = self.data.section_entries(section).len();
+        if len == 0 {
+            return;
+        }
+
+        let i = match self.section_list_state.selected() {
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0,
+        };
+
+        self.section_list_state.select(Some(i));
+    }
+
+    pub fn previous_section_entry(&mut self, section: Section) {
+        let len = self.data.section_entries(section).len();
+        if len == 0 {
+            return;
+        }
+
+        let i = match self.section_list_state.selected() {
+            Some(0) | None => len - 1,
+            Some(i) => i - 1,
+        };
+
+        self.section_list_state.select(Some(i));
+    }
+
+    pub fn toggle_section_entry(&mut self, section: Section) {
+        if let Some(i) = self.section_list_state.selected() {
+            self.data.toggle_section_entry(section, i);
+        }
+    }
+
+    /// Screens that can show the preview pane beside them
+    pub fn has_preview_pane(&self) -> bool {
+        matches!(
+            self.current_screen,
+            CurrentScreen::ProfileView
+                | CurrentScreen::JobTitleSelection
+                | CurrentScreen::EducationSelection
+                | CurrentScreen::ExperienceSelection
+                | CurrentScreen::ExperienceBulletSelection
+                | CurrentScreen::ProjectsSelection
+                | CurrentScreen::SkillsSelection
+                | CurrentScreen::SkillItemSelection
+                | CurrentScreen::SectionSelection(_)
+        )
+    }
+
+    pub fn toggle_preview(&mut self) {
+        self.preview_enabled = !self.preview_enabled;
+        self.refresh_preview();
+    }
+
+    // The preview pane and the page budget are the only users of the
+    // background compile
+    fn wants_compile(&self) -> bool {
+        self.preview_enabled || self.data.config.max_pages.is_some()
+    }
+
+    // Recompile in the background through the same pipeline as the PDF,
+    // if the selection changed since the last compile
+    pub fn refresh_preview(&mut self) {
+        if !self.wants_compile() {
+            return;
+        }
+        let inputs = (
+            Dict::from(self.data.to_filtered_data()),
+            self.data.template.clone(),
+        );
+        if self.preview_inputs.as_ref() == Some(&inputs) {
+            return;
+        }
+        self.preview_inputs = Some(inputs);
+
+        if self.pending_compile.is_some() {
+            self.preview_queued = true;
+        } else {
+            self.start_compile();
+        }
+    }
+
+    fn start_compile(&mut self) {
+        let (sender, receiver) = mpsc::channel();
+        let data = self.data.clone();
+        let data_dir = self.data_dir.clone();
+        let with_cuts = std::mem::take(&mut self.cuts_requested);
+        thread::spawn(move || {
+            let preview = compile(&data, &data_dir)
+                .map(|(document, _)| Preview::from_document(&document))
+                .map_err(|e| e.to_string());
+            // Trying cuts takes a compile per bullet, so it stays off the UI thread too
+            let max_pages = data.config.max_pages.filter(|_| with_cuts);
+            let cuts = max_pages.map(|max_pages| {
+                let pages = preview.as_ref().map_err(Clone::clone)?.pages.len();
+                suggest_cuts(&data, &data_dir, max_pages, pages).map_err(|e| e.to_string())
+            });
+            let _ = sender.send(Compiled { preview, cuts });
+        });
+        self.pending_compile = Some(receiver);
+    }
+
+    // Show a finished compile and start the one queued behind it, which
+    // picks up every change made while the last one ran
+    fn finish_compile(&mut self, result: Option<Compiled>) {
+        if let Some(result) = result {
+            self.preview = Some(result.preview);
+            if result.cuts.is_some() {
+                self.cuts = result.cuts;
+            }
+        }
+        self.pending_compile = None;
+        if std::mem::take(&mut self.preview_queued) {
+            self.start_compile();
+        }
+    }
+
+    /// Pick up the background compile if it has finished
+    pub fn poll_preview(&mut self) {
+        if let Some(receiver) = &self.pending_compile {
+            match receiver.try_recv() {
+                Ok(result) => self.finish_compile(Some(result)),
+                Err(TryRecvError::Empty) => {}
+                Err(TryRecvError::Disconnected) => self.finish_compile(None),
+            }
+        }
+    }
+
+    /// Block until the background compile, and any queued behind it, has
+    /// finished. The UI only ever polls; tests wait.
+    #[cfg(test)]
+    pub fn wait_for_preview(&mut self) {
+        while let Some(receiver) = &self.pending_compile {
+            let result = receiver.recv().ok();
+            self.finish_compile(result);
+        }
+    }
+
+    /// Pages of the last compile and whether that is over the budget
+    pub fn page_status(&self) -> Option<(usize, bool)> {
+        if !self.wants_compile() {
+            return None;
+        }
+        let pages = self.preview.as_ref()?.as_ref().ok()?.pages.len();
+        let over = self.data.config.max_pages.is_some_and(|max| pages > max);
+        Some((pages, over))
+    }
+
+    // Work out which bullets to hide to get back under the page budget. The
+    // next compile does it, after the one running (if any) so the page
+    // count it starts from is current; the screen shows "computing" until then.
+    pub fn open_page_budget(&mut self) {
+        if self.data.config.max_pages.is_none() {
+            return;
+        }
+        self.cuts = None;
+        self.cuts_requested = true;
+        if self.pending_compile.is_some() {
+            self.preview_queued = true;
+        } else {
+            self.start_compile();
+        }
+        self.cuts_return_screen = self.current_screen.clone();
+        self.current_screen = CurrentScreen::PageBudget;
+    }
+
+    pub fn apply_suggested_cuts(&mut self) {
+        // Still computing
+        if self.cuts.is_none() {
+            return;
+        }
+        if let Some(Ok(cuts)) = self.cuts.take() {
+            apply_cuts(&mut self.data, &cuts);
+        }
+        self.current_screen = self.cuts_return_screen.clone();
+    }
+
     pub fn handle_key_event(&mut self, key: KeyCode) {
+        if self.has_preview_pane() {
+            match key {
+                KeyCode::Char('v') => return self.toggle_preview(),
+                KeyCode::Char('c') if self.page_status().is_some_and(|(_, over)| over) => {
+                    return self.open_page_budget();
+                }
+                KeyCode::Char('J') if self.preview_enabled => {
+                    self.preview_scroll = self.preview_scroll.saturating_add(1);
+                    return;
+                }
+                KeyCode::Char('K') if self.preview_enabled => {
+                    self.preview_scroll = self.preview_scroll.saturating_sub(1);
+                    return;
+                }
+                _ => {}
+            }
+        }
+        self.handle_screen_key(key);
+        if self.has_preview_pane() {
+            self.refresh_preview();
+        }
+    }
+
+    fn handle_screen_key(&mut self, key: KeyCode) {
         match &self.current_screen {
             // ─────────────────────────────────────────────────────────────
             // Welcome → Job Title
             // ─────────────────────────────────────────────────────────────
             CurrentScreen::Welcome => match key {
                 KeyCode::Char('q') => self.current_screen = CurrentScreen::Exiting,
-                KeyCode::Enter => {
-                    // Skip if no job titles exist
-                    if self.data.job_titles.is_empty() {
-                        self.current_screen = CurrentScreen::ProfileView;
-                    } else {
-                        self.current_screen = CurrentScreen::JobTitleSelection;
-                        self.job_title_list_state.select(Some(0));
-                    }
+                KeyCode::Char('l') if !self.presets.is_empty() => {
+                    self.current_screen = CurrentScreen::PresetSelection;
+                    self.preset_list_state.select(Some(0));
+                }
+                KeyCode::Char('d') if !self.diagnostics.is_empty() => {
+                    self.current_screen = CurrentScreen::Diagnostics;
+                    self.diagnostics_scroll = 0;
+                }
+                KeyCode::Enter => self.start_builder(),
+                _ => {}
+            },
+
+            // ─────────────────────────────────────────────────────────────
+            // Diagnostics (problems found in the data files)
+            // ─────────────────────────────────────────────────────────────
+            CurrentScreen::Diagnostics => match key {
+                KeyCode::Char('q') => self.current_screen = CurrentScreen::Exiting,
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.diagnostics_scroll = self.diagnostics_scroll.saturating_add(1);
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.diagnostics_scroll = self.diagnostics_scroll.saturating_sub(1);
+                }
+                KeyCode::Enter | KeyCode::Esc | KeyCode::Backspace => {
+                    self.current_screen = CurrentScreen::Welcome;
+                }
+                _ => {}
+            },
+
+            // ─────────────────────────────────────────────────────────────
+            // Presets (load a saved selection)
+            // ─────────────────────────────────────────────────────────────
+            CurrentScreen::PresetSelection => match key {
+                KeyCode::Char('q') => self.current_screen = CurrentScreen::Exiting,
+                KeyCode::Char('j') | KeyCode::Down => self.next_preset(),
+                KeyCode::Char('k') | KeyCode::Up => self.previous_preset(),
+                KeyCode::Enter => self.load_selected_preset(),
+                KeyCode::Backspace | KeyCode::Esc => {
+                    self.current_screen = CurrentScreen::Welcome;
                 }
                 _ => {}
             },
@@ -303,8 +1009,7 @@ impl App {
                 KeyCode::Backspace => self.current_screen = CurrentScreen::Welcome,
                 KeyCode::Enter => {
                     if let Some(i) = self.job_title_list_state.selected() {
-                        self.data.job_title = Some(self.data.job_titles[i].title.clone());
-                        self.data.professional_summary = Some(self.data.job_titles[i].professional_summary.clone());
+                        self.data.select_job_title(i);
                         self.current_screen = CurrentScreen::ProfileView;
                     }
                 }
@@ -350,6 +1055,7 @@ impl App {
                 KeyCode::Char('k') => self.previous_education(),
                 KeyCode::Up => self.previous_education(),
                 KeyCode::Char(' ') => self.toggle_education(),
+                KeyCode::Char('o') => self.sort_by_date(),
                 KeyCode::Backspace => {
                     self.current_screen = CurrentScreen::ProfileView;
                 }
@@ -368,6 +1074,7 @@ impl App {
                 KeyCode::Char('j') | KeyCode::Down => self.next_experience(),
                 KeyCode::Char('k') | KeyCode::Up => self.previous_experience(),
                 KeyCode::Char(' ') => self.toggle_experience(),
+                KeyCode::Char('o') => self.sort_by_date(),
                 KeyCode::Char('e') | KeyCode::Right => {
                     self.current_screen = CurrentScreen::ExperienceBulletSelection;
                     self.experience_bullet_list_state.select(Some(0));
@@ -404,30 +1111,129 @@ impl App {
                 KeyCode::Char('j') | KeyCode::Down => self.next_project(),
                 KeyCode::Char('k') | KeyCode::Up => self.previous_project(),
                 KeyCode::Char(' ') => self.toggle_project(),
-                KeyCode::Enter => {
-                    self.current_screen = CurrentScreen::Generating;
-                    match generate_pdf(&self.data) {
-                        Ok(path) => {
-                            self.current_screen = CurrentScreen::Success(path);
-                        }
-                        Err(e) => {
-                            self.current_screen = CurrentScreen::Error(format!("{}", e));
-                        }
-                    }
-                }
+                KeyCode::Char('s') => self.open_save_preset(),
+                KeyCode::Enter => self.advance(),
                 KeyCode::Backspace => {
                     self.current_screen = CurrentScreen::ExperienceSelection;
                 }
                 _ => {}
             },
 
+            // ─────────────────────────────────────────────────────────────
+            // Skills
+            // ─────────────────────────────────────────────────────────────
+            CurrentScreen::SkillsSelection => match key {
+                KeyCode::Char('q') => self.current_screen = CurrentScreen::Exiting,
+                KeyCode::Char('j') | KeyCode::Down => self.next_skill_category(),
+                KeyCode::Char('k') | KeyCode::Up => self.previous_skill_category(),
+                KeyCode::Char(' ') => self.toggle_skill_category(),
+                KeyCode::Char('e') | KeyCode::Right => {
+                    self.current_screen = CurrentScreen::SkillItemSelection;
+                    self.skill_item_list_state.select(Some(0));
+                }
+                KeyCode::Char('s') => self.open_save_preset(),
+                KeyCode::Enter => self.advance(),
+                KeyCode::Backspace => self.go_back(),
+                _ => {}
+            },
+
+            // ─────────────────────────────────────────────────────────────
+            // Individual Skills
+            // ─────────────────────────────────────────────────────────────
+            CurrentScreen::SkillItemSelection => match key {
+                KeyCode::Char('q') => self.current_screen = CurrentScreen::Exiting,
+                KeyCode::Char('j') | KeyCode::Down => self.next_skill(),
+                KeyCode::Char('k') | KeyCode::Up => self.previous_skill(),
+                KeyCode::Char(' ') => self.toggle_skill(),
+                KeyCode::Enter | KeyCode::Esc | KeyCode::Left | KeyCode::Backspace => {
+                    self.current_screen = CurrentScreen::SkillsSelection;
+                }
+                _ => {}
+            },
+
+            // ─────────────────────────────────────────────────────────────
+            // Certifications / Awards / ... / custom sections
+            // ─────────────────────────────────────────────────────────────
+            CurrentScreen::SectionSelection(section) => {
+                let section = *section;
+                match key {
+                    KeyCode::Char('q') => self.current_screen = CurrentScreen::Exiting,
+                    KeyCode::Char('j') | KeyCode::Down => self.next_section_entry(section),
+                    KeyCode::Char('k') | KeyCode::Up => self.previous_section_entry(section),
+                    KeyCode::Char(' ') => self.toggle_section_entry(section),
+                    KeyCode::Char('s') => self.open_save_preset(),
+                    KeyCode::Enter => self.advance(),
+                    KeyCode::Backspace => self.go_back(),
+                    _ => {}
+                }
+            }
+
+            // ─────────────────────────────────────────────────────────────
+            // Save Preset (text input, so 'q' is a character here)
+            // ─────────────────────────────────────────────────────────────
+            CurrentScreen::SavePreset => match key {
+                KeyCode::Char(c) => self.preset_name_input.push(c),
+                KeyCode::Backspace => {
+                    self.preset_name_input.pop();
+                }
+                KeyCode::Enter => self.save_preset(),
+                KeyCode::Esc => self.current_screen = self.preset_return_screen.clone(),
+                _ => {}
+            },
+
+            // ─────────────────────────────────────────────────────────────
+            // Page budget (suggested cuts, opened with 'c' from the preview)
+            // ─────────────────────────────────────────────────────────────
+            CurrentScreen::PageBudget => match key {
+                KeyCode::Char('q') => self.current_screen = CurrentScreen::Exiting,
+                KeyCode::Enter => self.apply_suggested_cuts(),
+                KeyCode::Backspace | KeyCode::Esc => {
+                    self.current_screen = self.cuts_return_screen.clone();
+                }
+                _ => {}
+            },
+
+            // ─────────────────────────────────────────────────────────────
+            // Template picker → Generate
+            // ─────────────────────────────────────────────────────────────
+            CurrentScreen::TemplateSelection => match key {
+                KeyCode::Char('q') => self.current_screen = CurrentScreen::Exiting,
+                KeyCode::Char('j') | KeyCode::Down => self.next_template(),
+                KeyCode::Char('k') | KeyCode::Up => self.previous_template(),
+                KeyCode::Enter => self.generate_with_selected_template(),
+                KeyCode::Backspace => self.return_to_last_step(),
+                _ => {}
+            },
+
             // ─────────────────────────────────────────────────────────────
             // Terminal states
             // ─────────────────────────────────────────────────────────────
+            CurrentScreen::Success(_)
+                if matches!(key, KeyCode::Char('p' | 's' | 't' | 'm' | 'h' | 'w' | 'r')) =>
+            {
+                self.export_as(match key {
+                    KeyCode::Char('p') => OutputFormat::Png,
+                    KeyCode::Char('s') => OutputFormat::Svg,
+                    KeyCode::Char('t') => OutputFormat::Txt,
+                    KeyCode::Char('h') => OutputFormat::Html,
+                    KeyCode::Char('w') => OutputFormat::Docx,
+                    KeyCode::Char('r') => OutputFormat::Json,
+                    _ => OutputFormat::Md,
+                });
+            }
+
             CurrentScreen::Success(_) | CurrentScreen::Error(_) => match key {
                 KeyCode::Char('q') | KeyCode::Enter | KeyCode::Esc => {
                     self.current_screen = CurrentScreen::Exiting;
                 }
+                // Try another template (or selection) without restarting
+                KeyCode::Backspace => self.choose_template_or_return(),
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.output_scroll = self.output_scroll.saturating_add(1);
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.output_scroll = self.output_scroll.saturating_sub(1);
+                }
                 _ => {}
             },
 
@@ -0,0 +1,239 @@
+use clap::Args;
+use color_eyre::{Result, eyre::eyre};
+
+use crate::data_dir::DataDir;
+use crate::models::ResumeData;
+use crate::models::config::PageOverflow;
+use crate::models::diagnostics::{Diagnostic, summary};
+use crate::models::preset::Preset;
+use crate::page_budget::suggest_cuts;
+use crate::pdf::{CompileError, OutputFormat, compile, export_compiled};
+use crate::templates;
+
+#[derive(Debug, Default, Args)]
+pub struct BuildArgs {
+    /// Saved selection from data/presets/ to start from; other flags refine it
+    #[arg(long, value_name = "NAME")]
+    pub preset: Option<String>,
+
+    /// Job title to target, as listed in jobtitles.yaml
+    #[arg(long)]
+    pub job_title: Option<String>,
+
+    /// Template from data/templates/, by file name or display name
+    #[arg(long, value_name = "NAME")]
+    pub template: Option<String>,
+
+    /// Omit the email address from the header
+    #[arg(long)]
+    pub hide_email: bool,
+
+    /// Omit the phone number from the header
+    #[arg(long)]
+    pub hide_phone: bool,
+
+    /// Degree or id to leave out (repeatable)
+    #[arg(long, value_name = "DEGREE")]
+    pub exclude_education: Vec<String>,
+
+    /// Role or id to leave out (repeatable)
+    #[arg(long, value_name = "ROLE")]
+    pub exclude_experience: Vec<String>,
+
+    /// Project title or id to leave out (repeatable)
+    #[arg(long, value_name = "TITLE")]
+    pub exclude_project: Vec<String>,
+
+    /// Page budget; overrides `max_pages` in config.yaml
+    #[arg(long, value_name = "N")]
+    pub max_pages: Option<usize>,
+
+    /// Only warn when the PDF is over the page budget
+    #[arg(long)]
+    pub allow_overflow: bool,
+
+    /// When over the page budget, list the bullets to hide to fit
+    #[arg(long)]
+    pub suggest_cuts: bool,
+
+    /// Formats to write, comma separated; PNG and SVG get one file per page
+    #[arg(long, value_delimiter = ',', default_value = "pdf")]
+    pub format: Vec<OutputFormat>,
+
+    /// PNG resolution; overrides `png_dpi` in config.yaml
+    #[arg(long)]
+    pub dpi: Option<f32>,
+
+    /// Line width of txt and md output; overrides `text_width` in config.yaml
+    #[arg(long, value_name = "COLUMNS")]
+    pub text_width: Option<usize>,
+
+    /// HTML template from data/templates/html/; overrides `html_template` in config.yaml
+    #[arg(long, value_name = "NAME")]
+    pub html_template: Option<String>,
+}
+
+// Runs the same pipeline as the TUI: load -> select -> generate
+pub fn run(args: BuildArgs, data_dir: &DataDir) -> Result<()> {
+    let (mut data, diagnostics) = ResumeData::load(data_dir);
+    for diagnostic in &diagnostics {
+        eprintln!("{}", diagnostic);
+    }
+    if diagnostics.iter().any(Diagnostic::is_error) {
+        return Err(eyre!(
+            "Invalid data ({}); run `check` for details",
+            summary(&diagnostics)
+        ));
+    }
+
+    if let Some(ref name) = args.preset {
+        let preset = Preset::load(data_dir, name)?;
+        for warning in preset.apply(&mut data) {
+            eprintln!("Warning: preset {:?}: {}", name, warning);
+        }
+    }
+    apply(&args, &mut data)?;
+    if let Some(ref wanted) = args.template {
+        // Store the file name so --template accepts display names too
+        let path = templates::resolve(data_dir, Some(wanted))?;
+        data.template = path.file_name().map(|f| f.to_string_lossy().to_string());
+    }
+
+    if args.dpi.is_some() {
+        data.config.png_dpi = args.dpi;
+    }
+    if args.text_width.is_some() {
+        data.config.text_width = args.text_width;
+    }
+    if args.html_template.is_some() {
+        data.config.html_template = args.html_template.clone();
+    }
+
+    // Compiled once: the page count and the Typst formats share the document
+    let max_pages = args.max_pages.or(data.config.max_pages);
+    let compiled = if max_pages.is_some() || args.format.iter().any(|f| f.is_typst()) {
+        Some(compile(&data, data_dir).map_err(report_compile_error)?)
+    } else {
+        None
+    };
+    // Checked before writing so a failing build leaves no files behind
+    if let (Some(max_pages), Some((document, _))) = (max_pages, &compiled) {
+        check_page_budget(&args, &data, data_dir, max_pages, document.pages.len())?;
+    }
+    let output =
+        export_compiled(&data, data_dir, &args.format, compiled).map_err(report_compile_error)?;
+    for warning in &output.warnings {
+        eprintln!("{}\n", warning);
+    }
+    for path in &output.paths {
+        println!("{}", path);
+    }
+    Ok(())
+}
+
+// Print Typst diagnostics in full; the returned error is just the summary
+fn report_compile_error(e: color_eyre::Report) -> color_eyre::Report {
+    match e.downcast::<CompileError>() {
+        Ok(err) => {
+            for diagnostic in &err.diagnostics {
+                eprintln!("{}\n", diagnostic);
+            }
+            eyre!("Template failed to compile ({})", summary(&err.diagnostics))
+        }
+        Err(e) => e,
+    }
+}
+
+/// Fail, or only warn, when the `pages` the document has are over `max_pages`
+pub fn check_page_budget(
+    args: &BuildArgs,
+    data: &ResumeData,
+    data_dir: &DataDir,
+    max_pages: usize,
+    pages: usize,
+) -> Result<()> {
+    if pages <= max_pages {
+        return Ok(());
+    }
+    let message = format!("{} pages, over the budget of {}", pages, max_pages);
+
+    if args.suggest_cuts {
+        match suggest_cuts(data, data_dir, max_pages, pages) {
+            Ok(cuts) => {
+                eprintln!("Hide these {} bullets to fit:", cuts.len());
+                for cut in &cuts {
+                    eprintln!("  - {}: {}", cut.owner, cut.text);
+                }
+            }
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+
+    if args.allow_overflow || data.config.on_page_overflow == PageOverflow::Warn {
+        eprintln!("Warning: {}", message);
+        Ok(())
+    } else {
+        Err(eyre!("{}", message))
+    }
+}
+
+/// Apply the command line selection to freshly loaded data.
+/// Unknown names are errors so typos don't silently produce the wrong resume.
+pub fn apply(args: &BuildArgs, data: &mut ResumeData) -> Result<()> {
+    if let Some(ref wanted) = args.job_title {
+        let index = data
+            .job_titles
+            .iter()
+            .position(|jt| jt.title.eq_ignore_ascii_case(wanted.trim()))
+            .ok_or_else(|| {
+                let available: Vec<&str> =
+                    data.job_titles.iter().map(|jt| jt.title.as_str()).collect();
+                eyre!(
+                    "Unknown job title {:?}. Available: {}",
+                    wanted,
+                    available.join(", ")
+                )
+            })?;
+        data.select_job_title(index);
+    }
+
+    if let Some(profile) = &mut data.profile {
+        if args.hide_email {
+            profile.show_email = false;
+        }
+        if args.hide_phone {
+            profile.show_phone = false;
+        }
+    }
+
+    for degree in &args.exclude_education {
+        let item = data
+            .education
+            .iter_mut()
+            .find(|e| {
+                e.stable_id() == degree.trim() || e.degree.eq_ignore_ascii_case(degree.trim())
+            })
+            .ok_or_else(|| eyre!("No education entry with degree {:?}", degree))?;
+        item.is_visible = false;
+    }
+
+    for role in &args.exclude_experience {
+        let item = data
+            .experience
+            .iter_mut()
+            .find(|e| e.stable_id() == role.trim() || e.role.eq_ignore_ascii_case(role.trim()))
+            .ok_or_else(|| eyre!("No experience entry with role {:?}", role))?;
+        item.is_visible = false;
+    }
+
+    for title in &args.exclude_project {
+        let item = data
+            .projects
+            .iter_mut()
+            .find(|p| p.stable_id() == title.trim() || p.title.eq_ignore_ascii_case(title.trim()))
+            .ok_or_else(|| eyre!("No project titled {:?}", title))?;
+        item.is_visible = false;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use clap::Args;
+use color_eyre::Result;
+use std::process::ExitCode;
+
+use crate::data_dir::DataDir;
+use crate::models::ResumeData;
+use crate::models::diagnostics::{Diagnostic, summary};
+use crate::models::preset::Preset;
+
+#[derive(Debug, Default, Args)]
+pub struct CheckArgs {
+    /// Fail on warnings as well as errors
+    #[arg(long)]
+    pub strict: bool,
+}
+
+// Validate every data file and saved preset, printing all problems found
+/// Failure when there are errors, or any problem with `--strict`
+pub fn run(args: CheckArgs, data_dir: &DataDir) -> Result<ExitCode> {
+    let (data, mut diagnostics) = ResumeData::load(data_dir);
+
+    // Presets are validated against the data they would be applied to
+    for name in Preset::list(data_dir) {
+        let file = data_dir.presets_dir().join(format!("{}.yaml", name));
+        if let Some(preset) = Preset::read(&file, &mut diagnostics) {
+            diagnostics.extend(
+                preset
+                    .apply(&mut data.clone())
+                    .into_iter()
+                    .map(|warning| Diagnostic::warning(&file, warning)),
+            );
+        }
+    }
+
+    for diagnostic in &diagnostics {
+        println!("{}\n", diagnostic);
+    }
+    println!("{}: {}", data_dir.root().display(), summary(&diagnostics));
+
+    // The problems and the summary are already printed; fail without
+    // another error report under them
+    let failed = diagnostics.iter().any(|d| d.is_error() || args.strict);
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
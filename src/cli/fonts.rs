@@ -0,0 +1,42 @@
+use clap::Args;
+use color_eyre::Result;
+
+use crate::data_dir::DataDir;
+use crate::models::ResumeData;
+use crate::typst_backend::{FontOrigin, Fonts};
+
+#[derive(Debug, Default, Args)]
+pub struct FontsArgs {
+    /// Include system fonts even if `system_fonts` is off in config.yaml
+    #[arg(long)]
+    pub system: bool,
+}
+
+// List the font families templates can use with `#set text(font: ...)`
+pub fn run(args: FontsArgs, data_dir: &DataDir) -> Result<()> {
+    let system = args.system || ResumeData::load_config(data_dir).system_fonts;
+    let fonts = Fonts::load(&data_dir.fonts_dir(), system);
+
+    let families = fonts.families();
+    let width = families.iter().map(|(f, _, _)| f.len()).max().unwrap_or(0);
+    for (family, faces, origin) in &families {
+        let origin = match origin {
+            FontOrigin::Embedded => "built-in",
+            FontOrigin::User => "fonts/",
+            FontOrigin::System => "system",
+        };
+        let plural = if *faces == 1 { "" } else { "s" };
+        println!(
+            "{:<width$}  {} style{}, {}",
+            family,
+            faces,
+            plural,
+            origin,
+            width = width
+        );
+    }
+    if !system {
+        eprintln!("(system fonts not included; pass --system or set `system_fonts: true`)");
+    }
+    Ok(())
+}
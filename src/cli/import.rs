@@ -0,0 +1,440 @@
+use clap::{Args, Subcommand};
+use color_eyre::{Result, eyre::eyre};
+use serde::Serialize;
+use serde_yaml::Value;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+use super::schema::{MODELINE, with_modeline};
+use crate::data_dir::DataDir;
+use crate::json_resume::JsonResume;
+use crate::linkedin::{self, Archive};
+use crate::models::ResumeData;
+use crate::models::format::{self, Format};
+use crate::models::patch;
+use crate::models::resume::has_includes;
+use crate::models::schema;
+
+#[derive(Debug, Args)]
+pub struct ImportArgs {
+    #[command(subcommand)]
+    pub source: Source,
+
+    /// Overwrite data files that already exist; for linkedin, rewrite
+    /// files whole when the merge changes more than it appends
+    #[arg(long, global = true)]
+    pub force: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Source {
+    /// Convert a jsonresume.org resume.json into data files
+    JsonResume {
+        /// Path to the resume.json
+        file: PathBuf,
+    },
+    /// Merge a LinkedIn "Download your data" export into the data files
+    Linkedin {
+        /// The export zip, or the folder it was unpacked into
+        path: PathBuf,
+        /// Write without asking for confirmation
+        #[arg(long, short)]
+        yes: bool,
+        /// Only show what would change
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+// Files a LinkedIn import can change
+const MERGED_FILES: [&str; 4] = [
+    "profile.yaml",
+    "experience.yaml",
+    "education.yaml",
+    "projects.yaml",
+];
+
+pub fn run(args: ImportArgs, data_dir: &DataDir) -> Result<()> {
+    let data = match args.source {
+        Source::JsonResume { ref file } => {
+            let content = fs::read_to_string(file)
+                .map_err(|e| eyre!("Could not read {}: {}", file.display(), e))?;
+            let resume: JsonResume = serde_json::from_str(&content)
+                .map_err(|e| eyre!("{} is not a JSON Resume: {}", file.display(), e))?;
+            resume.into_resume_data()
+        }
+        Source::Linkedin {
+            ref path,
+            yes,
+            dry_run,
+        } => return merge_linkedin(path, yes, dry_run, args.force, data_dir),
+    };
+
+    for path in write_data_files(&data, data_dir, args.force)? {
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+// Merges into what is already there, so the preview is the only safeguard:
+// nothing is written until the user agrees to it
+fn merge_linkedin(path: &Path, yes: bool, dry_run: bool, force: bool, dir: &DataDir) -> Result<()> {
+    let imported = Archive::open(path)?.into_resume_data()?;
+    let rewrites = plan_merge(imported, dir)?;
+    if rewrites.is_empty() {
+        println!("Nothing new to import into {}", dir.root().display());
+        return Ok(());
+    }
+    for rewrite in &rewrites {
+        println!("{}", rewrite.path.display());
+        if !rewrite.patched && !rewrite.old.trim().is_empty() {
+            println!("  (written whole, which takes --force)");
+        }
+        for change in &rewrite.changes {
+            println!("  {}", change);
+        }
+        println!();
+        for line in diff(&rewrite.old, &rewrite.new) {
+            println!("    {}", line);
+        }
+        println!();
+    }
+
+    if dry_run {
+        return Ok(());
+    }
+    check_writable(&rewrites, dir, force)?;
+    if !yes && !confirm("Write these changes?")? {
+        println!("Nothing written");
+        return Ok(());
+    }
+    for rewrite in rewrites {
+        fs::write(&rewrite.path, &rewrite.new)?;
+        println!("{}", rewrite.path.display());
+    }
+    Ok(())
+}
+
+/// A data file as it is now and as an import would write it
+pub struct Rewrite {
+    pub path: PathBuf,
+    pub old: String,
+    pub new: String,
+    // What `linkedin::merge` did to the file, one line per entry
+    pub changes: Vec<String>,
+    // Only new entries and bullets were added; otherwise the whole file is
+    // written from the data, which takes --force and loses its comments
+    pub patched: bool,
+}
+
+/// Merge `imported` into the data files, returning the ones that change.
+/// New entries and bullets are added to YAML files in place; files where
+/// the merge does more, and TOML and JSON files, are written whole, in file
+/// order rather than `sort_by_date`.
+pub fn plan_merge(imported: ResumeData, dir: &DataDir) -> Result<Vec<Rewrite>> {
+    // A file that failed to parse would be written back without its entries
+    let (mut data, diagnostics) = ResumeData::load_in_file_order(dir);
+    let broken: Vec<String> = diagnostics
+        .iter()
+        .filter(|d| d.is_error() && MERGED_FILES.iter().any(|f| dir.file(f) == d.file))
+        .map(|d| d.file.display().to_string())
+        .collect();
+    if !broken.is_empty() {
+        return Err(eyre!(
+            "Fix {} before importing into it; run `check` for details",
+            broken.join(", ")
+        ));
+    }
+
+    let before = data.clone();
+    let changes = linkedin::merge(&mut data, imported);
+    data_files(&data)?
+        .into_iter()
+        .filter(|(name, _)| changes.iter().any(|c| c.file == *name))
+        .map(|(name, value)| {
+            let (path, whole) = render(name, &value, dir)?;
+            let old = fs::read_to_string(&path).unwrap_or_default();
+            let patched = match name {
+                "profile.yaml" => patch::entries(
+                    &path,
+                    &old,
+                    "profile",
+                    before.profile.as_slice(),
+                    data.profile.as_slice(),
+                ),
+                "experience.yaml" => patch::entries(
+                    &path,
+                    &old,
+                    "experience",
+                    &before.experience,
+                    &data.experience,
+                ),
+                "education.yaml" => {
+                    patch::entries(&path, &old, "education", &before.education, &data.education)
+                }
+                "projects.yaml" => {
+                    patch::entries(&path, &old, "projects", &before.projects, &data.projects)
+                }
+                _ => None,
+            };
+            Ok(Rewrite {
+                path,
+                old,
+                patched: patched.is_some(),
+                new: patched.unwrap_or(whole),
+                changes: changes
+                    .iter()
+                    .filter(|c| c.file == name)
+                    .map(|c| c.description.clone())
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// Refuse rewrites that would lose something the preview doesn't show, and
+/// whole-file rewrites of existing files without `force`
+pub fn check_writable(rewrites: &[Rewrite], dir: &DataDir, force: bool) -> Result<()> {
+    let names: Vec<&str> = MERGED_FILES
+        .into_iter()
+        .filter(|name| rewrites.iter().any(|r| r.path == dir.file(name)))
+        .collect();
+    refuse_split(names.into_iter(), dir)?;
+    // Writing back what was loaded would copy base and included entries in
+    if ResumeData::load_config(dir).extends.is_some() {
+        return Err(eyre!(
+            "Can't write into {}, which extends another data directory; preview with --dry-run and copy the changes by hand",
+            dir.root().display()
+        ));
+    }
+    let refuse = |paths: Vec<String>, reason: &str| {
+        if paths.is_empty() {
+            Ok(())
+        } else {
+            Err(eyre!(
+                "Can't write into {}, which {}; preview with --dry-run and copy the changes by hand",
+                paths.join(", "),
+                reason
+            ))
+        }
+    };
+    refuse(
+        rewrites
+            .iter()
+            .filter(|r| has_includes(&r.path))
+            .map(|r| r.path.display().to_string())
+            .collect(),
+        "includes other files",
+    )?;
+    // The schema modeline is written back; any other comment would be lost
+    refuse(
+        rewrites
+            .iter()
+            .filter(|r| !r.patched && format::has_comments(&r.path, without_modeline(&r.old)))
+            .map(|r| r.path.display().to_string())
+            .collect(),
+        "has comments that rewriting would drop",
+    )?;
+    let rewritten: Vec<String> = rewrites
+        .iter()
+        .filter(|r| !r.patched && !r.old.trim().is_empty())
+        .map(|r| r.path.display().to_string())
+        .collect();
+    if !force && !rewritten.is_empty() {
+        return Err(eyre!(
+            "{} would be rewritten whole, losing its formatting; pass --force to write it anyway",
+            rewritten.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+fn without_modeline(content: &str) -> &str {
+    match content.split_once('\n') {
+        Some((first, rest)) if first.starts_with(MODELINE) => rest,
+        None if content.starts_with(MODELINE) => "",
+        _ => content,
+    }
+}
+
+/// Changed lines marked `-` and `+`, with two unchanged lines around each
+/// change and `...` for the ones in between, like `diff -u`
+pub fn diff(old: &str, new: &str) -> Vec<String> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    // common[i][j]: length of the longest common subsequence of old[i..] and new[j..]
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push((' ', old[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(('-', old[i]));
+            i += 1;
+        } else {
+            lines.push(('+', new[j]));
+            j += 1;
+        }
+    }
+
+    let near_change = |k: usize| {
+        lines[k.saturating_sub(2)..(k + 3).min(lines.len())]
+            .iter()
+            .any(|(marker, _)| *marker != ' ')
+    };
+    let mut out = Vec::new();
+    let mut skipped = false;
+    for (k, (marker, line)) in lines.iter().enumerate() {
+        if !near_change(k) {
+            skipped = true;
+            continue;
+        }
+        if skipped {
+            out.push("...".to_string());
+            skipped = false;
+        }
+        out.push(format!("{} {}", marker, line).trim_end().to_string());
+    }
+    out
+}
+
+fn confirm(question: &str) -> Result<bool> {
+    if !io::stdin().is_terminal() {
+        return Err(eyre!(
+            "Not asking for confirmation without a terminal; pass --yes to write or --dry-run to preview"
+        ));
+    }
+    print!("{} [y/N] ", question);
+    io::stdout().flush()?;
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+/// Write the data files `ResumeData::load` reads. Core files are always
+/// written; optional sections only when they have entries. Nothing is
+/// written if a file would be overwritten without `force`.
+pub fn write_data_files(data: &ResumeData, dir: &DataDir, force: bool) -> Result<Vec<PathBuf>> {
+    let files = data_files(data)?;
+    let existing: Vec<&str> = files
+        .iter()
+        .map(|(name, _)| *name)
+        .filter(|name| dir.file(name).exists())
+        .collect();
+    if !force && !existing.is_empty() {
+        return Err(eyre!(
+            "{} already has {}; pass --force to overwrite",
+            dir.root().display(),
+            existing.join(", ")
+        ));
+    }
+    write_files(files, dir)
+}
+
+// File name and contents of every file `write_data_files` would write
+fn data_files(data: &ResumeData) -> Result<Vec<(&'static str, Value)>> {
+    let mut files = vec![
+        ("profile.yaml", to_value(&data.profile)?),
+        ("jobtitles.yaml", to_value(&data.job_titles)?),
+        ("experience.yaml", to_value(&data.experience)?),
+        ("education.yaml", to_value(&data.education)?),
+        ("projects.yaml", to_value(&data.projects)?),
+    ];
+    for (name, empty, value) in [
+        (
+            "skills.yaml",
+            data.skills.is_empty(),
+            to_value(&data.skills)?,
+        ),
+        (
+            "certifications.yaml",
+            data.certifications.is_empty(),
+            to_value(&data.certifications)?,
+        ),
+        (
+            "awards.yaml",
+            data.awards.is_empty(),
+            to_value(&data.awards)?,
+        ),
+        (
+            "publications.yaml",
+            data.publications.is_empty(),
+            to_value(&data.publications)?,
+        ),
+        (
+            "languages.yaml",
+            data.languages.is_empty(),
+            to_value(&data.languages)?,
+        ),
+        (
+            "volunteering.yaml",
+            data.volunteering.is_empty(),
+            to_value(&data.volunteering)?,
+        ),
+    ] {
+        if !empty {
+            files.push((name, value));
+        }
+    }
+    Ok(files)
+}
+
+fn write_files(files: Vec<(&str, Value)>, dir: &DataDir) -> Result<Vec<PathBuf>> {
+    refuse_split(files.iter().map(|(name, _)| *name), dir)?;
+    fs::create_dir_all(dir.root())?;
+    let mut written = Vec::new();
+    for (name, value) in files {
+        let (path, content) = render(name, &value, dir)?;
+        fs::write(&path, content)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+// Each file keeps the format it already has; new files are YAML
+fn render(name: &str, value: &Value, dir: &DataDir) -> Result<(PathBuf, String)> {
+    let path = dir.file(name);
+    let mut content = format::to_string(&path, value)?;
+    // Same editor hint the `schema --modeline` command adds
+    if Format::of(&path) == Format::Yaml
+        && dir.schemas_dir().is_dir()
+        && let Some(schema) = schema::name_for(Path::new(name))
+    {
+        content = with_modeline(&content, &format!("schemas/{}.schema.json", schema));
+    }
+    Ok((path, content))
+}
+
+// The loader reads a folder's entries as well as the file's, so rewriting
+// experience.yaml with all of them would duplicate experience/
+fn refuse_split<'a>(names: impl Iterator<Item = &'a str>, dir: &DataDir) -> Result<()> {
+    let split: Vec<String> = names
+        .map(|name| dir.file(name).with_extension(""))
+        .filter(|folder| folder.is_dir())
+        .map(|folder| format!("{}/", folder.display()))
+        .collect();
+    if split.is_empty() {
+        return Ok(());
+    }
+    Err(eyre!(
+        "Can't write into {}, which holds one file per entry; preview with --dry-run and copy the changes by hand",
+        split.join(", ")
+    ))
+}
+
+fn to_value<T: Serialize + ?Sized>(value: &T) -> Result<Value> {
+    Ok(serde_yaml::to_value(value)?)
+}
@@ -0,0 +1,53 @@
+use clap::{Parser, Subcommand};
+use color_eyre::Result;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use crate::data_dir::{DATA_DIR_ENV, DataDir};
+
+pub mod build;
+pub mod check;
+pub mod fonts;
+pub mod import;
+pub mod schema;
+
+// COMMAND LINE INTERFACE
+// Running without a subcommand opens the TUI.
+#[derive(Debug, Parser)]
+#[command(name = "resume-pipeline", version, about)]
+pub struct Cli {
+    /// Folder holding profile.yaml, templates/ and output/
+    /// [default: $XDG_CONFIG_HOME/resume-pipeline or ~/.config/resume-pipeline;
+    /// ./data without a home directory]
+    #[arg(long, global = true, env = DATA_DIR_ENV, value_name = "DIR")]
+    pub data_dir: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Generate a PDF without opening the TUI
+    Build(build::BuildArgs),
+    /// Validate the data files and presets, reporting every problem found
+    Check(check::CheckArgs),
+    /// List the font families available to templates
+    Fonts(fonts::FontsArgs),
+    /// Create data files from another resume format
+    Import(import::ImportArgs),
+    /// Write JSON Schemas for the data files, for editor autocompletion
+    Schema(schema::SchemaArgs),
+}
+
+pub fn run(command: Command, data_dir: &DataDir) -> Result<ExitCode> {
+    match command {
+        Command::Build(args) => build::run(args, data_dir)?,
+        // Reports its own problems and only sets the exit code
+        Command::Check(args) => return check::run(args, data_dir),
+        Command::Fonts(args) => fonts::run(args, data_dir)?,
+        Command::Import(args) => import::run(args, data_dir)?,
+        Command::Schema(args) => schema::run(args, data_dir)?,
+    }
+    Ok(ExitCode::SUCCESS)
+}
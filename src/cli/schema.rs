@@ -0,0 +1,131 @@
+use clap::Args;
+use color_eyre::{Result, eyre::eyre};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::data_dir::DataDir;
+use crate::models::schema;
+
+pub const MODELINE: &str = "# yaml-language-server: $schema=";
+
+#[derive(Debug, Default, Args)]
+pub struct SchemaArgs {
+    /// Print one schema (e.g. "experience") to stdout instead of writing files
+    #[arg(long, value_name = "NAME")]
+    pub print: Option<String>,
+
+    /// Where to write the schemas [default: <data-dir>/schemas]
+    #[arg(long, value_name = "DIR")]
+    pub out: Option<PathBuf>,
+
+    /// Also add a `# yaml-language-server: $schema=` line to each data file
+    #[arg(long)]
+    pub modeline: bool,
+}
+
+pub fn run(args: SchemaArgs, data_dir: &DataDir) -> Result<()> {
+    let schemas = schema::all();
+
+    if let Some(ref name) = args.print {
+        let found = schemas.iter().find(|s| s.name == name).ok_or_else(|| {
+            let names: Vec<&str> = schemas.iter().map(|s| s.name).collect();
+            eyre!("Unknown schema {:?}. Available: {}", name, names.join(", "))
+        })?;
+        print!("{}", schema::to_json(&found.schema));
+        return Ok(());
+    }
+
+    let out = args.out.unwrap_or_else(|| data_dir.schemas_dir());
+    fs::create_dir_all(&out)?;
+    for entry in &schemas {
+        let path = out.join(format!("{}.schema.json", entry.name));
+        fs::write(&path, schema::to_json(&entry.schema))?;
+        println!("{} ({})", path.display(), entry.files);
+    }
+
+    if args.modeline {
+        for file in data_files(data_dir.root()) {
+            let relative = file.strip_prefix(data_dir.root()).unwrap_or(&file);
+            let Some(name) = schema::name_for(relative) else {
+                continue;
+            };
+            let target = out.join(format!("{}.schema.json", name));
+            let schema_ref = relative_path(file.parent().unwrap_or(data_dir.root()), &target);
+            if write_modeline(&file, &schema_ref)? {
+                println!("{}: {}{}", file.display(), MODELINE, schema_ref);
+            }
+        }
+    }
+    Ok(())
+}
+
+// YAML files at the top of the data dir plus sections/, presets/ and the
+// folders experience, education and projects can be split into
+fn data_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for dir in [
+        root.to_path_buf(),
+        root.join("sections"),
+        root.join("presets"),
+        root.join("education"),
+        root.join("experience"),
+        root.join("projects"),
+    ] {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        files.extend(
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "yaml")),
+        );
+    }
+    files.sort();
+    files
+}
+
+/// Insert or replace the modeline on the first line; false if already current
+pub fn write_modeline(file: &Path, schema_ref: &str) -> Result<bool> {
+    let content = fs::read_to_string(file)?;
+    let updated = with_modeline(&content, schema_ref);
+    if updated == content {
+        return Ok(false);
+    }
+    fs::write(file, updated)?;
+    Ok(true)
+}
+
+/// `content` with the modeline inserted or replaced on the first line
+pub fn with_modeline(content: &str, schema_ref: &str) -> String {
+    let body = match content.split_once('\n') {
+        Some((first, rest)) if first.starts_with(MODELINE) => rest,
+        None if content.starts_with(MODELINE) => "",
+        _ => content,
+    };
+    format!("{}{}\n{}", MODELINE, schema_ref, body)
+}
+
+// `target` as seen from `from`, with forward slashes (editors expect URLs)
+fn relative_path(from: &Path, target: &Path) -> String {
+    let from_abs = absolute(from);
+    let from: Vec<_> = from_abs.components().collect();
+    let target_abs = absolute(target);
+    let target_parts: Vec<_> = target_abs.components().collect();
+    let common = from
+        .iter()
+        .zip(&target_parts)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut parts: Vec<String> = vec!["..".to_string(); from.len() - common];
+    parts.extend(
+        target_parts[common..]
+            .iter()
+            .map(|c| c.as_os_str().to_string_lossy().to_string()),
+    );
+    parts.join("/")
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
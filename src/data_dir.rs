@@ -0,0 +1,104 @@
+use std::path::{Path, PathBuf};
+
+/// Environment variable consulted when `--data-dir` is not given
+pub const DATA_DIR_ENV: &str = "RESUME_PIPELINE_DATA";
+
+/// Data files can be written in any of these
+pub const DATA_EXTENSIONS: [&str; 3] = ["yaml", "toml", "json"];
+
+// Root of a data tree: the YAML files plus templates/ and output/.
+// Resolved once at startup and passed down, so nothing re-reads current_dir().
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataDir {
+    root: PathBuf,
+}
+
+impl Default for DataDir {
+    fn default() -> Self {
+        Self::new("data")
+    }
+}
+
+impl DataDir {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Pick the data directory in order of precedence:
+    /// 1. an explicit path (`--data-dir` or `RESUME_PIPELINE_DATA`, handled by clap)
+    /// 2. `$XDG_CONFIG_HOME/resume-pipeline` (or `~/.config/resume-pipeline`)
+    ///
+    /// `./data` is only the fallback when there is no home directory at all.
+    pub fn resolve(explicit: Option<PathBuf>) -> Self {
+        if let Some(path) = explicit {
+            return Self::new(path);
+        }
+
+        match xdg_config_home() {
+            Some(config) => Self::new(config.join("resume-pipeline")),
+            None => Self::default(),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Path of a data file such as `profile.yaml`. When only `profile.toml`
+    /// or `profile.json` exists, that one is returned instead.
+    pub fn file(&self, name: &str) -> PathBuf {
+        let path = self.root.join(name);
+        if path.exists() {
+            return path;
+        }
+        self.variants(name).into_iter().next().unwrap_or(path)
+    }
+
+    /// The formats of a data file that exist, e.g. both `profile.yaml` and
+    /// `profile.json`. More than one is an error the loader reports.
+    pub fn variants(&self, name: &str) -> Vec<PathBuf> {
+        let path = self.root.join(name);
+        DATA_EXTENSIONS
+            .iter()
+            .map(|ext| path.with_extension(ext))
+            .filter(|path| path.is_file())
+            .collect()
+    }
+
+    pub fn templates_dir(&self) -> PathBuf {
+        self.root.join("templates")
+    }
+
+    /// HTML export templates live apart from the Typst ones
+    pub fn html_templates_dir(&self) -> PathBuf {
+        self.templates_dir().join("html")
+    }
+
+    pub fn output_dir(&self) -> PathBuf {
+        self.root.join("output")
+    }
+
+    pub fn presets_dir(&self) -> PathBuf {
+        self.root.join("presets")
+    }
+
+    pub fn sections_dir(&self) -> PathBuf {
+        self.root.join("sections")
+    }
+
+    pub fn fonts_dir(&self) -> PathBuf {
+        self.root.join("fonts")
+    }
+
+    pub fn schemas_dir(&self) -> PathBuf {
+        self.root.join("schemas")
+    }
+}
+
+fn xdg_config_home() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os("XDG_CONFIG_HOME").filter(|d| !d.is_empty()) {
+        return Some(PathBuf::from(dir));
+    }
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(".config"))
+}
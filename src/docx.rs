@@ -0,0 +1,400 @@
+use color_eyre::Result;
+use std::io::{Cursor, Write};
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+use crate::layout::{self, href};
+use crate::models::types::{Bullet, FilteredResumeData};
+use crate::text_export::{is_present, present};
+
+// A Word document written by hand: the handful of OOXML parts Word needs,
+// with named styles so recruiters can restyle it, and real hyperlinks.
+// Sections follow the same order and visibility as the default template.
+pub fn render(data: &FilteredResumeData) -> Result<Vec<u8>> {
+    let mut doc = Document::default();
+
+    // Header
+    let profile = &data.profile;
+    doc.paragraph("Title", vec![Run::text(&profile.name)]);
+    let mut contact = Vec::new();
+    for (value, url) in [
+        (&profile.phone, None),
+        (&profile.email, Some(format!("mailto:{}", profile.email))),
+        (&profile.url, Some(href(&profile.url))),
+        (&profile.website, Some(href(&profile.website))),
+    ] {
+        if !is_present(value) {
+            continue;
+        }
+        if !contact.is_empty() {
+            contact.push(Run::text(" | "));
+        }
+        contact.push(match url {
+            Some(url) => Run::link(value, &url),
+            None => Run::text(value),
+        });
+    }
+    doc.paragraph("Contact", contact);
+    let place = present(&[&profile.citizenship, &profile.location]).join(" • ");
+    doc.paragraph("Contact", vec![Run::text(&place)]);
+    if is_present(&data.job_title) {
+        doc.paragraph("JobTitle", vec![Run::text(&data.job_title)]);
+    }
+    if is_present(&data.professional_summary) {
+        doc.paragraph("Normal", vec![Run::text(&data.professional_summary)]);
+    }
+
+    if !data.education.is_empty() {
+        doc.heading("Education");
+        for edu in &data.education {
+            let when = present(&[&edu.status, &edu.date]).join(", ");
+            doc.entry(vec![Run::bold(&edu.school)], &when);
+            doc.paragraph("Normal", vec![Run::text(&edu.degree)]);
+        }
+    }
+
+    if !data.experience.is_empty() {
+        doc.heading("Work Experience");
+        for job in &data.experience {
+            doc.entry(titled(&job.role, &[&job.company, &job.location]), &job.date);
+            doc.paragraph("Normal", vec![Run::text(&job.summary)]);
+            doc.bullets(&job.bullets);
+        }
+    }
+
+    if !data.projects.is_empty() {
+        doc.heading("Projects");
+        for project in &data.projects {
+            let mut title = vec![Run::bold(&project.title)];
+            if let Some(url) = project.url.as_deref().filter(|u| is_present(u)) {
+                title.push(Run::text(" | "));
+                title.push(Run::link(url, &href(url)));
+            }
+            doc.entry(title, "");
+            doc.paragraph("Normal", vec![Run::text(&project.description)]);
+            if !project.tech_stack.is_empty() {
+                doc.paragraph(
+                    "Normal",
+                    vec![
+                        Run::italic("Tech: "),
+                        Run::text(&project.tech_stack.join(", ")),
+                    ],
+                );
+            }
+        }
+    }
+
+    if !data.skills.is_empty() {
+        doc.heading("Skills");
+        for category in &data.skills {
+            let skills: Vec<String> = category.skills.iter().map(layout::skill_line).collect();
+            doc.paragraph(
+                "Normal",
+                vec![
+                    Run::bold(&format!("{}: ", category.category)),
+                    Run::text(&skills.join(", ")),
+                ],
+            );
+        }
+    }
+
+    if !data.certifications.is_empty() {
+        doc.heading("Certifications");
+        for cert in &data.certifications {
+            let mut title = titled(&cert.name, &[&cert.issuer]);
+            if let Some(url) = cert.credential_url.as_deref().filter(|u| is_present(u)) {
+                title.push(Run::text(" | "));
+                title.push(Run::link("Credential", &href(url)));
+            }
+            doc.entry(title, &cert.date);
+        }
+    }
+
+    if !data.awards.is_empty() {
+        doc.heading("Awards");
+        for award in &data.awards {
+            doc.entry(titled(&award.title, &[&award.issuer]), &award.date);
+            doc.paragraph("Normal", vec![Run::text(&award.description)]);
+        }
+    }
+
+    if !data.publications.is_empty() {
+        doc.heading("Publications");
+        for publication in &data.publications {
+            doc.entry(
+                titled(&publication.title, &[&publication.venue]),
+                &publication.date,
+            );
+            doc.paragraph("Normal", vec![Run::text(&publication.authors.join(", "))]);
+            if let Some(ref doi) = publication.doi {
+                let url = format!("https://doi.org/{}", doi);
+                doc.paragraph("Normal", vec![Run::text("DOI: "), Run::link(doi, &url)]);
+            }
+        }
+    }
+
+    if !data.languages.is_empty() {
+        doc.heading("Languages");
+        let mut runs = Vec::new();
+        for (i, l) in data.languages.iter().enumerate() {
+            if i > 0 {
+                runs.push(Run::text(" • "));
+            }
+            runs.push(Run::bold(&l.language));
+            runs.push(Run::text(&format!(" ({})", l.proficiency)));
+        }
+        doc.paragraph("Normal", runs);
+    }
+
+    if !data.volunteering.is_empty() {
+        doc.heading("Volunteering");
+        for vol in &data.volunteering {
+            doc.entry(
+                titled(&vol.role, &[&vol.organization, &vol.location]),
+                &vol.date,
+            );
+            doc.paragraph("Normal", vec![Run::text(&vol.summary)]);
+            doc.bullets(&vol.bullets);
+        }
+    }
+
+    for section in &data.custom_sections {
+        doc.heading(&section.title);
+        for entry in &section.entries {
+            let mut title = titled(entry.heading(), &layout::details(entry));
+            if let Some(url) = entry.field("url").filter(|u| is_present(u)) {
+                title.push(Run::text(" | "));
+                title.push(Run::link("Link", &href(url)));
+            }
+            doc.entry(title, entry.field("date").unwrap_or_default());
+            doc.paragraph(
+                "Normal",
+                vec![Run::text(entry.field("description").unwrap_or_default())],
+            );
+            doc.bullets(&entry.bullets);
+        }
+    }
+
+    doc.package()
+}
+
+// Bold title followed by " | detail, detail" in italics
+fn titled(title: &str, details: &[&str]) -> Vec<Run> {
+    let mut runs = vec![Run::bold(title)];
+    let details = present(details).join(", ");
+    if !details.is_empty() {
+        runs.push(Run::text(" | "));
+        runs.push(Run::italic(&details));
+    }
+    runs
+}
+
+enum Run {
+    Text {
+        text: String,
+        bold: bool,
+        italic: bool,
+    },
+    Link {
+        text: String,
+        url: String,
+    },
+}
+
+impl Run {
+    fn text(text: &str) -> Self {
+        Run::Text {
+            text: text.to_string(),
+            bold: false,
+            italic: false,
+        }
+    }
+
+    fn bold(text: &str) -> Self {
+        Run::Text {
+            text: text.to_string(),
+            bold: true,
+            italic: false,
+        }
+    }
+
+    fn italic(text: &str) -> Self {
+        Run::Text {
+            text: text.to_string(),
+            bold: false,
+            italic: true,
+        }
+    }
+
+    fn link(text: &str, url: &str) -> Self {
+        Run::Link {
+            text: text.to_string(),
+            url: url.to_string(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Run::Text { text, .. } | Run::Link { text, .. } => text.trim().is_empty(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Document {
+    body: String,
+    // Hyperlink targets; the relationship id is "link" + index
+    links: Vec<String>,
+}
+
+impl Document {
+    // Paragraphs with no text are left out, like empty fields in the template
+    fn paragraph(&mut self, style: &str, runs: Vec<Run>) {
+        if runs.iter().all(Run::is_empty) {
+            return;
+        }
+        self.body.push_str(&format!(
+            r#"<w:p><w:pPr><w:pStyle w:val="{}"/></w:pPr>"#,
+            style
+        ));
+        for run in runs {
+            match run {
+                Run::Text { text, bold, italic } => {
+                    let mut props = String::new();
+                    if bold {
+                        props.push_str("<w:b/>");
+                    }
+                    if italic {
+                        props.push_str("<w:i/>");
+                    }
+                    self.body.push_str(&text_run(&props, &text));
+                }
+                Run::Link { text, url } => {
+                    self.body.push_str(&format!(
+                        r#"<w:hyperlink r:id="link{}">{}</w:hyperlink>"#,
+                        self.links.len(),
+                        text_run(r#"<w:rStyle w:val="Hyperlink"/>"#, &text)
+                    ));
+                    self.links.push(url);
+                }
+            }
+        }
+        self.body.push_str("</w:p>");
+    }
+
+    fn heading(&mut self, title: &str) {
+        self.paragraph("Heading1", vec![Run::text(title)]);
+    }
+
+    // Title on the left, date against the right margin
+    fn entry(&mut self, mut title: Vec<Run>, date: &str) {
+        if is_present(date) {
+            title.push(Run::Text {
+                text: format!("\t{}", date),
+                bold: false,
+                italic: true,
+            });
+        }
+        self.paragraph("Entry", title);
+    }
+
+    fn bullets(&mut self, bullets: &[Bullet]) {
+        for bullet in bullets {
+            self.paragraph("ListBullet", vec![Run::text(bullet.text())]);
+        }
+    }
+
+    fn package(self) -> Result<Vec<u8>> {
+        let document = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><w:body>{}<w:sectPr><w:pgSz w:w="12240" w:h="15840"/><w:pgMar w:top="864" w:right="1080" w:bottom="864" w:left="1080" w:header="720" w:footer="720" w:gutter="0"/></w:sectPr></w:body></w:document>"#,
+            self.body
+        );
+        let links: String = self
+            .links
+            .iter()
+            .enumerate()
+            .map(|(i, url)| {
+                format!(
+                    r#"<Relationship Id="link{}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink" Target="{}" TargetMode="External"/>"#,
+                    i,
+                    escape(url)
+                )
+            })
+            .collect();
+        let document_rels = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="styles" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/><Relationship Id="numbering" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/numbering" Target="numbering.xml"/>{}</Relationships>"#,
+            links
+        );
+
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default();
+        for (name, content) in [
+            ("[Content_Types].xml", CONTENT_TYPES),
+            ("_rels/.rels", PACKAGE_RELS),
+            ("word/document.xml", document.as_str()),
+            ("word/_rels/document.xml.rels", document_rels.as_str()),
+            ("word/styles.xml", STYLES),
+            ("word/numbering.xml", NUMBERING),
+        ] {
+            zip.start_file(name, options)?;
+            zip.write_all(content.as_bytes())?;
+        }
+        Ok(zip.finish()?.into_inner())
+    }
+}
+
+fn text_run(properties: &str, text: &str) -> String {
+    // Tabs are their own element; everything else is plain text
+    let content: Vec<String> = text
+        .split('\t')
+        .map(|part| format!(r#"<w:t xml:space="preserve">{}</w:t>"#, escape(part)))
+        .collect();
+    format!(
+        "<w:r><w:rPr>{}</w:rPr>{}</w:r>",
+        properties,
+        content.join("<w:tab/>")
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace(not_xml, "")
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Control characters other than tab and newlines can't be written in XML
+// 1.0, not even as references, and Word refuses the file; they are dropped
+fn not_xml(c: char) -> bool {
+    matches!(
+        c,
+        '\0'..='\u{8}' | '\u{b}' | '\u{c}' | '\u{e}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}'
+    )
+}
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/><Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/><Override PartName="/word/numbering.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.numbering+xml"/></Types>"#;
+
+const PACKAGE_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="document" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/></Relationships>"#;
+
+// Letter paper with 0.75in side margins leaves 10080 twips for the
+// right-aligned date tab stop
+const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:docDefaults><w:rPrDefault><w:rPr><w:rFonts w:ascii="Arial" w:hAnsi="Arial" w:cs="Arial"/><w:sz w:val="20"/><w:lang w:val="en-US"/></w:rPr></w:rPrDefault><w:pPrDefault><w:pPr><w:spacing w:after="80" w:line="259" w:lineRule="auto"/></w:pPr></w:pPrDefault></w:docDefaults>
+<w:style w:type="paragraph" w:default="1" w:styleId="Normal"><w:name w:val="Normal"/><w:qFormat/><w:pPr><w:jc w:val="both"/></w:pPr></w:style>
+<w:style w:type="paragraph" w:styleId="Title"><w:name w:val="Title"/><w:basedOn w:val="Normal"/><w:next w:val="Contact"/><w:qFormat/><w:pPr><w:spacing w:after="60"/><w:jc w:val="center"/></w:pPr><w:rPr><w:b/><w:sz w:val="32"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:customStyle="1" w:styleId="Contact"><w:name w:val="Contact"/><w:basedOn w:val="Normal"/><w:qFormat/><w:pPr><w:spacing w:after="0"/><w:jc w:val="center"/></w:pPr></w:style>
+<w:style w:type="paragraph" w:customStyle="1" w:styleId="JobTitle"><w:name w:val="Job Title"/><w:basedOn w:val="Normal"/><w:qFormat/><w:pPr><w:spacing w:before="160" w:after="160"/><w:jc w:val="center"/></w:pPr><w:rPr><w:b/><w:sz w:val="22"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading1"><w:name w:val="heading 1"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:keepNext/><w:pBdr><w:bottom w:val="single" w:sz="4" w:space="1" w:color="808080"/></w:pBdr><w:spacing w:before="240" w:after="80"/><w:jc w:val="left"/><w:outlineLvl w:val="0"/></w:pPr><w:rPr><w:b/></w:rPr></w:style>
+<w:style w:type="paragraph" w:customStyle="1" w:styleId="Entry"><w:name w:val="Entry"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:keepNext/><w:tabs><w:tab w:val="right" w:pos="10080"/></w:tabs><w:spacing w:before="120" w:after="40"/><w:jc w:val="left"/></w:pPr></w:style>
+<w:style w:type="paragraph" w:styleId="ListBullet"><w:name w:val="List Bullet"/><w:basedOn w:val="Normal"/><w:qFormat/><w:pPr><w:numPr><w:numId w:val="1"/></w:numPr><w:spacing w:after="40"/></w:pPr></w:style>
+<w:style w:type="character" w:styleId="Hyperlink"><w:name w:val="Hyperlink"/><w:rPr><w:color w:val="1A5FB4"/><w:u w:val="single"/></w:rPr></w:style>
+</w:styles>"#;
+
+const NUMBERING: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:numbering xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:abstractNum w:abstractNumId="0"><w:multiLevelType w:val="singleLevel"/><w:lvl w:ilvl="0"><w:start w:val="1"/><w:numFmt w:val="bullet"/><w:lvlText w:val="•"/><w:lvlJc w:val="left"/><w:pPr><w:ind w:left="360" w:hanging="360"/></w:pPr></w:lvl></w:abstractNum><w:num w:numId="1"><w:abstractNumId w:val="0"/></w:num></w:numbering>"#;
@@ -0,0 +1,87 @@
+use color_eyre::{Result, eyre::eyre};
+use minijinja::value::ViaDeserialize;
+use minijinja::{Environment, context, path_loader};
+use std::fs;
+
+use crate::data_dir::DataDir;
+use crate::layout;
+use crate::models::types::{Bullet, CustomEntry, FilteredResumeData, Skill};
+
+// Used when neither config.yaml nor --html-template picks one
+pub const DEFAULT_HTML_TEMPLATE: &str = "default.html";
+
+/// Render the filtered data with a minijinja template from
+/// `data/templates/html/`. The data is available as `resume`; output is
+/// HTML-escaped. Templates can `{% include %}` partials from the same folder.
+pub fn render(data: &FilteredResumeData, dir: &DataDir, wanted: Option<&str>) -> Result<String> {
+    let name = resolve(dir, wanted)?;
+
+    // Bullets are plain strings or { id, text } maps; templates only need the text
+    let mut data = data.clone();
+    for job in &mut data.experience {
+        plain_bullets(&mut job.bullets);
+    }
+    for vol in &mut data.volunteering {
+        plain_bullets(&mut vol.bullets);
+    }
+    for section in &mut data.custom_sections {
+        for entry in &mut section.entries {
+            plain_bullets(&mut entry.bullets);
+        }
+    }
+
+    let mut env = Environment::new();
+    env.set_loader(path_loader(dir.html_templates_dir()));
+    // The placement rules the text and DOCX exports use
+    env.add_function("href", |url: String| layout::href(&url));
+    env.add_function("details", |entry: ViaDeserialize<CustomEntry>| {
+        layout::details(&entry)
+            .into_iter()
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    });
+    env.add_function("skill_details", |skill: ViaDeserialize<Skill>| {
+        layout::skill_details(&skill)
+    });
+    env.get_template(&name)
+        .and_then(|template| template.render(context! { resume => data }))
+        .map_err(|e| eyre!("HTML template error: {:#}", e))
+}
+
+fn plain_bullets(bullets: &mut [Bullet]) {
+    for bullet in bullets {
+        *bullet = Bullet::Text(bullet.text().to_string());
+    }
+}
+
+// File name in templates/html/; ".html" may be left off
+fn resolve(dir: &DataDir, wanted: Option<&str>) -> Result<String> {
+    let wanted = wanted.map(str::trim).unwrap_or(DEFAULT_HTML_TEMPLATE);
+    let name = if wanted.ends_with(".html") {
+        wanted.to_string()
+    } else {
+        format!("{}.html", wanted)
+    };
+    if dir.html_templates_dir().join(&name).is_file() {
+        return Ok(name);
+    }
+
+    let mut available: Vec<String> = fs::read_dir(dir.html_templates_dir())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|file| file.ends_with(".html") && !file.starts_with('_'))
+        .collect();
+    available.sort();
+    Err(eyre!(
+        "Unknown HTML template {:?} in {}. Available: {}",
+        wanted,
+        dir.html_templates_dir().display(),
+        if available.is_empty() {
+            "none".to_string()
+        } else {
+            available.join(", ")
+        }
+    ))
+}
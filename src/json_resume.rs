@@ -0,0 +1,627 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::ResumeData;
+use crate::models::date::{DateEnd, DateFormat, DateRange, YearMonth};
+use crate::models::types::{
+    Award, Bullet, Certification, Education, Experience, JobTitle, Language, Profile, Project,
+    Publication, Skill, SkillCategory, Volunteering,
+};
+use crate::text_export::is_present;
+
+// The parts of the jsonresume.org schema (v1.0.0) this tool has a place for.
+// Unknown keys are ignored on import.
+const SCHEMA_URL: &str =
+    "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct JsonResume {
+    #[serde(rename = "$schema", skip_serializing_if = "String::is_empty")]
+    pub schema: String,
+    pub basics: Basics,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub work: Vec<Work>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub volunteer: Vec<Volunteer>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub education: Vec<Study>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub awards: Vec<JsonAward>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub certificates: Vec<Certificate>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub publications: Vec<JsonPublication>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<JsonSkill>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<JsonLanguage>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<JsonProject>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Basics {
+    pub name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub label: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub email: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub phone: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub summary: String,
+    #[serde(skip_serializing_if = "Location::is_empty")]
+    pub location: Location,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<SocialProfile>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Location {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub address: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub city: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub region: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub country_code: String,
+}
+
+impl Location {
+    fn is_empty(&self) -> bool {
+        self.to_string().is_empty()
+    }
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&join(&[
+            &self.address,
+            &self.city,
+            &self.region,
+            &self.country_code,
+        ]))
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SocialProfile {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub network: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub username: String,
+    pub url: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Work {
+    pub name: String,
+    pub position: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub location: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub start_date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub end_date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub summary: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Volunteer {
+    pub organization: String,
+    pub position: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub start_date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub end_date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub summary: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Study {
+    pub institution: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub area: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub study_type: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub start_date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub end_date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub score: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonAward {
+    pub title: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub awarder: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub summary: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Certificate {
+    pub name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub issuer: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub url: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct JsonPublication {
+    pub name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub publisher: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub release_date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub summary: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonSkill {
+    pub name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub level: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonLanguage {
+    pub language: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub fluency: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonProject {
+    pub name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub url: String,
+}
+
+// IMPORT
+
+impl JsonResume {
+    /// Map onto this tool's data files. `basics.label` and `summary` become
+    /// the only job title; an open-ended `endDate` means "present".
+    pub fn into_resume_data(self) -> ResumeData {
+        let basics = self.basics;
+        // The LinkedIn profile fills `url`; basics.url is the personal site
+        let url = basics
+            .profiles
+            .iter()
+            .find(|p| p.network.eq_ignore_ascii_case("linkedin"))
+            .or(basics.profiles.first())
+            .map(|p| p.url.clone())
+            .unwrap_or_default();
+
+        ResumeData {
+            profile: Some(Profile {
+                name: basics.name,
+                email: basics.email,
+                phone: basics.phone,
+                url,
+                website: basics.url,
+                location: basics.location.to_string(),
+                citizenship: String::new(),
+                show_email: true,
+                show_phone: true,
+            }),
+            job_titles: if basics.label.is_empty() && basics.summary.is_empty() {
+                Vec::new()
+            } else {
+                vec![JobTitle {
+                    title: basics.label,
+                    professional_summary: basics.summary,
+                }]
+            },
+            experience: self
+                .work
+                .into_iter()
+                .map(|w| {
+                    let dates = DateRange::from_strs(&w.start_date, &w.end_date, year_month);
+                    Experience {
+                        id: None,
+                        role: w.position,
+                        company: w.name,
+                        location: w.location,
+                        date: String::new(),
+                        date_text: String::new(),
+                        start: dates.start,
+                        end: dates.end,
+                        summary: w.summary,
+                        bullets: w.highlights.into_iter().map(Bullet::Text).collect(),
+                        is_visible: true,
+                        hidden_bullets: Vec::new(),
+                    }
+                })
+                .collect(),
+            education: self
+                .education
+                .into_iter()
+                .map(|s| {
+                    let dates = DateRange::from_strs(&s.start_date, &s.end_date, year_month);
+                    Education {
+                        id: None,
+                        school: s.institution,
+                        degree: [s.study_type, s.area].join(" ").trim().to_string(),
+                        status: s.score,
+                        date: String::new(),
+                        date_text: String::new(),
+                        start: dates.start,
+                        end: dates.end,
+                        is_visible: true,
+                    }
+                })
+                .collect(),
+            projects: self
+                .projects
+                .into_iter()
+                .map(|p| Project {
+                    id: None,
+                    title: p.name,
+                    url: Some(p.url).filter(|u| !u.is_empty()),
+                    description: p.description,
+                    // The templates list tech_stack as the project's bullets
+                    tech_stack: p.keywords.into_iter().chain(p.highlights).collect(),
+                    is_visible: true,
+                })
+                .collect(),
+            skills: self
+                .skills
+                .into_iter()
+                .map(|s| {
+                    let skills = if s.keywords.is_empty() {
+                        vec![Skill {
+                            id: None,
+                            name: s.name.clone(),
+                            proficiency: Some(s.level).filter(|l| !l.is_empty()),
+                            years: None,
+                        }]
+                    } else {
+                        s.keywords
+                            .into_iter()
+                            .map(|name| Skill {
+                                id: None,
+                                name,
+                                proficiency: None,
+                                years: None,
+                            })
+                            .collect()
+                    };
+                    SkillCategory {
+                        id: None,
+                        category: s.name,
+                        skills,
+                        is_visible: true,
+                        hidden_skills: Vec::new(),
+                    }
+                })
+                .collect(),
+            certifications: self
+                .certificates
+                .into_iter()
+                .map(|c| Certification {
+                    id: None,
+                    name: c.name,
+                    issuer: c.issuer,
+                    date: month(&c.date),
+                    credential_url: Some(c.url).filter(|u| !u.is_empty()),
+                    is_visible: true,
+                })
+                .collect(),
+            awards: self
+                .awards
+                .into_iter()
+                .map(|a| Award {
+                    id: None,
+                    title: a.title,
+                    issuer: a.awarder,
+                    date: month(&a.date),
+                    description: a.summary,
+                    is_visible: true,
+                })
+                .collect(),
+            publications: self
+                .publications
+                .into_iter()
+                .map(|p| Publication {
+                    id: None,
+                    title: p.name,
+                    venue: p.publisher,
+                    authors: Vec::new(),
+                    date: month(&p.release_date),
+                    doi: None,
+                    url: Some(p.url).filter(|u| !u.is_empty()),
+                    is_visible: true,
+                })
+                .collect(),
+            languages: self
+                .languages
+                .into_iter()
+                .map(|l| Language {
+                    id: None,
+                    language: l.language,
+                    proficiency: l.fluency,
+                    is_visible: true,
+                })
+                .collect(),
+            volunteering: self
+                .volunteer
+                .into_iter()
+                .map(|v| Volunteering {
+                    id: None,
+                    role: v.position,
+                    organization: v.organization,
+                    location: String::new(),
+                    // Volunteering only has a free-text date
+                    date: DateRange::from_strs(&v.start_date, &v.end_date, year_month)
+                        .format(&iso_format())
+                        .unwrap_or_default(),
+                    summary: v.summary,
+                    bullets: v.highlights.into_iter().map(Bullet::Text).collect(),
+                    is_visible: true,
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+// "2023-06-15" / "2023-06" / "2023" -> 2023-06 / 2023
+fn year_month(date: &str) -> Option<YearMonth> {
+    let date = date.trim();
+    YearMonth::parse(date.get(..7).unwrap_or(date))
+}
+
+// Free-text dates keep their month so `date_format` still applies to them
+fn month(date: &str) -> String {
+    year_month(date).map_or_else(|| date.trim().to_string(), |d| d.to_string())
+}
+
+fn start_date(range: DateRange) -> String {
+    range.start.map(|d| d.to_string()).unwrap_or_default()
+}
+
+// Ongoing entries have no end date in JSON Resume, so a single date ends
+// where it starts rather than reading back as ongoing
+fn end_date(range: DateRange) -> String {
+    match range.end {
+        Some(DateEnd::Date(d)) => d.to_string(),
+        Some(DateEnd::Present) => String::new(),
+        None => start_date(range),
+    }
+}
+
+// "2020-01 - Present", the form volunteering dates are imported in
+fn iso_format() -> DateFormat {
+    DateFormat {
+        pattern: "%Y-%m".to_string(),
+        present: "Present".to_string(),
+        separator: " - ".to_string(),
+    }
+}
+
+// Free-text volunteering dates read back as a range when both ends are
+// YYYY-MM or "present"; anything else has no structured date
+fn loose_range(text: &str) -> DateRange {
+    let (start, end) = ["\u{2013}", "\u{2014}", " - ", " to "]
+        .iter()
+        .find_map(|separator| text.split_once(separator))
+        .unwrap_or((text, text));
+    let end = if ["present", "current", "now"].contains(&end.trim().to_lowercase().as_str()) {
+        Some(DateEnd::Present)
+    } else {
+        year_month(end).map(DateEnd::Date)
+    };
+    match (year_month(start), end) {
+        (Some(start), Some(end)) => DateRange {
+            start: Some(start),
+            end: Some(end),
+        },
+        _ => DateRange::default(),
+    }
+}
+
+fn join(parts: &[&str]) -> String {
+    parts
+        .iter()
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// EXPORT
+
+/// The current selection as JSON Resume. Structured dates are written as
+/// ISO months; free-text dates only when they are "YYYY-MM" to begin with,
+/// or for volunteering a range of those.
+pub fn export(data: &ResumeData) -> String {
+    // Loose dates come out of the filter as "YYYY-MM" with this format
+    let mut data = data.clone();
+    data.config.date_format = iso_format();
+    let filtered = data.to_filtered_data();
+    let iso = |date: &str| year_month(date).map(|d| d.to_string()).unwrap_or_default();
+
+    let profile = filtered.profile;
+    let present = |value: String| {
+        if is_present(&value) {
+            value.trim().to_string()
+        } else {
+            String::new()
+        }
+    };
+    let url = present(profile.url);
+    let resume = JsonResume {
+        schema: SCHEMA_URL.to_string(),
+        basics: Basics {
+            name: profile.name,
+            label: present(filtered.job_title),
+            email: present(profile.email),
+            phone: present(profile.phone),
+            url: present(profile.website),
+            summary: present(filtered.professional_summary),
+            location: Location {
+                address: present(profile.location),
+                ..Default::default()
+            },
+            profiles: if url.is_empty() {
+                Vec::new()
+            } else {
+                vec![SocialProfile {
+                    network: if url.contains("linkedin.") {
+                        "LinkedIn".to_string()
+                    } else {
+                        String::new()
+                    },
+                    username: String::new(),
+                    url,
+                }]
+            },
+        },
+        work: filtered
+            .experience
+            .into_iter()
+            .map(|e| Work {
+                start_date: start_date(e.dates()),
+                end_date: end_date(e.dates()),
+                name: e.company,
+                position: e.role,
+                location: e.location,
+                url: String::new(),
+                summary: e.summary,
+                highlights: e.bullets.iter().map(|b| b.text().to_string()).collect(),
+            })
+            .collect(),
+        volunteer: filtered
+            .volunteering
+            .into_iter()
+            .map(|v| Volunteer {
+                start_date: start_date(loose_range(&v.date)),
+                end_date: end_date(loose_range(&v.date)),
+                organization: v.organization,
+                position: v.role,
+                summary: v.summary,
+                highlights: v.bullets.iter().map(|b| b.text().to_string()).collect(),
+            })
+            .collect(),
+        education: filtered
+            .education
+            .into_iter()
+            .map(|e| Study {
+                start_date: start_date(e.dates()),
+                end_date: end_date(e.dates()),
+                institution: e.school,
+                area: e.degree,
+                study_type: String::new(),
+                score: e.status,
+            })
+            .collect(),
+        awards: filtered
+            .awards
+            .into_iter()
+            .map(|a| JsonAward {
+                date: iso(&a.date),
+                title: a.title,
+                awarder: a.issuer,
+                summary: a.description,
+            })
+            .collect(),
+        certificates: filtered
+            .certifications
+            .into_iter()
+            .map(|c| Certificate {
+                date: iso(&c.date),
+                name: c.name,
+                issuer: c.issuer,
+                url: c.credential_url.unwrap_or_default(),
+            })
+            .collect(),
+        publications: filtered
+            .publications
+            .into_iter()
+            .map(|p| JsonPublication {
+                release_date: iso(&p.date),
+                name: p.title,
+                publisher: p.venue,
+                url: p.url.unwrap_or_default(),
+                // JSON Resume has no authors field, and summary is prose that
+                // import can't turn back into a list
+                summary: String::new(),
+            })
+            .collect(),
+        skills: filtered
+            .skills
+            .into_iter()
+            .map(|c| JsonSkill {
+                name: c.category,
+                level: String::new(),
+                keywords: c.skills.into_iter().map(|s| s.name).collect(),
+            })
+            .collect(),
+        languages: filtered
+            .languages
+            .into_iter()
+            .map(|l| JsonLanguage {
+                language: l.language,
+                fluency: l.proficiency,
+            })
+            .collect(),
+        projects: filtered
+            .projects
+            .into_iter()
+            .map(|p| JsonProject {
+                name: p.title,
+                description: p.description,
+                highlights: p.tech_stack,
+                keywords: Vec::new(),
+                url: p.url.unwrap_or_default(),
+            })
+            .collect(),
+    };
+
+    let mut json = serde_json::to_string_pretty(&resume).expect("JSON Resume serializes");
+    json.push('\n');
+    json
+}
@@ -0,0 +1,48 @@
+use crate::models::types::{CustomEntry, Skill};
+use crate::text_export::present;
+
+// Field placement shared by the text, DOCX and HTML exports, so they agree
+// with each other and with custom_entry in the Typst template. HTML templates
+// call these as href(), details() and skill_details().
+
+/// Custom entry fields with a place of their own; the rest are details
+pub const RESERVED_FIELDS: [&str; 5] = ["title", "name", "date", "url", "description"];
+
+/// Values of a custom entry's other fields, in file order
+pub fn details(entry: &CustomEntry) -> Vec<&str> {
+    entry
+        .fields
+        .iter()
+        .filter(|(key, value)| !RESERVED_FIELDS.contains(&key.as_str()) && !value.trim().is_empty())
+        .map(|(_, value)| value.as_str())
+        .collect()
+}
+
+/// "Advanced, 3 yrs" or "1 yr"; empty when neither is given
+pub fn skill_details(skill: &Skill) -> String {
+    let years = skill
+        .years
+        .map(|y| format!("{} {}", y, if y == 1 { "yr" } else { "yrs" }))
+        .unwrap_or_default();
+    present(&[skill.proficiency.as_deref().unwrap_or_default(), &years]).join(", ")
+}
+
+/// "Rust (Advanced, 3 yrs)"
+pub fn skill_line(skill: &Skill) -> String {
+    let details = skill_details(skill);
+    if details.is_empty() {
+        skill.name.clone()
+    } else {
+        format!("{} ({})", skill.name, details)
+    }
+}
+
+/// `url` as a link target: "jane.dev" becomes "https://jane.dev", while
+/// "mailto:jane@x.dev" and anything with a scheme are returned as they are
+pub fn href(url: &str) -> String {
+    if url.contains("://") || url.starts_with("mailto:") {
+        url.to_string()
+    } else {
+        format!("https://{}", url)
+    }
+}
@@ -0,0 +1,470 @@
+use color_eyre::{Result, eyre::eyre};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+use crate::models::ResumeData;
+use crate::models::date::{DateRange, MONTHS, YearMonth};
+use crate::models::types::{Bullet, Education, Experience, Profile, Project, slugify};
+
+// The CSVs we read from a "Download your data" export
+const KNOWN_FILES: [&str; 4] = [
+    "Profile.csv",
+    "Positions.csv",
+    "Education.csv",
+    "Projects.csv",
+];
+
+// One CSV row, keyed by column header
+type Row = BTreeMap<String, String>;
+
+/// The CSVs of a LinkedIn data export, read from the zip or an unpacked folder
+pub struct Archive {
+    // Base file name -> contents
+    files: BTreeMap<String, String>,
+}
+
+impl Archive {
+    pub fn open(path: &Path) -> Result<Self> {
+        let mut files = BTreeMap::new();
+        if path.is_dir() {
+            for entry in fs::read_dir(path)? {
+                let path = entry?.path();
+                if let Some(name) = csv_name(&path.to_string_lossy()) {
+                    files.insert(name, fs::read_to_string(&path)?);
+                }
+            }
+        } else {
+            let file = fs::File::open(path)
+                .map_err(|e| eyre!("Could not read {}: {}", path.display(), e))?;
+            let mut zip = zip::ZipArchive::new(file)
+                .map_err(|e| eyre!("{} is not a zip archive: {}", path.display(), e))?;
+            for i in 0..zip.len() {
+                let mut entry = zip.by_index(i)?;
+                // Newer exports nest the CSVs in a folder
+                if let Some(name) = csv_name(&entry.name()?) {
+                    let mut content = String::new();
+                    entry.read_to_string(&mut content)?;
+                    files.insert(name, content);
+                }
+            }
+        }
+
+        if !KNOWN_FILES.iter().any(|name| files.contains_key(*name)) {
+            return Err(eyre!(
+                "{} has none of {}; is it a LinkedIn data export?",
+                path.display(),
+                KNOWN_FILES.join(", ")
+            ));
+        }
+        Ok(Self { files })
+    }
+
+    // A missing file is an empty table; LinkedIn leaves out empty sections
+    fn rows(&self, name: &str) -> Result<Vec<Row>> {
+        let Some(content) = self.files.get(name) else {
+            return Ok(Vec::new());
+        };
+        let content = content.trim_start_matches('\u{feff}');
+        let mut reader = csv::ReaderBuilder::new()
+            .flexible(true)
+            .from_reader(content.as_bytes());
+        let headers: Vec<String> = reader
+            .headers()?
+            .iter()
+            .map(|h| h.trim().to_string())
+            .collect();
+        let mut rows = Vec::new();
+        for record in reader.records() {
+            let record = record.map_err(|e| eyre!("{}: {}", name, e))?;
+            rows.push(
+                headers
+                    .iter()
+                    .cloned()
+                    .zip(record.iter().map(|v| v.trim().to_string()))
+                    .collect(),
+            );
+        }
+        Ok(rows)
+    }
+
+    /// Map the archive onto this tool's data. Only profile, experience,
+    /// education and projects are filled; everything else stays empty.
+    pub fn into_resume_data(self) -> Result<ResumeData> {
+        let profile = self.rows("Profile.csv")?.into_iter().next().map(|row| {
+            let email = self.rows("Email Addresses.csv").unwrap_or_default();
+            let email = email
+                .iter()
+                .find(|r| field(r, "Primary").eq_ignore_ascii_case("yes"))
+                .or(email.first());
+            let phone = self.rows("PhoneNumbers.csv").unwrap_or_default();
+            Profile {
+                name: format!("{} {}", field(&row, "First Name"), field(&row, "Last Name"))
+                    .trim()
+                    .to_string(),
+                email: email
+                    .map(|r| field(r, "Email Address").to_string())
+                    .unwrap_or_default(),
+                phone: phone
+                    .first()
+                    .map(|r| field(r, "Number").to_string())
+                    .unwrap_or_default(),
+                url: String::new(),
+                website: website(field(&row, "Websites")),
+                location: field(&row, "Geo Location").to_string(),
+                citizenship: String::new(),
+                show_email: true,
+                show_phone: true,
+            }
+        });
+
+        let experience = self
+            .rows("Positions.csv")?
+            .iter()
+            .map(|row| {
+                let dates = DateRange::from_strs(
+                    field(row, "Started On"),
+                    field(row, "Finished On"),
+                    year_month,
+                );
+                let (summary, bullets) = split_description(field(row, "Description"));
+                Experience {
+                    id: None,
+                    role: field(row, "Title").to_string(),
+                    company: field(row, "Company Name").to_string(),
+                    location: field(row, "Location").to_string(),
+                    date: String::new(),
+                    date_text: String::new(),
+                    start: dates.start,
+                    end: dates.end,
+                    summary,
+                    bullets: bullets.into_iter().map(Bullet::Text).collect(),
+                    is_visible: true,
+                    hidden_bullets: Vec::new(),
+                }
+            })
+            .collect();
+
+        let education = self
+            .rows("Education.csv")?
+            .iter()
+            .map(|row| {
+                let dates = DateRange::from_strs(
+                    field(row, "Start Date"),
+                    field(row, "End Date"),
+                    year_month,
+                );
+                Education {
+                    id: None,
+                    school: field(row, "School Name").to_string(),
+                    degree: field(row, "Degree Name").to_string(),
+                    status: String::new(),
+                    date: String::new(),
+                    date_text: String::new(),
+                    start: dates.start,
+                    end: dates.end,
+                    is_visible: true,
+                }
+            })
+            .collect();
+
+        let projects = self
+            .rows("Projects.csv")?
+            .iter()
+            .map(|row| Project {
+                id: None,
+                title: field(row, "Title").to_string(),
+                url: Some(field(row, "Url").to_string()).filter(|url| !url.is_empty()),
+                description: field(row, "Description").to_string(),
+                tech_stack: Vec::new(),
+                is_visible: true,
+            })
+            .collect();
+
+        Ok(ResumeData {
+            profile,
+            experience,
+            education,
+            projects,
+            ..Default::default()
+        })
+    }
+}
+
+// "Basic_LinkedInDataExport/Positions.csv" -> "Positions.csv"
+fn csv_name(path: &str) -> Option<String> {
+    let name = path.rsplit(['/', '\\']).next()?;
+    name.ends_with(".csv").then(|| name.to_string())
+}
+
+fn field<'a>(row: &'a Row, column: &str) -> &'a str {
+    row.get(column).map_or("", String::as_str)
+}
+
+// "[PORTFOLIO:https://jane.dev,OTHER:https://blog.jane.dev]" -> "https://jane.dev"
+fn website(websites: &str) -> String {
+    websites
+        .split([',', '[', ']'])
+        .filter_map(|site| site.find("http").map(|start| site[start..].trim()))
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+// "Jun 2023" / "2023" -> 2023-06 / 2023
+fn year_month(date: &str) -> Option<YearMonth> {
+    let date = date.trim();
+    if let Some(date) = YearMonth::parse(date) {
+        return Some(date);
+    }
+    let (month, year) = date.split_once(' ')?;
+    let month = MONTHS.iter().position(|name| {
+        month
+            .get(..3)
+            .is_some_and(|m| name[..3].eq_ignore_ascii_case(m))
+    })?;
+    Some(YearMonth {
+        year: year.trim().parse().ok()?,
+        month: Some(month as u8 + 1),
+    })
+}
+
+const MARKERS: [char; 9] = ['•', '-', '*', '–', '—', '·', '▪', '◦', '‣'];
+
+/// Split a free-text description into a summary and bullets. Lines with a
+/// bullet marker ("•", "-", "1.") are bullets and text before the first one
+/// is the summary. Without markers every line is a bullet, and a single
+/// paragraph is split into sentences; one sentence stays the summary.
+pub fn split_description(text: &str) -> (String, Vec<String>) {
+    // Bullets pasted inline: "Did this • Did that"
+    let text = text.replace('•', "\n•");
+    let lines: Vec<&str> = text
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect();
+
+    let mut summary = Vec::new();
+    let mut bullets = Vec::new();
+    for line in &lines {
+        match strip_marker(line) {
+            Some(bullet) => bullets.push(bullet.to_string()),
+            None if bullets.is_empty() => summary.push(*line),
+            // Text after the list continues it as its own point
+            None => bullets.push(line.to_string()),
+        }
+    }
+    if !bullets.is_empty() {
+        return (summary.join(" "), bullets);
+    }
+
+    match lines.as_slice() {
+        [] => (String::new(), Vec::new()),
+        [paragraph] => {
+            let sentences = sentences(paragraph);
+            if sentences.len() > 1 {
+                (String::new(), sentences)
+            } else {
+                (paragraph.to_string(), Vec::new())
+            }
+        }
+        lines => (String::new(), lines.iter().map(|l| l.to_string()).collect()),
+    }
+}
+
+// "- text" / "• text" / "1. text" / "2) text" -> "text"
+fn strip_marker(line: &str) -> Option<&str> {
+    let rest = match line.strip_prefix(MARKERS) {
+        Some(rest) => rest,
+        None => {
+            let digits = line.trim_start_matches(|c: char| c.is_ascii_digit());
+            if digits.len() == line.len() {
+                return None;
+            }
+            digits.strip_prefix(['.', ')'])?
+        }
+    };
+    // "-5% churn" is not a bullet, "- 5% churn" is
+    let text = rest.trim_start();
+    (text.len() < rest.len() && !text.is_empty()).then_some(text)
+}
+
+// Break after ". ", "! " or "? " when the next word is capitalised. Words
+// with an inner dot ("e.g.", "U.S.") do not end a sentence.
+fn sentences(paragraph: &str) -> Vec<String> {
+    let mut sentences = Vec::new();
+    let mut current = String::new();
+    let mut words = paragraph.split_whitespace().peekable();
+    while let Some(word) = words.next() {
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+        let ends = word.ends_with(['.', '!', '?'])
+            && !word[..word.len() - 1].contains('.')
+            && words
+                .peek()
+                .is_some_and(|next| next.starts_with(|c: char| c.is_uppercase()));
+        if ends {
+            sentences.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        sentences.push(current);
+    }
+    sentences
+}
+
+// MERGE
+
+/// One line of the preview shown before anything is written
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub file: &'static str,
+    pub description: String,
+}
+
+/// Merge imported entries into `data` without overwriting anything: new
+/// entries are appended, and entries that already exist (same role and
+/// company, school and degree, or project title) only get their empty
+/// fields and missing bullets filled in.
+pub fn merge(data: &mut ResumeData, imported: ResumeData) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    if let Some(new) = imported.profile {
+        match data.profile {
+            None => {
+                changes.push(Change {
+                    file: "profile.yaml",
+                    description: format!("+ {}", new.name),
+                });
+                data.profile = Some(new);
+            }
+            Some(ref mut profile) => {
+                let mut filled = Vec::new();
+                fill(&mut profile.name, new.name, "name", &mut filled);
+                fill(&mut profile.email, new.email, "email", &mut filled);
+                fill(&mut profile.phone, new.phone, "phone", &mut filled);
+                fill(&mut profile.website, new.website, "website", &mut filled);
+                fill(&mut profile.location, new.location, "location", &mut filled);
+                if !filled.is_empty() {
+                    changes.push(Change {
+                        file: "profile.yaml",
+                        description: format!("~ {}: {}", profile.name, filled.join(", ")),
+                    });
+                }
+            }
+        }
+    }
+
+    for new in imported.experience {
+        let heading = format!("{} at {}", new.role, new.company);
+        let key = slugify(&[&new.role, &new.company]);
+        let Some(job) = data
+            .experience
+            .iter_mut()
+            .find(|e| slugify(&[&e.role, &e.company]) == key)
+        else {
+            changes.push(Change {
+                file: "experience.yaml",
+                description: format!("+ {} ({} bullets)", heading, new.bullets.len()),
+            });
+            data.experience.push(new);
+            continue;
+        };
+
+        let mut filled = Vec::new();
+        if job.dates().is_empty() && job.date.is_empty() && !new.dates().is_empty() {
+            (job.start, job.end) = (new.start, new.end);
+            filled.push("dates".to_string());
+        }
+        fill(&mut job.location, new.location, "location", &mut filled);
+        fill(&mut job.summary, new.summary, "summary", &mut filled);
+        let known: Vec<String> = job.bullets.iter().map(|b| slugify(&[b.text()])).collect();
+        let before = job.bullets.len();
+        job.bullets.extend(
+            new.bullets
+                .into_iter()
+                .filter(|b| !known.contains(&slugify(&[b.text()]))),
+        );
+        match job.bullets.len() - before {
+            0 => {}
+            1 => filled.push("1 new bullet".to_string()),
+            n => filled.push(format!("{} new bullets", n)),
+        }
+        if !filled.is_empty() {
+            changes.push(Change {
+                file: "experience.yaml",
+                description: format!("~ {}: {}", heading, filled.join(", ")),
+            });
+        }
+    }
+
+    for new in imported.education {
+        let heading = format!("{} at {}", new.degree, new.school);
+        let key = slugify(&[&new.school, &new.degree]);
+        let Some(edu) = data
+            .education
+            .iter_mut()
+            .find(|e| slugify(&[&e.school, &e.degree]) == key)
+        else {
+            changes.push(Change {
+                file: "education.yaml",
+                description: format!("+ {}", heading),
+            });
+            data.education.push(new);
+            continue;
+        };
+        if edu.dates().is_empty() && edu.date.is_empty() && !new.dates().is_empty() {
+            (edu.start, edu.end) = (new.start, new.end);
+            changes.push(Change {
+                file: "education.yaml",
+                description: format!("~ {}: dates", heading),
+            });
+        }
+    }
+
+    for new in imported.projects {
+        let key = slugify(&[&new.title]);
+        let Some(project) = data
+            .projects
+            .iter_mut()
+            .find(|p| slugify(&[&p.title]) == key)
+        else {
+            changes.push(Change {
+                file: "projects.yaml",
+                description: format!("+ {}", new.title),
+            });
+            data.projects.push(new);
+            continue;
+        };
+        let mut filled = Vec::new();
+        fill(
+            &mut project.description,
+            new.description,
+            "description",
+            &mut filled,
+        );
+        if project.url.is_none() && new.url.is_some() {
+            project.url = new.url;
+            filled.push("url".to_string());
+        }
+        if !filled.is_empty() {
+            changes.push(Change {
+                file: "projects.yaml",
+                description: format!("~ {}: {}", project.title, filled.join(", ")),
+            });
+        }
+    }
+
+    changes
+}
+
+// Only empty fields are filled; what the user wrote always wins
+fn fill(target: &mut String, value: String, label: &str, filled: &mut Vec<String>) {
+    if target.trim().is_empty() && !value.trim().is_empty() {
+        *target = value;
+        filled.push(label.to_string());
+    }
+}
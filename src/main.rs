@@ -1,37 +1,64 @@
+use clap::Parser;
 use color_eyre::Result;
 use crossterm::event::{self, Event, KeyEventKind};
 use ratatui::DefaultTerminal;
+use std::process::ExitCode;
+use std::time::Duration;
 
 mod app;
+mod cli;
+mod data_dir;
+mod docx;
+mod html;
+mod json_resume;
+mod layout;
+mod linkedin;
 mod models;
+mod page_budget;
 mod pdf;
+mod preview;
+mod templates;
+mod text_export;
 mod typst_backend;
 mod ui;
 
 use app::{App, CurrentScreen};
+use cli::Cli;
+use data_dir::DataDir;
 use ui::render_ui;
 
 // ENTRY POINT
-fn main() -> Result<()> {
+fn main() -> Result<ExitCode> {
     color_eyre::install()?;
+    let cli = Cli::parse();
+    let data_dir = DataDir::resolve(cli.data_dir);
+
+    // Headless subcommands never touch the terminal
+    if let Some(command) = cli.command {
+        return cli::run(command, &data_dir);
+    }
+
     let mut terminal = ratatui::init();
-    let app_result = run(&mut terminal);
+    let app_result = run(&mut terminal, data_dir);
     ratatui::restore();
-    app_result
+    app_result.map(|()| ExitCode::SUCCESS)
 }
 
 // APPLICATION LOOP
-fn run(terminal: &mut DefaultTerminal) -> Result<()> {
-    let mut app = App::new();
+fn run(terminal: &mut DefaultTerminal, data_dir: DataDir) -> Result<()> {
+    let mut app = App::new(data_dir);
 
     while app.current_screen != CurrentScreen::Exiting {
         terminal.draw(|frame| render_ui(frame, &mut app))?;
 
-        if let Event::Key(key) = event::read()? {
-            if key.kind == KeyEventKind::Press {
-                app.handle_key_event(key.code);
-            }
+        // Wake up regularly so background compiles show up without a key press
+        if event::poll(Duration::from_millis(100))?
+            && let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            app.handle_key_event(key.code);
         }
+        app.poll_preview();
     }
     Ok(())
 }
@@ -0,0 +1,35 @@
+use super::date::DateFormat;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// Optional data/config.yaml; every setting has a default
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub date_format: DateFormat,
+    // Sort education and experience newest-first when loading
+    pub sort_by_date: bool,
+    // Also offer the system fonts to templates, not just data/fonts/
+    pub system_fonts: bool,
+    // Page budget; None means any length is fine
+    pub max_pages: Option<usize>,
+    // What a headless build does when the budget is exceeded
+    pub on_page_overflow: PageOverflow,
+    // Resolution of PNG exports; None means 144
+    pub png_dpi: Option<f32>,
+    // Line width of text and Markdown exports; None means 80
+    pub text_width: Option<usize>,
+    // File in templates/html/ used for HTML exports; None means default.html
+    pub html_template: Option<String>,
+    // Data directory this one builds on, relative to it. Its files are read
+    // first; this directory's fields and entries override them.
+    pub extends: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PageOverflow {
+    #[default]
+    Fail,
+    Warn,
+}
@@ -0,0 +1,335 @@
+use schemars::JsonSchema;
+use schemars::r#gen::SchemaGenerator;
+use schemars::schema::Schema;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+// A "2023-06" or "2023" date. Written as a string or a bare year in YAML.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "RawDate", into = "RawDate")]
+pub struct YearMonth {
+    pub year: i32,
+    // 1-12; None for year-only dates
+    pub month: Option<u8>,
+}
+
+// End of a range: a date, or "present" for ongoing entries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawDate", into = "RawDate")]
+pub enum DateEnd {
+    Date(YearMonth),
+    Present,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawDate {
+    Year(i32),
+    Text(String),
+}
+
+// Year-only dates are written back as bare years, the way they are typed
+impl From<YearMonth> for RawDate {
+    fn from(date: YearMonth) -> Self {
+        match date.month {
+            Some(_) => Self::Text(date.to_string()),
+            None => Self::Year(date.year),
+        }
+    }
+}
+
+impl From<DateEnd> for RawDate {
+    fn from(end: DateEnd) -> Self {
+        match end {
+            DateEnd::Date(date) => date.into(),
+            DateEnd::Present => Self::Text(end.to_string()),
+        }
+    }
+}
+
+impl YearMonth {
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.trim().split('-');
+        let year = parts.next()?.parse().ok()?;
+        let month = match parts.next() {
+            Some(m) => Some(m.parse::<u8>().ok().filter(|m| (1..=12).contains(m))?),
+            None => None,
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self { year, month })
+    }
+
+    /// The current month in UTC
+    pub fn today() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        // Civil-from-days (H. Hinnant), enough for a year and month
+        let z = (secs / 86_400) as i64 + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year as i32,
+            month: Some(month as u8),
+        }
+    }
+
+    // Months since year 0; year-only dates count as January
+    fn index(self) -> i32 {
+        self.year * 12 + i32::from(self.month.unwrap_or(1)) - 1
+    }
+
+    pub fn format(self, format: &DateFormat) -> String {
+        let Some(month) = self.month else {
+            return self.year.to_string();
+        };
+        let name = MONTHS[usize::from(month) - 1];
+        let mut out = String::new();
+        let mut chars = format.pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                out.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('Y') => out.push_str(&self.year.to_string()),
+                Some('m') => out.push_str(&format!("{:02}", month)),
+                Some('b') => out.push_str(&name[..3]),
+                Some('B') => out.push_str(name),
+                Some(other) => {
+                    out.push('%');
+                    out.push(other);
+                }
+                None => out.push('%'),
+            }
+        }
+        out
+    }
+}
+
+impl fmt::Display for YearMonth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.month {
+            Some(month) => write!(f, "{}-{:02}", self.year, month),
+            None => write!(f, "{}", self.year),
+        }
+    }
+}
+
+impl From<YearMonth> for String {
+    fn from(date: YearMonth) -> Self {
+        date.to_string()
+    }
+}
+
+impl TryFrom<RawDate> for YearMonth {
+    type Error = String;
+
+    fn try_from(raw: RawDate) -> Result<Self, Self::Error> {
+        match raw {
+            RawDate::Year(year) => Ok(Self { year, month: None }),
+            RawDate::Text(text) => Self::parse(&text)
+                .ok_or_else(|| format!("invalid date {:?}, expected YYYY-MM or YYYY", text)),
+        }
+    }
+}
+
+// Hand-written schemas: both types are strings (or bare years) in YAML
+impl JsonSchema for YearMonth {
+    fn schema_name() -> String {
+        "YearMonth".to_string()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        serde_json::from_value(serde_json::json!({
+            "description": "A date as YYYY-MM or YYYY",
+            "anyOf": [
+                { "type": "string", "pattern": "^[0-9]{4}(-(0[1-9]|1[0-2]))?$" },
+                { "type": "integer" }
+            ]
+        }))
+        .expect("valid schema")
+    }
+}
+
+impl JsonSchema for DateEnd {
+    fn schema_name() -> String {
+        "DateEnd".to_string()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        serde_json::from_value(serde_json::json!({
+            "description": "A date as YYYY-MM or YYYY, or \"present\" for ongoing entries",
+            "anyOf": [
+                { "type": "string", "enum": ["present", "current", "now"] },
+                generator.subschema_for::<YearMonth>()
+            ]
+        }))
+        .expect("valid schema")
+    }
+}
+
+impl DateEnd {
+    fn resolve(self, today: YearMonth) -> YearMonth {
+        match self {
+            DateEnd::Date(date) => date,
+            DateEnd::Present => today,
+        }
+    }
+}
+
+impl fmt::Display for DateEnd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateEnd::Date(date) => date.fmt(f),
+            DateEnd::Present => f.write_str("present"),
+        }
+    }
+}
+
+impl From<DateEnd> for String {
+    fn from(end: DateEnd) -> Self {
+        end.to_string()
+    }
+}
+
+impl TryFrom<RawDate> for DateEnd {
+    type Error = String;
+
+    fn try_from(raw: RawDate) -> Result<Self, Self::Error> {
+        match raw {
+            RawDate::Text(text)
+                if ["present", "current", "now"].contains(&text.trim().to_lowercase().as_str()) =>
+            {
+                Ok(DateEnd::Present)
+            }
+            raw => YearMonth::try_from(raw).map(DateEnd::Date),
+        }
+    }
+}
+
+// How dates are written in the output, set by `date_format` in config.yaml
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct DateFormat {
+    // %Y year, %m month number, %b "Jun", %B "June"
+    pub pattern: String,
+    pub present: String,
+    pub separator: String,
+}
+
+impl Default for DateFormat {
+    fn default() -> Self {
+        Self {
+            pattern: "%b %Y".to_string(),
+            present: "Present".to_string(),
+            separator: " \u{2013} ".to_string(),
+        }
+    }
+}
+
+// Structured start/end of an Experience or Education entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DateRange {
+    pub start: Option<YearMonth>,
+    pub end: Option<DateEnd>,
+}
+
+impl DateRange {
+    /// Dates imported from another format, read with that format's `parse`.
+    /// A start without an end is ongoing, as LinkedIn and JSON Resume write
+    /// current positions.
+    pub fn from_strs(start: &str, end: &str, parse: impl Fn(&str) -> Option<YearMonth>) -> Self {
+        let start = parse(start);
+        let end = match parse(end) {
+            Some(date) => Some(DateEnd::Date(date)),
+            None if start.is_some() && end.trim().is_empty() => Some(DateEnd::Present),
+            None => None,
+        };
+        Self { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start.is_none() && self.end.is_none()
+    }
+
+    /// "Jun 2023 – Present"; None when no structured date is set
+    pub fn format(&self, format: &DateFormat) -> Option<String> {
+        let end = self.end.map(|end| match end {
+            DateEnd::Date(date) => date.format(format),
+            DateEnd::Present => format.present.clone(),
+        });
+        match (self.start.map(|s| s.format(format)), end) {
+            (Some(start), Some(end)) if start == end => Some(start),
+            (Some(start), Some(end)) => Some(format!("{}{}{}", start, format.separator, end)),
+            (Some(start), None) => Some(start),
+            (None, end) => end,
+        }
+    }
+
+    /// Whole months from start to end (inclusive), ongoing entries end `today`
+    pub fn months(&self, today: YearMonth) -> Option<i32> {
+        let start = self.start?;
+        let end = self.end?.resolve(today);
+        Some((end.index() - start.index() + 1).max(0))
+    }
+
+    /// "2 yrs 3 mos"
+    pub fn duration(&self, today: YearMonth) -> Option<String> {
+        let months = self.months(today)?;
+        let plural =
+            |n: i32, unit: &str| format!("{} {}{}", n, unit, if n == 1 { "" } else { "s" });
+        Some(match (months / 12, months % 12) {
+            (0, m) => plural(m, "mo"),
+            (y, 0) => plural(y, "yr"),
+            (y, m) => format!("{} {}", plural(y, "yr"), plural(m, "mo")),
+        })
+    }
+
+    /// Reverse-chronological order: ongoing first, then by end and start.
+    /// Entries without structured dates sort last.
+    pub fn newest_first(&self, other: &Self, today: YearMonth) -> Ordering {
+        let key = |r: &Self| {
+            let end = r.end.map(|e| e.resolve(today)).or(r.start)?;
+            Some((end, r.start))
+        };
+        match (key(self), key(other)) {
+            (Some(a), Some(b)) => b.cmp(&a),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+/// Reformat a free-text date when it is in YYYY-MM form; anything else is kept
+pub fn format_loose(text: &str, format: &DateFormat) -> String {
+    match YearMonth::parse(text) {
+        Some(date) => date.format(format),
+        None => text.to_string(),
+    }
+}
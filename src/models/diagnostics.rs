@@ -0,0 +1,246 @@
+use super::format::{self, Step};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+// A problem found while loading or checking the data files
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    // Path as shown to the user, e.g. data/experience.yaml
+    pub file: PathBuf,
+    // 1-based line and column, when known
+    pub location: Option<(usize, usize)>,
+    pub message: String,
+    // The offending source line
+    pub snippet: Option<String>,
+    // Extra lines such as "hint: ..." shown below the excerpt
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn error(file: impl Into<PathBuf>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            file: file.into(),
+            location: None,
+            message: message.into(),
+            snippet: None,
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn warning(file: impl Into<PathBuf>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            ..Self::error(file, message)
+        }
+    }
+
+    /// Attach a position and the matching line of `source`
+    pub fn at(mut self, source: &str, line: usize, column: usize) -> Self {
+        self.location = Some((line, column));
+        self.snippet = source
+            .lines()
+            .nth(line.saturating_sub(1))
+            .map(str::to_string);
+        self
+    }
+
+    /// Build from a serde_yaml error, keeping its position when it has one
+    pub fn from_yaml(file: impl Into<PathBuf>, source: &str, err: &serde_yaml::Error) -> Self {
+        // serde_yaml appends " at line X column Y"; we show the location separately
+        let mut message = err.to_string();
+        if let Some(pos) = message.find(" at line ") {
+            message.truncate(pos);
+        }
+        let diagnostic = Self::error(file, message);
+        match err.location() {
+            Some(loc) => diagnostic.at(source, loc.line(), loc.column()),
+            None => diagnostic,
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{}", self.file.display())?;
+        if let Some((line, column)) = self.location {
+            write!(f, ":{}:{}", line, column)?;
+        }
+        write!(f, ": {}: {}", severity, self.message)?;
+
+        // rustc-style excerpt:
+        //   12 |   - role: ""
+        //      |     ^
+        //      = hint: ...
+        let mut gutter = 0;
+        if let (Some((line, column)), Some(snippet)) = (self.location, &self.snippet) {
+            gutter = line.to_string().len();
+            write!(f, "\n{} | {}", line, snippet)?;
+            write!(
+                f,
+                "\n{} | {}^",
+                " ".repeat(gutter),
+                " ".repeat(column.saturating_sub(1))
+            )?;
+        }
+        for note in &self.notes {
+            write!(f, "\n{} = {}", " ".repeat(gutter), note)?;
+        }
+        Ok(())
+    }
+}
+
+/// "1 error, 2 warnings"
+pub fn summary(diagnostics: &[Diagnostic]) -> String {
+    let errors = diagnostics.iter().filter(|d| d.is_error()).count();
+    let warnings = diagnostics.len() - errors;
+    let plural = |n: usize, word: &str| format!("{} {}{}", n, word, if n == 1 { "" } else { "s" });
+    format!(
+        "{}, {}",
+        plural(errors, "error"),
+        plural(warnings, "warning")
+    )
+}
+
+// Where a loaded entry was read from: its file and the steps to it there
+#[derive(Debug, Clone, PartialEq)]
+pub struct Origin {
+    pub file: PathBuf,
+    pub steps: Vec<Step>,
+}
+
+// Contents of the files read by the loader, keyed by the path shown to the user,
+// and the origin of each entry of a list file, in load order, keyed by the
+// file name without extension (experience, sections/talks).
+// Lints use them to point at the line an entry came from.
+#[derive(Debug, Default)]
+pub struct Sources {
+    files: BTreeMap<PathBuf, String>,
+    origins: BTreeMap<String, Vec<Origin>>,
+}
+
+impl Sources {
+    pub fn insert(&mut self, file: PathBuf, content: String) {
+        self.files.insert(file, content);
+    }
+
+    /// Every file read, with its contents
+    pub fn files(&self) -> impl Iterator<Item = (&PathBuf, &String)> {
+        self.files.iter()
+    }
+
+    pub fn set_origins(&mut self, name: &str, origins: Vec<Origin>) {
+        self.origins.insert(name.to_string(), origins);
+    }
+
+    /// Origin of entry `index` of the list file `name`
+    pub fn origin(&self, name: &str, index: usize) -> Option<&Origin> {
+        self.origins.get(name)?.get(index)
+    }
+
+    /// Move the diagnostic to the file of `origin` and position it at what
+    /// `steps` lead to inside that entry, or at the entry itself when the
+    /// field isn't written out
+    pub fn locate_at(
+        &self,
+        mut diagnostic: Diagnostic,
+        origin: &Origin,
+        steps: &[Step],
+    ) -> Diagnostic {
+        diagnostic.file = origin.file.clone();
+        let Some(source) = self.files.get(&origin.file) else {
+            return diagnostic;
+        };
+        let full: Vec<Step> = origin.steps.iter().chain(steps).cloned().collect();
+        match format::position(&origin.file, source, &full)
+            .or_else(|| format::position(&origin.file, source, &origin.steps))
+        {
+            Some((line, column)) => diagnostic.at(source, line, column),
+            None => diagnostic,
+        }
+    }
+
+    /// Position the diagnostic at the `nth` (0-based) occurrence of `needle`
+    /// in its file, if it can be found
+    pub fn locate(&self, diagnostic: Diagnostic, needle: &str, nth: usize) -> Diagnostic {
+        let Some(source) = self.files.get(&diagnostic.file) else {
+            return diagnostic;
+        };
+        if needle.is_empty() {
+            return diagnostic;
+        }
+        let Some(&offset) = matches(source, needle).get(nth) else {
+            return diagnostic;
+        };
+        let (line, column) = format::line_column(source, offset);
+        diagnostic.at(source, line, column)
+    }
+
+    /// `locate` for entries that may come from several files: each place is
+    /// a file and the folder it can be split into (experience.yaml,
+    /// experience/*.yaml). Occurrences are counted across them in load order
+    /// and the diagnostic moves to the file the `nth` one is in.
+    pub fn locate_across(
+        &self,
+        mut diagnostic: Diagnostic,
+        places: &[(PathBuf, PathBuf)],
+        needle: &str,
+        mut nth: usize,
+    ) -> Diagnostic {
+        if needle.is_empty() {
+            return diagnostic;
+        }
+        let files = places.iter().flat_map(|(file, folder)| {
+            std::iter::once(file).chain(
+                self.files
+                    .keys()
+                    .filter(move |path| path.parent() == Some(folder.as_path())),
+            )
+        });
+        for file in files {
+            let Some(source) = self.files.get(file) else {
+                continue;
+            };
+            let count = matches(source, needle).len();
+            if nth < count {
+                diagnostic.file = file.clone();
+                return self.locate(diagnostic, needle, nth);
+            }
+            nth -= count;
+        }
+        diagnostic
+    }
+}
+
+// Offsets of `needle`, preferring those that start a value ("key: ", "- ",
+// TOML's "key = ") so "example.com" finds the website rather than the email
+fn matches(source: &str, needle: &str) -> Vec<usize> {
+    let all: Vec<usize> = source.match_indices(needle).map(|(i, _)| i).collect();
+    let values: Vec<usize> = all
+        .iter()
+        .copied()
+        .filter(|&i| {
+            let before = source[..i]
+                .trim_end_matches(['"', '\''])
+                .trim_end_matches(' ');
+            before.ends_with([':', '-', '='])
+        })
+        .collect();
+    if values.is_empty() { all } else { values }
+}
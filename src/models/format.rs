@@ -0,0 +1,272 @@
+use color_eyre::Result;
+use saphyr_parser::{Event, Marker, Parser};
+use serde::Serialize;
+use serde::de::{DeserializeOwned, IntoDeserializer};
+use std::path::Path;
+use toml::de::{DeTable, DeValue};
+
+use super::diagnostics::Diagnostic;
+
+// Data files are YAML, TOML or JSON, told apart by their extension.
+// All three deserialize into the same types through serde.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl Format {
+    /// Anything that isn't `.toml` or `.json` is read as YAML
+    pub fn of(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::Toml,
+            Some("json") => Self::Json,
+            _ => Self::Yaml,
+        }
+    }
+}
+
+/// Parse a data file, recording a failure in `diagnostics`. TOML has no
+/// top-level arrays, so list files put their entries under a key named after
+/// the file: `[[experience]]` in experience.toml.
+pub fn parse<T: DeserializeOwned>(
+    path: &Path,
+    content: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Option<T> {
+    let parsed = match Format::of(path) {
+        Format::Yaml => {
+            serde_yaml::from_str(content).map_err(|e| Diagnostic::from_yaml(path, content, &e))
+        }
+        Format::Json => serde_json::from_str(content).map_err(|e| {
+            let message = e.to_string();
+            let message = message
+                .split(" at line ")
+                .next()
+                .unwrap_or_default()
+                .to_string();
+            Diagnostic::error(path, message).at(content, e.line(), e.column())
+        }),
+        Format::Toml => {
+            let toml_error = |e: toml::de::Error| {
+                let diagnostic = Diagnostic::error(path, e.message().trim());
+                match e.span() {
+                    Some(span) => {
+                        let (line, column) = line_column(content, span.start);
+                        diagnostic.at(content, line, column)
+                    }
+                    None => diagnostic,
+                }
+            };
+            match toml::from_str::<toml::Table>(content) {
+                Err(e) => Err(toml_error(e)),
+                Ok(mut table) => {
+                    let stem = path
+                        .file_stem()
+                        .and_then(|s| s.to_str())
+                        .unwrap_or_default();
+                    if table.len() == 1 && table.get(stem).is_some_and(toml::Value::is_array) {
+                        let entries = table.remove(stem).unwrap_or(toml::Value::Array(Vec::new()));
+                        T::deserialize(entries.into_deserializer()).map_err(toml_error)
+                    } else {
+                        toml::from_str(content).map_err(toml_error)
+                    }
+                }
+            }
+        }
+    };
+    parsed.map_err(|d| diagnostics.push(d)).ok()
+}
+
+// One step from a node to a child: an index into a list or a mapping key
+#[derive(Debug, Clone, PartialEq)]
+pub enum Step {
+    Index(usize),
+    Key(String),
+}
+
+impl Step {
+    pub fn key(key: &str) -> Self {
+        Self::Key(key.to_string())
+    }
+}
+
+/// 1-based line and column of the node `steps` lead to from the top of the
+/// file, if it is there. As in `parse`, a TOML list file's entries are under
+/// the key named after the file.
+pub fn position(path: &Path, content: &str, steps: &[Step]) -> Option<(usize, usize)> {
+    match Format::of(path) {
+        // JSON is read as YAML here; its flow syntax is valid YAML
+        Format::Yaml | Format::Json => {
+            let events: Vec<(Event, Marker)> = Parser::new_from_str(content)
+                .map_while(Result::ok)
+                .map(|(event, span)| (event, span.start))
+                .filter(|(event, _)| !matches!(event, Event::StreamStart | Event::DocumentStart(_)))
+                .collect();
+            let i = find(&events, steps)?;
+            match events.get(i)? {
+                (Event::SequenceEnd | Event::MappingEnd | Event::StreamEnd, _) => None,
+                (_, mark) => Some((mark.line(), mark.col() + 1)),
+            }
+        }
+        Format::Toml => {
+            let table = DeTable::parse(content).ok()?;
+            let stem = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or_default();
+            let wrapped = matches!(steps.first(), Some(Step::Index(_)))
+                && table.get_ref().len() == 1
+                && table.get_ref().iter().any(|(key, _)| key.get_ref() == stem);
+            let mut span = table.span();
+            let mut node = DeValue::Table(table.into_inner());
+            let prefix = wrapped.then(|| Step::key(stem));
+            for step in prefix.iter().chain(steps) {
+                let child = match (node, step) {
+                    (DeValue::Array(items), Step::Index(n)) => items.into_iter().nth(*n)?,
+                    (DeValue::Table(table), Step::Key(key)) => table
+                        .into_iter()
+                        .find(|(name, _)| name.get_ref() == key)
+                        .map(|(_, value)| value)?,
+                    _ => return None,
+                };
+                span = child.span();
+                node = child.into_inner();
+            }
+            Some(line_column(content, span.start))
+        }
+    }
+}
+
+/// 1-based line and column of the key `key` in the mapping `steps` lead to,
+/// for problems with the key itself. TOML points at its value instead.
+pub fn key_position(
+    path: &Path,
+    content: &str,
+    steps: &[Step],
+    key: &str,
+) -> Option<(usize, usize)> {
+    if Format::of(path) == Format::Toml {
+        let full: Vec<Step> = steps.iter().cloned().chain([Step::key(key)]).collect();
+        return position(path, content, &full);
+    }
+    let events: Vec<(Event, Marker)> = Parser::new_from_str(content)
+        .map_while(Result::ok)
+        .map(|(event, span)| (event, span.start))
+        .filter(|(event, _)| !matches!(event, Event::StreamStart | Event::DocumentStart(_)))
+        .collect();
+    let mapping = find(&events, steps)?;
+    if !matches!(events.get(mapping)?.0, Event::MappingStart(..)) {
+        return None;
+    }
+    let mut item = mapping + 1;
+    loop {
+        match events.get(item)? {
+            (Event::Scalar(name, ..), mark) if name == key => {
+                return Some((mark.line(), mark.col() + 1));
+            }
+            (Event::MappingEnd, _) => return None,
+            _ => item = skip_node(&events, skip_node(&events, item)?)?,
+        }
+    }
+}
+
+/// Index of the event that starts the node `steps` lead to, in the events
+/// of a YAML parse without its stream and document starts
+pub fn find<M>(events: &[(Event, M)], steps: &[Step]) -> Option<usize> {
+    let mut i = 0;
+    for step in steps {
+        i = match (&events.get(i)?.0, step) {
+            (Event::SequenceStart(..), Step::Index(n)) => {
+                let mut item = i + 1;
+                for _ in 0..*n {
+                    item = skip_node(events, item)?;
+                }
+                item
+            }
+            (Event::MappingStart(..), Step::Key(key)) => {
+                let mut item = i + 1;
+                loop {
+                    match &events.get(item)?.0 {
+                        Event::Scalar(name, ..) if name == key => break item + 1,
+                        _ => item = skip_node(events, skip_node(events, item)?)?,
+                    }
+                }
+            }
+            _ => return None,
+        };
+    }
+    Some(i)
+}
+
+/// Index just past the node that starts at `start`, nested nodes included
+pub fn skip_node<M>(events: &[(Event, M)], start: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, (event, _)) in events.iter().enumerate().skip(start) {
+        match event {
+            Event::SequenceStart(..) | Event::MappingStart(..) => depth += 1,
+            Event::SequenceEnd | Event::MappingEnd if depth == 0 => return None,
+            Event::SequenceEnd | Event::MappingEnd => depth -= 1,
+            Event::StreamEnd | Event::DocumentEnd => return None,
+            _ => {}
+        }
+        if depth == 0 {
+            return Some(i + 1);
+        }
+    }
+    None
+}
+
+/// 1-based line and column of a byte offset
+pub fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+    (line, column)
+}
+
+/// Whether a YAML or TOML file has `#` comments, which `to_string` can't
+/// keep. A `#` inside a quoted string doesn't count; one in a block scalar
+/// does, so this errs on the side of yes. Quotes only open a string where a
+/// value can start, so the apostrophe in `Jane's # note` is not one.
+pub fn has_comments(path: &Path, content: &str) -> bool {
+    if Format::of(path) == Format::Json {
+        return false;
+    }
+    content.lines().any(|line| {
+        let mut quote = None;
+        let mut previous = ' ';
+        for c in line.chars() {
+            match quote {
+                Some(q) if c == q && previous != '\\' => quote = None,
+                Some(_) => {}
+                None if (c == '"' || c == '\'') && " \t[{,:=".contains(previous) => quote = Some(c),
+                None if c == '#' && previous.is_whitespace() => return true,
+                None => {}
+            }
+            previous = c;
+        }
+        false
+    })
+}
+
+/// Serialize for the format `path` asks for; the inverse of `parse`
+pub fn to_string<T: Serialize + ?Sized>(path: &Path, value: &T) -> Result<String> {
+    Ok(match Format::of(path) {
+        Format::Yaml => serde_yaml::to_string(value)?,
+        Format::Json => serde_json::to_string_pretty(value)? + "\n",
+        Format::Toml => match toml::Value::try_from(value)? {
+            toml::Value::Table(table) => toml::to_string(&table)?,
+            entries => {
+                let stem = path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or_default();
+                let mut table = toml::Table::new();
+                table.insert(stem.to_string(), entries);
+                toml::to_string(&table)?
+            }
+        },
+    })
+}
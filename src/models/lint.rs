@@ -0,0 +1,273 @@
+use super::diagnostics::{Diagnostic, Sources};
+use super::format::Step;
+use super::resume::ResumeData;
+use super::types::{Bullet, SectionEntry};
+use crate::data_dir::DataDir;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+// Semantic checks on data that parsed fine but will likely render badly.
+// Everything here is a warning. Entries of list files are located through the
+// origins the loader recorded; profile fields by searching the source.
+// `roots` are the data directories read, base first (see `extends`).
+pub fn lint(data: &ResumeData, roots: &[DataDir], sources: &Sources) -> Vec<Diagnostic> {
+    let mut lints = Lints {
+        roots,
+        sources,
+        found: Vec::new(),
+    };
+
+    // 1. Required profile fields
+    if let Some(profile) = &data.profile {
+        for (field, value) in [("name", &profile.name), ("email", &profile.email)] {
+            if value.trim().is_empty() {
+                lints.warn(
+                    "profile.yaml",
+                    format!("Profile `{}` is empty", field),
+                    &format!("{}:", field),
+                    0,
+                );
+            }
+        }
+        lints.url("profile.yaml", "url", &profile.url);
+        lints.url("profile.yaml", "website", &profile.website);
+    }
+
+    // 2. Empty bullets
+    for (i, job) in data.experience.iter().enumerate() {
+        let owner = format!("{} at {}", job.role, job.company);
+        lints.bullets("experience", i, &owner, &job.bullets);
+    }
+    for (i, item) in data.volunteering.iter().enumerate() {
+        let owner = format!("{} at {}", item.role, item.organization);
+        lints.bullets("volunteering", i, &owner, &item.bullets);
+    }
+    for section in &data.custom_sections {
+        let name = format!("sections/{}", section.key);
+        for (i, entry) in section.entries.iter().enumerate() {
+            lints.bullets(&name, i, &entry.label(), &entry.bullets);
+        }
+    }
+
+    // 3. Duplicates. Job titles are matched by title; everything else by the
+    // stable id presets use, since two entries with one id can't be told apart.
+    // Repeats are pointed at by the field they are labelled with.
+    let field = |field: &'static str| move |i: usize| (i, vec![Step::key(field)]);
+    lints.duplicates(
+        "jobtitles",
+        "job title",
+        data.job_titles
+            .iter()
+            .map(|j| (j.title.clone(), j.title.clone()))
+            .collect(),
+        field("title"),
+    );
+    lints.duplicates(
+        "education",
+        "education entry",
+        data.education
+            .iter()
+            .map(|e| (e.stable_id(), e.degree.clone()))
+            .collect(),
+        field("degree"),
+    );
+    lints.duplicates(
+        "experience",
+        "experience entry",
+        data.experience
+            .iter()
+            .map(|e| (e.stable_id(), e.role.clone()))
+            .collect(),
+        field("role"),
+    );
+    lints.duplicates(
+        "projects",
+        "project",
+        data.projects
+            .iter()
+            .map(|p| (p.stable_id(), p.title.clone()))
+            .collect(),
+        field("title"),
+    );
+    lints.duplicates(
+        "skills",
+        "skill category",
+        data.skills
+            .iter()
+            .map(|c| (c.stable_id(), c.category.clone()))
+            .collect(),
+        field("category"),
+    );
+    // Bullets and skills are hidden by id too, so repeats would toggle together
+    for (i, job) in data.experience.iter().enumerate() {
+        lints.duplicates(
+            "experience",
+            "bullet",
+            job.bullets
+                .iter()
+                .map(|b| (b.stable_id(), b.text().to_string()))
+                .collect(),
+            |k| (i, vec![Step::key("bullets"), Step::Index(k)]),
+        );
+    }
+    for (i, category) in data.skills.iter().enumerate() {
+        lints.duplicates(
+            "skills",
+            "skill",
+            category
+                .skills
+                .iter()
+                .map(|s| (s.stable_id(), s.name.clone()))
+                .collect(),
+            |k| (i, vec![Step::key("skills"), Step::Index(k)]),
+        );
+    }
+    for section in data.sections() {
+        lints.duplicates(
+            &data.section_key(section),
+            "entry",
+            data.section_entries(section)
+                .iter()
+                .map(|e| (e.stable_id(), e.label()))
+                .collect(),
+            |i| (i, Vec::new()),
+        );
+    }
+
+    // 4. URLs without a scheme
+    for (i, project) in data.projects.iter().enumerate() {
+        if let Some(url) = &project.url {
+            lints.entry_url("projects", i, "url", url);
+        }
+    }
+    for (i, cert) in data.certifications.iter().enumerate() {
+        if let Some(url) = &cert.credential_url {
+            lints.entry_url("certifications", i, "credential_url", url);
+        }
+    }
+    for (i, publication) in data.publications.iter().enumerate() {
+        if let Some(url) = &publication.url {
+            lints.entry_url("publications", i, "url", url);
+        }
+    }
+    for section in &data.custom_sections {
+        let name = format!("sections/{}", section.key);
+        for (i, entry) in section.entries.iter().enumerate() {
+            if let Some(url) = entry.field("url") {
+                lints.entry_url(&name, i, "url", url);
+            }
+        }
+    }
+
+    lints.found
+}
+
+struct Lints<'a> {
+    roots: &'a [DataDir],
+    sources: &'a Sources,
+    found: Vec<Diagnostic>,
+}
+
+impl Lints<'_> {
+    // `needle` is the text to point at; `nth` picks among repeated occurrences
+    fn warn(&mut self, file: &str, message: String, needle: &str, nth: usize) {
+        // Reported against the directory being loaded unless the entry is
+        // found in a base directory or in a folder such as experience/
+        let places: Vec<(PathBuf, PathBuf)> = self
+            .roots
+            .iter()
+            .map(|root| {
+                let path = root.file(file);
+                let folder = path.with_extension("");
+                (path, folder)
+            })
+            .collect();
+        let Some((path, _)) = places.last() else {
+            return;
+        };
+        let diagnostic = Diagnostic::warning(path, message);
+        self.found
+            .push(self.sources.locate_across(diagnostic, &places, needle, nth));
+    }
+
+    // At what `steps` lead to inside entry `index` of the list file `name`
+    fn at(&mut self, name: &str, index: usize, steps: &[Step], message: String) {
+        let diagnostic = match self.sources.origin(name, index) {
+            Some(origin) => {
+                self.sources
+                    .locate_at(Diagnostic::warning(&origin.file, message), origin, steps)
+            }
+            None => {
+                let Some(root) = self.roots.last() else {
+                    return;
+                };
+                Diagnostic::warning(root.file(&format!("{}.yaml", name)), message)
+            }
+        };
+        self.found.push(diagnostic);
+    }
+
+    fn bullets(&mut self, name: &str, index: usize, owner: &str, bullets: &[Bullet]) {
+        for (k, bullet) in bullets.iter().enumerate() {
+            if bullet.text().trim().is_empty() {
+                self.at(
+                    name,
+                    index,
+                    &[Step::key("bullets"), Step::Index(k)],
+                    format!("Bullet {} of {:?} is empty", k + 1, owner),
+                );
+            }
+        }
+    }
+
+    // `entries` are (key, label) pairs; repeats after the first are reported
+    // where `place` says: the entry index and the steps inside that entry.
+    // Keys are stable ids except for job titles, which have no `id` field.
+    fn duplicates(
+        &mut self,
+        name: &str,
+        kind: &str,
+        entries: Vec<(String, String)>,
+        place: impl Fn(usize) -> (usize, Vec<Step>),
+    ) {
+        let hint = if kind == "job title" {
+            ""
+        } else {
+            "; give one of them an `id`"
+        };
+        let mut seen: HashSet<&str> = HashSet::new();
+        for (k, (key, label)) in entries.iter().enumerate() {
+            if !seen.insert(key) {
+                let (index, steps) = place(k);
+                self.at(
+                    name,
+                    index,
+                    &steps,
+                    format!("Duplicate {} {:?}{}", kind, label, hint),
+                );
+            }
+        }
+    }
+
+    fn entry_url(&mut self, name: &str, index: usize, field: &str, url: &str) {
+        if let Some(message) = missing_scheme(field, url) {
+            self.at(name, index, &[Step::key(field)], message);
+        }
+    }
+
+    fn url(&mut self, file: &str, field: &str, url: &str) {
+        if let Some(message) = missing_scheme(field, url) {
+            self.warn(file, message, url.trim(), 0);
+        }
+    }
+}
+
+fn missing_scheme(field: &str, url: &str) -> Option<String> {
+    let url = url.trim();
+    (!url.is_empty() && !url.contains("://") && !url.starts_with("mailto:")).then(|| {
+        format!(
+            "`{}` {:?} has no scheme; https:// will be assumed",
+            field, url
+        )
+    })
+}
@@ -1,4 +1,12 @@
 pub mod types;
+pub mod config;
+pub mod date;
+pub mod diagnostics;
+pub mod format;
+pub mod lint;
+pub mod patch;
+pub mod preset;
 pub mod resume;
+pub mod schema;
 
 pub use resume::*;
@@ -0,0 +1,331 @@
+use saphyr_parser::{Event, Parser, ScalarStyle, Span};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_yaml::{Mapping, Value};
+use std::path::Path;
+
+use super::format::{self, Format, Step};
+
+// Adds what a merge appended to a YAML data file by inserting lines, so
+// everything already there stays exactly as the user wrote it: quoting,
+// comments, blank lines, grouping and order. Only new entries and new items
+// at the end of an entry's lists (bullets) are written this way; any other
+// change needs the whole file rewritten.
+
+/// `content` with the entries of `new` past the `old` ones appended after
+/// the last entry, and items added to the end of an entry's lists appended
+/// after its last item. None when anything else changed, a list is written
+/// in flow style (`[a, b]`) or the result wouldn't read back as `new`; the
+/// caller then has to rewrite the whole file.
+pub fn entries<T: Serialize + DeserializeOwned>(
+    path: &Path,
+    content: &str,
+    name: &str,
+    old: &[T],
+    new: &[T],
+) -> Option<String> {
+    if Format::of(path) != Format::Yaml || old.is_empty() || new.len() < old.len() {
+        return None;
+    }
+    let content = if content.ends_with('\n') {
+        content.to_string()
+    } else {
+        format!("{}\n", content)
+    };
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    let document: Value = serde_yaml::from_str(&content).ok()?;
+    let steps = layout(&document, name)?;
+    if steps.len() != old.len() {
+        return None;
+    }
+    let events: Vec<(Event, Span)> = Parser::new_from_str(&content)
+        .map_while(Result::ok)
+        .filter(|(event, _)| !matches!(event, Event::StreamStart | Event::DocumentStart(_)))
+        .collect();
+
+    // Lines to insert, and how many lines of the file come before them
+    let mut inserts: Vec<(usize, Vec<String>)> = Vec::new();
+    for (i, entry) in steps.iter().enumerate() {
+        let (before, after) = (to_mapping(&old[i])?, to_mapping(&new[i])?);
+        if before.keys().any(|key| !after.contains_key(key)) {
+            return None;
+        }
+        for (key, value) in &after {
+            let (old_items, items) = match (before.get(key), value) {
+                (Some(old), _) if old == value => continue,
+                (Some(Value::Sequence(old)), Value::Sequence(new))
+                    if !old.is_empty() && new.starts_with(old) =>
+                {
+                    (old, new)
+                }
+                _ => return None,
+            };
+            let list: Vec<Step> = entry
+                .iter()
+                .cloned()
+                .chain([Step::key(key.as_str()?)])
+                .collect();
+            inserts.push(append(
+                &lines,
+                &events,
+                &list,
+                old_items.len(),
+                &items[old_items.len()..],
+            )?);
+        }
+    }
+
+    if new.len() > old.len() {
+        let (_, list) = steps.last()?.split_last()?;
+        let len = at(&document, list)?.as_sequence()?.len();
+        let added = new[old.len()..]
+            .iter()
+            .map(|entry| Some(compact::<T>(serde_yaml::to_value(entry).ok()?)))
+            .collect::<Option<Vec<_>>>()?;
+        inserts.push(append(&lines, &events, list, len, &added)?);
+    }
+
+    // New bullets of the last entry go before the entries added after it
+    inserts.sort_by_key(|(line, _)| *line);
+    let mut out = String::new();
+    let mut cursor = 0;
+    for (line, added) in inserts {
+        out.extend(lines[cursor..line].iter().copied());
+        out.extend(added);
+        cursor = line;
+    }
+    out.extend(lines[cursor..].iter().copied());
+
+    reads_back(&out, name, new).then_some(out)
+}
+
+// Steps to every entry, matching how the loader reads the file: a list of
+// entries, a list of `- education: [...]` groups, or a single entry
+fn layout(document: &Value, name: &str) -> Option<Vec<Vec<Step>>> {
+    let group = |value: &Value| {
+        value
+            .as_mapping()
+            .filter(|m| m.len() == 1)
+            .and_then(|m| m.get(name))
+            .and_then(Value::as_sequence)
+            .map(Vec::len)
+    };
+    let mut entries = Vec::new();
+    match document {
+        Value::Sequence(items) => {
+            for (i, item) in items.iter().enumerate() {
+                match group(item) {
+                    Some(len) => entries.extend(
+                        (0..len).map(|j| vec![Step::Index(i), Step::key(name), Step::Index(j)]),
+                    ),
+                    None => entries.push(vec![Step::Index(i)]),
+                }
+            }
+        }
+        Value::Mapping(_) => match group(document) {
+            Some(len) => entries.extend((0..len).map(|j| vec![Step::key(name), Step::Index(j)])),
+            None => entries.push(Vec::new()),
+        },
+        _ => return None,
+    }
+    Some(entries)
+}
+
+fn at<'a>(value: &'a Value, steps: &[Step]) -> Option<&'a Value> {
+    steps.iter().try_fold(value, |value, step| match step {
+        Step::Index(i) => value.as_sequence()?.get(*i),
+        Step::Key(key) => value.as_mapping()?.get(key.as_str()),
+    })
+}
+
+// `items` as lines to add to the block list `steps` lead to, which has
+// `len` items, and the line of the file they go after: the last line of
+// its last item. Items separated by blank lines get one before them too.
+fn append(
+    lines: &[&str],
+    events: &[(Event, Span)],
+    steps: &[Step],
+    len: usize,
+    items: &[Value],
+) -> Option<(usize, Vec<String>)> {
+    let start = format::find(events, steps)?;
+    // A block list starts at its first dash; a flow list spans its bracket
+    let Some((Event::SequenceStart(..), span)) = events.get(start) else {
+        return None;
+    };
+    if span.end.index() != span.start.index() {
+        return None;
+    }
+    let dash = span.start.col();
+
+    // First and last line of each item
+    let mut extents = Vec::new();
+    let mut item = start + 1;
+    for _ in 0..len {
+        let next = format::skip_node(events, item)?;
+        extents.push((
+            events[item].1.start.line(),
+            last_line(lines, &events[item..next])?,
+        ));
+        item = next;
+    }
+    if !matches!(events.get(item)?.0, Event::SequenceEnd) {
+        return None;
+    }
+    let &(last_start, last_end) = extents.last()?;
+    let spaced = extents.len() > 1
+        && lines[extents[extents.len() - 2].1..last_start - 1]
+            .iter()
+            .any(|l| l.trim().is_empty());
+
+    let mut added = Vec::new();
+    for value in items {
+        if spaced {
+            added.push("\n".to_string());
+        }
+        added.extend(list_item(value, dash)?);
+    }
+    Some((last_end, added))
+}
+
+// 1-based last line holding part of the node made of `events`. A block
+// scalar's span runs on to whatever follows it, comments included, so its
+// last line is the last one indented as far as its content.
+fn last_line(lines: &[&str], events: &[(Event, Span)]) -> Option<usize> {
+    events
+        .iter()
+        .filter(|(event, _)| {
+            matches!(
+                event,
+                Event::Scalar(..)
+                    | Event::Alias(_)
+                    | Event::SequenceStart(..)
+                    | Event::MappingStart(..)
+            )
+        })
+        .map(|(event, span)| match event {
+            Event::Scalar(_, ScalarStyle::Literal | ScalarStyle::Folded, ..) => (span.start.line()
+                ..=span.end.line())
+                .filter(|&l| {
+                    lines.get(l - 1).is_some_and(|line| {
+                        !line.trim().is_empty() && indentation(line) >= span.start.col()
+                    })
+                })
+                .max()
+                .unwrap_or(span.start.line()),
+            _ => span.end.line(),
+        })
+        .max()
+}
+
+fn indentation(line: &str) -> usize {
+    line.chars().take_while(|c| *c == ' ').count()
+}
+
+// `value` as a block list item with its dash at column `dash`
+fn list_item(value: &Value, dash: usize) -> Option<Vec<String>> {
+    let indent = " ".repeat(dash);
+    match value {
+        Value::Mapping(mapping) if !mapping.is_empty() => {
+            let mut lines = fields(mapping, dash + 2)?;
+            lines[0] = format!("{}- {}", indent, lines[0].trim_start());
+            Some(lines)
+        }
+        _ => Some(vec![format!("{}- {}\n", indent, scalar(value)?)]),
+    }
+}
+
+// A mapping's fields at `column`, lists indented under their key the way
+// the data files are written
+fn fields(mapping: &Mapping, column: usize) -> Option<Vec<String>> {
+    let indent = " ".repeat(column);
+    let mut lines = Vec::new();
+    for (key, value) in mapping {
+        let key = scalar(key)?;
+        match value {
+            Value::Sequence(items) if !items.is_empty() => {
+                lines.push(format!("{}{}:\n", indent, key));
+                for item in items {
+                    lines.extend(list_item(item, column + 2)?);
+                }
+            }
+            Value::Mapping(inner) if !inner.is_empty() => {
+                lines.push(format!("{}{}:\n", indent, key));
+                lines.extend(fields(inner, column + 2)?);
+            }
+            _ => lines.push(format!("{}{}: {}\n", indent, key, scalar(value)?)),
+        }
+    }
+    Some(lines)
+}
+
+// A scalar or empty collection on one line. Strings serde_yaml would write
+// as a block scalar are JSON-quoted instead, which YAML reads the same.
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::Sequence(items) if items.is_empty() => Some("[]".to_string()),
+        Value::Mapping(mapping) if mapping.is_empty() => Some("{}".to_string()),
+        Value::Sequence(_) | Value::Mapping(_) | Value::Tagged(_) => None,
+        _ => {
+            let text = serde_yaml::to_string(value).ok()?;
+            let text = text.strip_suffix('\n')?;
+            if text.contains('\n') {
+                serde_json::to_string(value).ok()
+            } else {
+                Some(text.to_string())
+            }
+        }
+    }
+}
+
+// Whether the edited file holds exactly the `new` entries
+fn reads_back<T: Serialize + DeserializeOwned>(content: &str, name: &str, new: &[T]) -> bool {
+    let read = || -> Option<bool> {
+        let document: Value = serde_yaml::from_str(content).ok()?;
+        let steps = layout(&document, name)?;
+        if steps.len() != new.len() {
+            return Some(false);
+        }
+        for (steps, entry) in steps.iter().zip(new) {
+            let written: T = serde_yaml::from_value(at(&document, steps)?.clone()).ok()?;
+            if serde_yaml::to_value(&written).ok()? != serde_yaml::to_value(entry).ok()? {
+                return Some(false);
+            }
+        }
+        Some(true)
+    };
+    read().unwrap_or(false)
+}
+
+fn to_mapping<T: Serialize>(entry: &T) -> Option<Mapping> {
+    match serde_yaml::to_value(entry).ok()? {
+        Value::Mapping(mapping) => Some(mapping),
+        _ => None,
+    }
+}
+
+// Leave out empty fields a new entry can do without
+fn compact<T: DeserializeOwned>(value: Value) -> Value {
+    let Value::Mapping(mut mapping) = value else {
+        return value;
+    };
+    let keys: Vec<Value> = mapping.keys().cloned().collect();
+    for key in keys {
+        let empty = match &mapping[&key] {
+            Value::Null => true,
+            Value::String(s) => s.is_empty(),
+            Value::Sequence(s) => s.is_empty(),
+            Value::Mapping(m) => m.is_empty(),
+            _ => false,
+        };
+        if !empty {
+            continue;
+        }
+        let mut without = mapping.clone();
+        without.shift_remove(&key);
+        if serde_yaml::from_value::<T>(Value::Mapping(without.clone())).is_ok() {
+            mapping = without;
+        }
+    }
+    Value::Mapping(mapping)
+}
@@ -0,0 +1,352 @@
+use super::diagnostics::Diagnostic;
+use super::format;
+use super::resume::ResumeData;
+use super::schema;
+use super::types::{default_true, slugify};
+use crate::data_dir::DataDir;
+use color_eyre::{Result, eyre::eyre};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// A saved selection ("variant"), stored as data/presets/<name>.yaml.
+// Entries are keyed by their stable id so editing the YAML order doesn't
+// change which items a preset shows. Only hidden items are stored, so
+// entries and bullets added after the preset was saved show up.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Preset {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    // Template file; None keeps the current choice
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    #[serde(default = "default_true")]
+    pub show_email: bool,
+    #[serde(default = "default_true")]
+    pub show_phone: bool,
+
+    // List (education, experience, projects, skills or a section key such
+    // as "certifications") -> hidden entries
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub hidden: BTreeMap<String, Vec<String>>,
+    // Experience id -> hidden bullets
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub hidden_bullets: BTreeMap<String, Vec<String>>,
+    // Category id -> hidden skills
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub hidden_skills: BTreeMap<String, Vec<String>>,
+
+    // Older presets list the visible entries instead; anything not listed
+    // is hidden. Read, but `capture` never sets them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub education: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub experience: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projects: Option<Vec<String>>,
+    // Absent in presets saved before skills existed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skills: Option<Vec<String>>,
+    // Sections missing from the map are left untouched
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub sections: BTreeMap<String, Vec<String>>,
+}
+
+impl Preset {
+    /// Snapshot the current UI selection
+    pub fn capture(data: &ResumeData) -> Self {
+        let profile = data.profile.as_ref();
+        let hidden = |ids: Vec<(String, bool)>| -> Vec<String> {
+            ids.into_iter()
+                .filter(|(_, visible)| !visible)
+                .map(|(id, _)| id)
+                .collect()
+        };
+        let mut lists = vec![
+            (
+                "education".to_string(),
+                hidden(
+                    data.education
+                        .iter()
+                        .map(|e| (e.stable_id(), e.is_visible))
+                        .collect(),
+                ),
+            ),
+            (
+                "experience".to_string(),
+                hidden(
+                    data.experience
+                        .iter()
+                        .map(|e| (e.stable_id(), e.is_visible))
+                        .collect(),
+                ),
+            ),
+            (
+                "projects".to_string(),
+                hidden(
+                    data.projects
+                        .iter()
+                        .map(|p| (p.stable_id(), p.is_visible))
+                        .collect(),
+                ),
+            ),
+            (
+                "skills".to_string(),
+                hidden(
+                    data.skills
+                        .iter()
+                        .map(|c| (c.stable_id(), c.is_visible))
+                        .collect(),
+                ),
+            ),
+        ];
+        for section in data.sections() {
+            let entries = data.section_entries(section);
+            let ids = entries
+                .iter()
+                .map(|e| (e.stable_id(), e.is_visible()))
+                .collect();
+            lists.push((data.section_key(section), hidden(ids)));
+        }
+
+        Self {
+            job_title: data.job_title.clone(),
+            template: data.template.clone(),
+            show_email: profile.is_none_or(|p| p.show_email),
+            show_phone: profile.is_none_or(|p| p.show_phone),
+            hidden: lists
+                .into_iter()
+                .filter(|(_, ids)| !ids.is_empty())
+                .collect(),
+            hidden_bullets: data
+                .experience
+                .iter()
+                .filter(|e| !e.hidden_bullets.is_empty())
+                .map(|e| (e.stable_id(), e.hidden_bullets.clone()))
+                .collect(),
+            hidden_skills: data
+                .skills
+                .iter()
+                .filter(|c| !c.hidden_skills.is_empty())
+                .map(|c| (c.stable_id(), c.hidden_skills.clone()))
+                .collect(),
+            education: None,
+            experience: None,
+            projects: None,
+            skills: None,
+            sections: BTreeMap::new(),
+        }
+    }
+
+    // Written before hidden entries were stored
+    fn lists_visible(&self) -> bool {
+        self.education.is_some() || self.experience.is_some() || self.projects.is_some()
+    }
+
+    // The ids the preset stores for the list `key`: hidden ones, or the
+    // visible ones of an older preset. None leaves the list untouched.
+    fn stored(&self, key: &str) -> Option<&[String]> {
+        if !self.lists_visible() {
+            return Some(self.hidden.get(key).map_or(&[], Vec::as_slice));
+        }
+        match key {
+            "education" => self.education.as_deref(),
+            "experience" => self.experience.as_deref(),
+            "projects" => self.projects.as_deref(),
+            "skills" => self.skills.as_deref(),
+            _ => self.sections.get(key).map(Vec::as_slice),
+        }
+    }
+
+    // Whether the preset shows entry `id` of the list `key`, the same way
+    // for every list; None when it doesn't cover the list
+    fn shows(&self, key: &str, id: &str) -> Option<bool> {
+        let listed = self.stored(key)?.iter().any(|stored| stored == id);
+        Some(listed == self.lists_visible())
+    }
+
+    /// Apply the preset to loaded data.
+    /// Returns a warning for every key that no longer matches anything.
+    pub fn apply(&self, data: &mut ResumeData) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if let Some(ref title) = self.job_title {
+            match data.job_titles.iter().position(|jt| &jt.title == title) {
+                Some(index) => data.select_job_title(index),
+                None => warnings.push(format!("Job title {:?} no longer exists", title)),
+            }
+        }
+
+        if self.template.is_some() {
+            data.template = self.template.clone();
+        }
+
+        if let Some(profile) = &mut data.profile {
+            profile.show_email = self.show_email;
+            profile.show_phone = self.show_phone;
+        }
+
+        for item in &mut data.education {
+            if let Some(visible) = self.shows("education", &item.stable_id()) {
+                item.is_visible = visible;
+            }
+        }
+        for item in &mut data.experience {
+            let id = item.stable_id();
+            if let Some(visible) = self.shows("experience", &id) {
+                item.is_visible = visible;
+            }
+            item.hidden_bullets = self.hidden_bullets.get(&id).cloned().unwrap_or_default();
+        }
+        for item in &mut data.projects {
+            if let Some(visible) = self.shows("projects", &item.stable_id()) {
+                item.is_visible = visible;
+            }
+        }
+        for item in &mut data.skills {
+            let id = item.stable_id();
+            if let Some(visible) = self.shows("skills", &id) {
+                item.is_visible = visible;
+            }
+            item.hidden_skills = self.hidden_skills.get(&id).cloned().unwrap_or_default();
+        }
+        for section in data.sections() {
+            let key = data.section_key(section);
+            for entry in data.section_entries_mut(section) {
+                if let Some(visible) = self.shows(&key, &entry.stable_id()) {
+                    entry.set_visible(visible);
+                }
+            }
+        }
+
+        if self.lists_visible() {
+            warnings.push(
+                "Lists the entries to show, so entries added since it was saved are hidden; \
+                 save the preset again to store the hidden ones instead"
+                    .to_string(),
+            );
+        }
+
+        let mut known: Vec<(String, Vec<String>)> = vec![
+            (
+                "education".to_string(),
+                data.education.iter().map(|e| e.stable_id()).collect(),
+            ),
+            (
+                "experience".to_string(),
+                data.experience.iter().map(|e| e.stable_id()).collect(),
+            ),
+            (
+                "projects".to_string(),
+                data.projects.iter().map(|p| p.stable_id()).collect(),
+            ),
+            (
+                "skills".to_string(),
+                data.skills.iter().map(|c| c.stable_id()).collect(),
+            ),
+        ];
+        for section in data.sections() {
+            let ids = data
+                .section_entries(section)
+                .iter()
+                .map(|e| e.stable_id())
+                .collect();
+            known.push((data.section_key(section), ids));
+        }
+        let keys = self.hidden.keys().chain(self.sections.keys());
+        for key in keys.filter(|key| !known.iter().any(|(k, _)| k == *key)) {
+            warnings.push(format!("Section {:?} no longer exists", key));
+        }
+        for (key, ids) in &known {
+            for id in self.stored(key).unwrap_or_default() {
+                if !ids.contains(id) {
+                    warnings.push(format!("Unknown {} entry {:?}", key, id));
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Names of the presets saved in `data/presets/`, sorted
+    pub fn list(dir: &DataDir) -> Vec<String> {
+        let Ok(entries) = fs::read_dir(dir.presets_dir()) else {
+            return Vec::new();
+        };
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "yaml"))
+            .filter_map(|path| Some(path.file_stem()?.to_string_lossy().to_string()))
+            .collect();
+        names.sort();
+        names
+    }
+
+    pub fn load(dir: &DataDir, name: &str) -> Result<Self> {
+        let path = preset_path(dir, name)?;
+        if !path.exists() {
+            let available = Self::list(dir);
+            return Err(eyre!(
+                "Preset {:?} not found at {:?}. Available: {}",
+                name,
+                path,
+                if available.is_empty() {
+                    "none".to_string()
+                } else {
+                    available.join(", ")
+                }
+            ));
+        }
+        let mut diagnostics = Vec::new();
+        Self::read(&path, &mut diagnostics).ok_or_else(|| match diagnostics.pop() {
+            Some(diagnostic) => eyre!("{}", diagnostic),
+            None => eyre!("Invalid preset {:?}", path),
+        })
+    }
+
+    /// Parse a preset file, recording a failure in `diagnostics` with its
+    /// position like the data files
+    pub fn read(path: &Path, diagnostics: &mut Vec<Diagnostic>) -> Option<Self> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| diagnostics.push(Diagnostic::error(path, e.to_string())))
+            .ok()?;
+        let preset = format::parse(path, &content, diagnostics);
+        schema::validate("preset", path, &content, false, diagnostics);
+        preset
+    }
+
+    /// Whether `load` would find a preset by this name
+    pub fn exists(dir: &DataDir, name: &str) -> bool {
+        preset_path(dir, name).is_ok_and(|path| path.exists())
+    }
+
+    /// Write the preset; an existing one is only replaced with `replace`
+    pub fn save(&self, dir: &DataDir, name: &str, replace: bool) -> Result<PathBuf> {
+        let path = preset_path(dir, name)?;
+        if !replace && path.exists() {
+            return Err(eyre!("Preset {:?} already exists at {:?}", name, path));
+        }
+        fs::create_dir_all(dir.presets_dir())?;
+        fs::write(&path, serde_yaml::to_string(self)?)?;
+        Ok(path)
+    }
+}
+
+// Preset names become file names, so keep them to a safe slug. A file
+// named exactly `name` wins, so hand-written presets load under the name
+// `list` gives them.
+fn preset_path(dir: &DataDir, name: &str) -> Result<PathBuf> {
+    let exact = dir.presets_dir().join(format!("{}.yaml", name));
+    if !name.contains(['/', '\\']) && !name.starts_with('.') && exact.is_file() {
+        return Ok(exact);
+    }
+    let slug = slugify(&[name]);
+    if slug.is_empty() {
+        return Err(eyre!("Preset name {:?} is empty", name));
+    }
+    Ok(dir.presets_dir().join(format!("{}.yaml", slug)))
+}
@@ -1,10 +1,42 @@
+use super::config::Config;
+use super::date::{YearMonth, format_loose};
+use super::diagnostics::{Diagnostic, Origin, Sources};
+use super::format::{self, Step};
+use super::lint::lint;
+use super::schema;
 use super::types::{
-    Education, EducationWrapper, Experience, FilteredResumeData, JobTitle, Profile, Project,
-    ProjectsWrapper,
+    Award, Certification, CustomSection, Education, Experience, FilteredResumeData, JobTitle,
+    Language, Profile, Project, Publication, SectionEntry, SkillCategory, Volunteering,
 };
-use color_eyre::Result;
+use crate::data_dir::{DATA_DIR_ENV, DATA_EXTENSIONS, DataDir};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
+use std::path::{Path, PathBuf};
+
+// The optional toggle-only sections, in the order they appear in the TUI.
+// `Custom` indexes into `ResumeData::custom_sections`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+    Certifications,
+    Awards,
+    Publications,
+    Languages,
+    Volunteering,
+    Custom(usize),
+}
+
+impl Section {
+    /// The built-in sections, each backed by its own data file
+    pub const ALL: [Section; 5] = [
+        Section::Certifications,
+        Section::Awards,
+        Section::Publications,
+        Section::Languages,
+        Section::Volunteering,
+    ];
+}
 
 // The Master Container
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -13,117 +45,198 @@ pub struct ResumeData {
     pub education: Vec<Education>,
     pub experience: Vec<Experience>,
     pub projects: Vec<Project>,
+    #[serde(default)]
+    pub skills: Vec<SkillCategory>,
+    #[serde(default)]
+    pub certifications: Vec<Certification>,
+    #[serde(default)]
+    pub awards: Vec<Award>,
+    #[serde(default)]
+    pub publications: Vec<Publication>,
+    #[serde(default)]
+    pub languages: Vec<Language>,
+    #[serde(default)]
+    pub volunteering: Vec<Volunteering>,
+    #[serde(default)]
+    pub custom_sections: Vec<CustomSection>,
+    #[serde(default)]
+    pub config: Config,
     pub job_title: Option<String>,
     pub job_titles: Vec<JobTitle>,
+    // Template file chosen in the picker or with --template; None for the default
+    #[serde(default)]
+    pub template: Option<String>,
     pub professional_summary: Option<String>,
 }
 
 impl ResumeData {
     // 3. THE LOADER
-    // Reads specific files from the 'data/' directory
-    pub fn load_from_dir() -> Result<Self> {
-        let mut data = ResumeData::default();
-
-        // DEBUG: Print where the app thinks it is running
-        //let current_dir = std::env::current_dir()?;
-        //eprintln!("DEBUG MESSAGE: I am running in: {:?}", current_dir);
-
-        // Helper to read a file
-        let read_yaml = |filename: &str| -> Result<String> {
-            // Use current_dir() to find the folder relative to where the user runs the app
-            // instead of baking in the build-server path.
-            let current_dir = std::env::current_dir()?;
-            let path = current_dir.join("data").join(filename);
-
-            if !path.exists() {
-                return Err(color_eyre::eyre::eyre!(
-                    "File not found at: {:?}.\nPlease ensure the 'data' folder is in the same directory as the executable.",
-                    path
-                ));
-            }
+    // Reads specific files from the resolved data directory.
+    // Problems are collected rather than returned early so the TUI and
+    // `check` can show all of them at once.
+    pub fn load(dir: &DataDir) -> (Self, Vec<Diagnostic>) {
+        let (mut data, diagnostics) = Self::load_in_file_order(dir);
+        if data.config.sort_by_date {
+            data.sort_by_date();
+        }
+        (data, diagnostics)
+    }
 
-            Ok(fs::read_to_string(&path)?)
+    /// `load` without `sort_by_date`, for commands that write entries back
+    pub fn load_in_file_order(dir: &DataDir) -> (Self, Vec<Diagnostic>) {
+        let mut loader = Loader::default();
+        // `extends` in config.yaml layers this directory over a base one
+        loader.resolve_roots(dir);
+        if !dir.root().is_dir() {
+            let mut missing = Diagnostic::warning(dir.root(), "Data directory not found");
+            missing.notes.push(format!(
+                "hint: unzip resume-data.zip here, or pass --data-dir or set {} to point at your data folder",
+                DATA_DIR_ENV
+            ));
+            loader.diagnostics.push(missing);
+        }
+
+        let mut data = ResumeData {
+            config: loader.fields("config.yaml", false).unwrap_or_default(),
+            ..Default::default()
         };
 
-        // Load Profile
-        match read_yaml("profile.yaml") {
-            Ok(profile_str) => {
-                if !profile_str.is_empty() {
-                    data.profile = Some(serde_yaml::from_str(&profile_str).map_err(|e| {
-                        color_eyre::eyre::eyre!("YAML Parsing Error in profile.yaml: {}", e)
-                    })?);
-                }
-            }
-            Err(e) => eprintln!("Warning: Could not load profile.yaml: {}", e),
+        // Core files: a missing one is reported
+        data.profile = loader.fields("profile.yaml", true);
+        data.job_titles = loader.entries("jobtitles", true, |j: &JobTitle| j.title.clone());
+
+        // Can also be split into a folder with one file per entry
+        data.experience = loader.entries("experience", true, Experience::stable_id);
+        data.education = loader.entries("education", true, Education::stable_id);
+        data.projects = loader.entries("projects", true, Project::stable_id);
+
+        // Optional sections: a missing file is not worth a warning
+        data.skills = loader.entries("skills", false, SkillCategory::stable_id);
+        data.certifications = loader.entries("certifications", false, Certification::stable_id);
+        data.awards = loader.entries("awards", false, Award::stable_id);
+        data.publications = loader.entries("publications", false, Publication::stable_id);
+        data.languages = loader.entries("languages", false, Language::stable_id);
+        data.volunteering = loader.entries("volunteering", false, Volunteering::stable_id);
+        data.custom_sections = loader.custom_sections();
+
+        loader.validate();
+
+        // Before sorting, while entries are in the order their origins were recorded
+        let mut diagnostics = loader.diagnostics;
+        diagnostics.extend(lint(&data, &loader.roots, &loader.sources));
+        (data, diagnostics)
+    }
+
+    /// Only config.yaml, layered over its `extends` bases like in `load`,
+    /// for commands that need nothing else. Problems are left to `check`.
+    pub fn load_config(dir: &DataDir) -> Config {
+        let mut loader = Loader::default();
+        loader.resolve_roots(dir);
+        loader.fields("config.yaml", false).unwrap_or_default()
+    }
+
+    /// Order education and experience newest-first by their start/end dates.
+    /// Entries without structured dates keep their relative order at the end.
+    pub fn sort_by_date(&mut self) {
+        let today = YearMonth::today();
+        self.education
+            .sort_by(|a, b| a.dates().newest_first(&b.dates(), today));
+        self.experience
+            .sort_by(|a, b| a.dates().newest_first(&b.dates(), today));
+    }
+
+    /// Built-in sections followed by the custom ones
+    pub fn sections(&self) -> Vec<Section> {
+        Section::ALL
+            .into_iter()
+            .chain((0..self.custom_sections.len()).map(Section::Custom))
+            .collect()
+    }
+
+    /// Heading used in the TUI
+    pub fn section_title(&self, section: Section) -> &str {
+        match section {
+            Section::Certifications => "Certifications",
+            Section::Awards => "Awards",
+            Section::Publications => "Publications",
+            Section::Languages => "Languages",
+            Section::Volunteering => "Volunteering",
+            Section::Custom(i) => self.custom_sections.get(i).map_or("", |s| &s.title),
         }
+    }
 
-        // Load Job Titles
-        match read_yaml("jobtitles.yaml") {
-            Ok(job_titles_str) => {
-                if !job_titles_str.is_empty() {
-                    data.job_titles = serde_yaml::from_str(&job_titles_str).map_err(|e| {
-                        color_eyre::eyre::eyre!("YAML Parsing Error in jobtitles.yaml: {}", e)
-                    })?;
-                }
-            }
-            Err(e) => eprintln!("Warning: Could not load jobtitles.yaml: {}", e),
-        }
-
-        // Load Education
-        match read_yaml("education.yaml") {
-            Ok(edu_str) => {
-                if !edu_str.is_empty() {
-                    // Parse the wrapper structure
-                    let wrappers: Vec<EducationWrapper> =
-                        serde_yaml::from_str(&edu_str).map_err(|e| {
-                            color_eyre::eyre::eyre!("YAML Parsing Error in education.yaml: {}", e)
-                        })?;
-
-                    // Flatten the nested structure
-                    for wrapper in wrappers {
-                        data.education.extend(wrapper.education);
-                    }
-                }
-            }
-            Err(e) => eprintln!("Warning: Could not load education.yaml: {}", e),
+    /// Data file path without extension; also the key in presets
+    pub fn section_key(&self, section: Section) -> String {
+        match section {
+            Section::Certifications => "certifications".to_string(),
+            Section::Awards => "awards".to_string(),
+            Section::Publications => "publications".to_string(),
+            Section::Languages => "languages".to_string(),
+            Section::Volunteering => "volunteering".to_string(),
+            Section::Custom(i) => self
+                .custom_sections
+                .get(i)
+                .map_or_else(String::new, |s| format!("sections/{}", s.key)),
+        }
+    }
+
+    /// Entries of an optional section, for generic listing
+    pub fn section_entries(&self, section: Section) -> Vec<&dyn SectionEntry> {
+        fn erase<T: SectionEntry>(items: &[T]) -> Vec<&dyn SectionEntry> {
+            items.iter().map(|i| i as &dyn SectionEntry).collect()
+        }
+        match section {
+            Section::Certifications => erase(&self.certifications),
+            Section::Awards => erase(&self.awards),
+            Section::Publications => erase(&self.publications),
+            Section::Languages => erase(&self.languages),
+            Section::Volunteering => erase(&self.volunteering),
+            Section::Custom(i) => self
+                .custom_sections
+                .get(i)
+                .map_or_else(Vec::new, |s| erase(&s.entries)),
         }
+    }
 
-        // Load Experience
-        match read_yaml("experience.yaml") {
-            Ok(exp_str) => {
-                if !exp_str.is_empty() {
-                    data.experience = serde_yaml::from_str(&exp_str).map_err(|e| {
-                        color_eyre::eyre::eyre!("YAML Parsing Error in experience.yaml: {}", e)
-                    })?;
-                }
-            }
-            Err(e) => eprintln!("Warning: Could not load experience.yaml: {}", e),
-        }
-
-        // Load Projects
-        match read_yaml("projects.yaml") {
-            Ok(proj_str) => {
-                if !proj_str.is_empty() {
-                    // Parse the wrapper structure
-                    let wrappers: Vec<ProjectsWrapper> =
-                        serde_yaml::from_str(&proj_str).map_err(|e| {
-                            color_eyre::eyre::eyre!("YAML Parsing Error in projects.yaml: {}", e)
-                        })?;
-
-                    // Flatten the nested structure
-                    for wrapper in wrappers {
-                        data.projects.extend(wrapper.projects);
-                    }
-                }
-            }
-            Err(e) => eprintln!("Warning: Could not load projects.yaml: {}", e),
+    /// Mutable entries of an optional section, for toggling
+    pub fn section_entries_mut(&mut self, section: Section) -> Vec<&mut dyn SectionEntry> {
+        fn erase<T: SectionEntry>(items: &mut [T]) -> Vec<&mut dyn SectionEntry> {
+            items
+                .iter_mut()
+                .map(|i| i as &mut dyn SectionEntry)
+                .collect()
+        }
+        match section {
+            Section::Certifications => erase(&mut self.certifications),
+            Section::Awards => erase(&mut self.awards),
+            Section::Publications => erase(&mut self.publications),
+            Section::Languages => erase(&mut self.languages),
+            Section::Volunteering => erase(&mut self.volunteering),
+            Section::Custom(i) => self
+                .custom_sections
+                .get_mut(i)
+                .map_or_else(Vec::new, |s| erase(&mut s.entries)),
         }
+    }
+
+    pub fn toggle_section_entry(&mut self, section: Section, index: usize) {
+        if let Some(entry) = self.section_entries_mut(section).into_iter().nth(index) {
+            let visible = entry.is_visible();
+            entry.set_visible(!visible);
+        }
+    }
 
-        Ok(data)
+    /// Apply the job title (and its summary) at `index` in `job_titles`
+    pub fn select_job_title(&mut self, index: usize) {
+        if let Some(job) = self.job_titles.get(index) {
+            self.job_title = Some(job.title.clone());
+            self.professional_summary = Some(job.professional_summary.clone());
+        }
     }
 
     /// Create a filtered dataset with only visible items
     pub fn to_filtered_data(&self) -> FilteredResumeData {
+        let format = &self.config.date_format;
         FilteredResumeData {
             profile: {
                 let mut p = self.profile.clone().unwrap_or_else(|| Profile {
@@ -149,7 +262,14 @@ impl ResumeData {
                 .education
                 .iter()
                 .filter(|e| e.is_visible)
-                .cloned()
+                .map(|e| {
+                    let mut filtered_e = e.clone();
+                    filtered_e.date_text = e.date.clone();
+                    if let Some(date) = e.dates().format(format) {
+                        filtered_e.date = date;
+                    }
+                    filtered_e
+                })
                 .collect(),
             experience: self
                 .experience
@@ -157,12 +277,15 @@ impl ResumeData {
                 .filter(|e| e.is_visible)
                 .map(|e| {
                     let mut filtered_e = e.clone();
+                    filtered_e.date_text = e.date.clone();
+                    if let Some(date) = e.dates().format(format) {
+                        filtered_e.date = date;
+                    }
                     filtered_e.bullets = e
                         .bullets
                         .iter()
-                        .enumerate()
-                        .filter(|(i, _)| !e.hidden_bullets.contains(i))
-                        .map(|(_, b)| b.clone())
+                        .filter(|b| e.is_bullet_visible(b))
+                        .cloned()
                         .collect();
                     filtered_e
                 })
@@ -173,6 +296,71 @@ impl ResumeData {
                 .filter(|p| p.is_visible)
                 .cloned()
                 .collect(),
+            skills: self
+                .skills
+                .iter()
+                .filter(|c| c.is_visible)
+                .map(|c| {
+                    let mut filtered_c = c.clone();
+                    filtered_c.skills = c
+                        .skills
+                        .iter()
+                        .filter(|s| c.is_skill_visible(s))
+                        .cloned()
+                        .collect();
+                    filtered_c
+                })
+                .filter(|c| !c.skills.is_empty())
+                .collect(),
+            // Free-text dates elsewhere follow the same format when they are YYYY-MM
+            certifications: visible_entries(&self.certifications)
+                .into_iter()
+                .map(|mut c| {
+                    c.date = format_loose(&c.date, format);
+                    c
+                })
+                .collect(),
+            awards: visible_entries(&self.awards)
+                .into_iter()
+                .map(|mut a| {
+                    a.date = format_loose(&a.date, format);
+                    a
+                })
+                .collect(),
+            publications: visible_entries(&self.publications)
+                .into_iter()
+                .map(|mut p| {
+                    p.date = format_loose(&p.date, format);
+                    p
+                })
+                .collect(),
+            languages: visible_entries(&self.languages),
+            volunteering: visible_entries(&self.volunteering)
+                .into_iter()
+                .map(|mut v| {
+                    v.date = format_loose(&v.date, format);
+                    v
+                })
+                .collect(),
+            custom_sections: self
+                .custom_sections
+                .iter()
+                .map(|s| CustomSection {
+                    entries: visible_entries(&s.entries)
+                        .into_iter()
+                        .map(|mut entry| {
+                            for (key, value) in &mut entry.fields {
+                                if key == "date" {
+                                    *value = format_loose(value, format);
+                                }
+                            }
+                            entry
+                        })
+                        .collect(),
+                    ..s.clone()
+                })
+                .filter(|s| !s.entries.is_empty())
+                .collect(),
             job_title: self.job_title.clone().unwrap_or_else(|| " N/A".to_string()),
             professional_summary: self
                 .professional_summary
@@ -181,3 +369,450 @@ impl ResumeData {
         }
     }
 }
+
+// Reads and parses data files, recording problems instead of failing
+#[derive(Default)]
+struct Loader {
+    // Base directories first, the one being loaded last
+    roots: Vec<DataDir>,
+    sources: Sources,
+    diagnostics: Vec<Diagnostic>,
+    // Files being read, outermost first, to catch include cycles
+    including: Vec<PathBuf>,
+    // Files that set only some fields of a mapping merged across roots
+    partial: Vec<PathBuf>,
+}
+
+// Experience, education and projects can also be split into a folder
+const SPLIT: [&str; 3] = ["experience", "education", "projects"];
+
+impl Loader {
+    // The chain of `extends` in config.yaml, base first. A missing base or a
+    // cycle is reported and ends the chain there.
+    fn resolve_roots(&mut self, dir: &DataDir) {
+        let mut chain = vec![dir.clone()];
+        let mut seen: Vec<PathBuf> = dir.root().canonicalize().into_iter().collect();
+        loop {
+            let current = &chain[chain.len() - 1];
+            let config = current.file("config.yaml");
+            let Ok(content) = fs::read_to_string(&config) else {
+                break;
+            };
+            // Syntax errors are reported when the config itself is read
+            let Some(Extends {
+                extends: Some(base),
+            }) = format::parse(&config, &content, &mut Vec::new())
+            else {
+                break;
+            };
+            let path = current.root().join(&base);
+            let problem = match path.canonicalize() {
+                Err(_) => format!("Base data directory {} not found", base),
+                Ok(canonical) if seen.contains(&canonical) => {
+                    let names: Vec<String> = chain
+                        .iter()
+                        .chain([&DataDir::new(&path)])
+                        .map(|d| d.root().display().to_string())
+                        .collect();
+                    format!("`extends` cycle: {}", names.join(" -> "))
+                }
+                Ok(canonical) => {
+                    seen.push(canonical);
+                    chain.push(DataDir::new(path));
+                    continue;
+                }
+            };
+            let diagnostic = Diagnostic::error(&config, problem);
+            self.sources.insert(config, content);
+            self.diagnostics
+                .push(self.sources.locate(diagnostic, &base, 0));
+            break;
+        }
+        chain.reverse();
+        self.roots = chain;
+    }
+
+    // The directory being loaded
+    fn dir(&self) -> &DataDir {
+        &self.roots[self.roots.len() - 1]
+    }
+
+    // A file of one root, if it exists in exactly one format
+    fn single(&mut self, root: &DataDir, filename: &str) -> Option<PathBuf> {
+        let mut variants = root.variants(filename);
+        if variants.len() > 1 {
+            self.diagnostics.push(duplicate_formats(&variants));
+            return None;
+        }
+        variants.pop()
+    }
+
+    // A mapping file such as profile.yaml. Every root may set some of its
+    // fields; later roots override earlier ones.
+    fn fields<T: DeserializeOwned>(&mut self, filename: &str, required: bool) -> Option<T> {
+        let paths: Vec<PathBuf> = self
+            .roots
+            .clone()
+            .iter()
+            .filter_map(|root| self.single(root, filename))
+            .collect();
+        match paths.as_slice() {
+            [] => {
+                // Reports the missing file
+                let path = self.dir().file(filename);
+                self.read(&path, required);
+                None
+            }
+            [path] => self.parse_path(path.clone(), false),
+            [.., last] => {
+                let mut merged = serde_yaml::Mapping::new();
+                for path in &paths {
+                    let Some(content) = self.read(path, false) else {
+                        continue;
+                    };
+                    let fields: Option<serde_yaml::Mapping> =
+                        format::parse(path, &content, &mut self.diagnostics);
+                    merged.extend(fields.unwrap_or_default());
+                    self.sources.insert(path.clone(), content);
+                    self.partial.push(path.clone());
+                }
+                T::deserialize(serde_yaml::Value::Mapping(merged))
+                    .map_err(|e| {
+                        self.diagnostics
+                            .push(Diagnostic::error(last, e.to_string()))
+                    })
+                    .ok()
+            }
+        }
+    }
+
+    fn parse_path<T: DeserializeOwned>(&mut self, path: PathBuf, required: bool) -> Option<T> {
+        let content = self.read(&path, required)?;
+        let parsed = format::parse(&path, &content, &mut self.diagnostics);
+        self.sources.insert(path, content);
+        parsed
+    }
+
+    // None when the file is missing, unreadable or empty
+    fn read(&mut self, path: &Path, required: bool) -> Option<String> {
+        if !path.exists() {
+            // A missing data directory is reported once, not per file
+            if required && self.dir().root().is_dir() {
+                self.diagnostics.push(Diagnostic::warning(
+                    path,
+                    "File not found. Pass --data-dir or set RESUME_PIPELINE_DATA to point at your data folder.",
+                ));
+            }
+            return None;
+        }
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => {
+                self.diagnostics
+                    .push(Diagnostic::error(path, e.to_string()));
+                return None;
+            }
+        };
+        (!content.trim().is_empty()).then_some(content)
+    }
+
+    // Check every file read against the schema for its place in a data
+    // directory. Files included from elsewhere have no schema to check.
+    fn validate(&mut self) {
+        for (path, content) in self.sources.files() {
+            let name = self
+                .roots
+                .iter()
+                .filter_map(|root| path.strip_prefix(root.root()).ok())
+                .min_by_key(|relative| relative.components().count())
+                .and_then(|relative| schema::name_for(&relative.with_extension("yaml")));
+            if let Some(name) = name {
+                let partial = self.partial.contains(path);
+                schema::validate(name, path, content, partial, &mut self.diagnostics);
+            }
+        }
+    }
+
+    // A list file such as experience.yaml, from every root in turn. Within a
+    // root, the entries of `<name>.yaml` come before those in the files of a
+    // `<name>/` folder, in file name order. An entry of a later root replaces
+    // the one of an earlier root with the same `key`.
+    fn entries<T: DeserializeOwned>(
+        &mut self,
+        name: &str,
+        required: bool,
+        key: impl Fn(&T) -> String,
+    ) -> Vec<T> {
+        let file = format!("{}.yaml", name);
+        let mut entries: Vec<(T, Origin)> = Vec::new();
+        let mut found = false;
+        for root in self.roots.clone() {
+            let mut layer = Vec::new();
+            if let Some(path) = self.single(&root, &file) {
+                found = true;
+                layer.extend(self.parse_entries(path, name));
+            }
+            let folder = root.root().join(name);
+            if SPLIT.contains(&name) && folder.is_dir() {
+                found = true;
+                for (_, path) in self.data_files_in(&folder) {
+                    layer.extend(self.parse_entries(path, name));
+                }
+            }
+
+            let base = entries.len();
+            for entry in layer {
+                let id = key(&entry.0);
+                match entries[..base].iter().position(|(e, _)| key(e) == id) {
+                    Some(i) => entries[i] = entry,
+                    None => entries.push(entry),
+                }
+            }
+        }
+        if !found {
+            // Reports the missing file
+            let path = self.dir().file(&file);
+            self.read(&path, required);
+        }
+        let (entries, origins) = entries.into_iter().unzip();
+        self.sources.set_origins(name, origins);
+        entries
+    }
+
+    // A file holds a list of entries or a single one. A mapping whose only key
+    // is the section name (`- education: [...]`) holds a group of entries, and
+    // one whose only key is `include` stands for the entries of another file.
+    fn parse_entries<T: DeserializeOwned>(
+        &mut self,
+        path: PathBuf,
+        name: &str,
+    ) -> Vec<(T, Origin)> {
+        let Some(content) = self.read(&path, false) else {
+            return Vec::new();
+        };
+        self.sources.insert(path.clone(), content.clone());
+        self.including
+            .push(path.canonicalize().unwrap_or_else(|_| path.clone()));
+
+        let only = |item: &BTreeMap<String, serde_yaml::Value>, key: &str| {
+            item.len() == 1 && item.contains_key(key)
+        };
+        let origin = |steps: Vec<Step>| Origin {
+            file: path.clone(),
+            steps,
+        };
+        // Entry `j` of a group, itself at `steps`
+        let grouped = |steps: &[Step], entries: Vec<T>| -> Vec<(T, Origin)> {
+            entries
+                .into_iter()
+                .enumerate()
+                .map(|(j, entry)| {
+                    let steps = [steps, &[Step::key(name), Step::Index(j)]].concat();
+                    (entry, origin(steps))
+                })
+                .collect()
+        };
+        // Only the shape is checked here; errors come from the real parse below
+        let diagnostics = &mut self.diagnostics;
+        let entries = match format::parse::<Layout>(&path, &content, &mut Vec::new()) {
+            Some(Layout::One(item)) if only(&item, "include") => {
+                Some(self.include(&path, &item["include"], name))
+            }
+            Some(Layout::One(item)) if only(&item, name) => {
+                format::parse::<BTreeMap<String, Vec<T>>>(&path, &content, diagnostics)
+                    .map(|groups| grouped(&[], groups.into_values().flatten().collect()))
+            }
+            Some(Layout::One(_)) => format::parse::<T>(&path, &content, diagnostics)
+                .map(|entry| vec![(entry, origin(Vec::new()))]),
+            Some(Layout::List(items)) if items.iter().any(|i| only(i, "include")) => {
+                let mut entries = Vec::new();
+                for (i, item) in items.into_iter().enumerate() {
+                    if only(&item, "include") {
+                        entries.extend(self.include(&path, &item["include"], name));
+                        continue;
+                    }
+                    // Positions are lost once the file is split up; name the entry instead
+                    let value = serde_yaml::to_value(&item).unwrap_or_default();
+                    let parsed = if only(&item, name) {
+                        BTreeMap::<String, Vec<T>>::deserialize(value).map(|groups| {
+                            grouped(&[Step::Index(i)], groups.into_values().flatten().collect())
+                        })
+                    } else {
+                        T::deserialize(value)
+                            .map(|entry| vec![(entry, origin(vec![Step::Index(i)]))])
+                    };
+                    match parsed {
+                        Ok(parsed) => entries.extend(parsed),
+                        Err(e) => self
+                            .diagnostics
+                            .push(Diagnostic::error(&path, format!("Entry {}: {}", i + 1, e))),
+                    }
+                }
+                Some(entries)
+            }
+            Some(Layout::List(items))
+                if !items.is_empty() && items.iter().all(|i| only(i, name)) =>
+            {
+                format::parse::<Vec<BTreeMap<String, Vec<T>>>>(&path, &content, diagnostics).map(
+                    |groups| {
+                        groups
+                            .into_iter()
+                            .enumerate()
+                            .flat_map(|(i, g)| {
+                                grouped(&[Step::Index(i)], g.into_values().flatten().collect())
+                            })
+                            .collect()
+                    },
+                )
+            }
+            _ => format::parse::<Vec<T>>(&path, &content, diagnostics).map(|entries| {
+                entries
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, entry)| (entry, origin(vec![Step::Index(i)])))
+                    .collect()
+            }),
+        };
+        self.including.pop();
+        entries.unwrap_or_default()
+    }
+
+    // `include: <path>`, relative to the file it appears in
+    fn include<T: DeserializeOwned>(
+        &mut self,
+        from: &Path,
+        target: &serde_yaml::Value,
+        name: &str,
+    ) -> Vec<(T, Origin)> {
+        let Some(target) = target.as_str() else {
+            self.diagnostics
+                .push(Diagnostic::error(from, "`include` must be a file path"));
+            return Vec::new();
+        };
+        let path = from.parent().unwrap_or(Path::new("")).join(target);
+        let problem = match path.canonicalize() {
+            Err(_) => format!("Included file {} not found", target),
+            Ok(canonical) => match self.including.iter().position(|p| *p == canonical) {
+                Some(start) => {
+                    let names: Vec<String> = self.including[start..]
+                        .iter()
+                        .chain([&canonical])
+                        .map(|p| p.display().to_string())
+                        .collect();
+                    format!("Include cycle: {}", names.join(" -> "))
+                }
+                None => return self.parse_entries(path, name),
+            },
+        };
+        let diagnostic = Diagnostic::error(from, problem);
+        self.diagnostics
+            .push(self.sources.locate(diagnostic, target, 0));
+        Vec::new()
+    }
+
+    // Data files directly inside `folder`, keyed by file stem and sorted.
+    // Two formats of one stem are reported and both skipped.
+    fn data_files_in(&mut self, folder: &Path) -> Vec<(String, PathBuf)> {
+        let Ok(entries) = fs::read_dir(folder) else {
+            return Vec::new();
+        };
+        let mut by_stem: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+        for path in entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+        {
+            let is_data = path
+                .extension()
+                .is_some_and(|ext| DATA_EXTENSIONS.iter().any(|e| ext == *e));
+            if is_data && path.is_file() {
+                let stem = path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                by_stem.entry(stem).or_default().push(path);
+            }
+        }
+
+        let mut files = Vec::new();
+        for (stem, mut paths) in by_stem {
+            if paths.len() > 1 {
+                paths.sort();
+                self.diagnostics.push(duplicate_formats(&paths));
+            } else if let Some(path) = paths.pop() {
+                files.push((stem, path));
+            }
+        }
+        files
+    }
+
+    // Every data file in data/sections/ is one section, ordered by file name.
+    // A later root's section replaces an earlier one's with the same file name.
+    fn custom_sections(&mut self) -> Vec<CustomSection> {
+        let mut sections = BTreeMap::new();
+        for root in self.roots.clone() {
+            for (key, path) in self.data_files_in(&root.sections_dir()) {
+                if let Some(section) = self.parse_path::<CustomSection>(path.clone(), false) {
+                    let origins = (0..section.entries.len())
+                        .map(|i| Origin {
+                            file: path.clone(),
+                            steps: vec![Step::key("entries"), Step::Index(i)],
+                        })
+                        .collect();
+                    self.sources
+                        .set_origins(&format!("sections/{}", key), origins);
+                    sections.insert(key.clone(), CustomSection { key, ..section });
+                }
+            }
+        }
+        sections.into_values().collect()
+    }
+}
+
+// Just enough of a list file to tell its layout apart
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Layout {
+    List(Vec<BTreeMap<String, serde_yaml::Value>>),
+    One(BTreeMap<String, serde_yaml::Value>),
+}
+
+// The one config.yaml setting needed before anything else is read
+#[derive(Deserialize)]
+struct Extends {
+    #[serde(default)]
+    extends: Option<String>,
+}
+
+/// Whether a list file pulls in other files with `include`, which rewriting
+/// it would inline
+pub fn has_includes(path: &Path) -> bool {
+    let Ok(content) = fs::read_to_string(path) else {
+        return false;
+    };
+    let include = |item: &BTreeMap<String, serde_yaml::Value>| item.contains_key("include");
+    match format::parse::<Layout>(path, &content, &mut Vec::new()) {
+        Some(Layout::One(item)) => include(&item),
+        Some(Layout::List(items)) => items.iter().any(include),
+        None => false,
+    }
+}
+
+fn duplicate_formats(paths: &[PathBuf]) -> Diagnostic {
+    let names: Vec<String> = paths
+        .iter()
+        .filter_map(|p| p.file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .collect();
+    Diagnostic::error(
+        &paths[0],
+        format!(
+            "{} {} exist; keep only one of them",
+            names.join(" and "),
+            if names.len() == 2 { "both" } else { "all" }
+        ),
+    )
+}
+
+fn visible_entries<T: SectionEntry + Clone>(items: &[T]) -> Vec<T> {
+    items.iter().filter(|i| i.is_visible()).cloned().collect()
+}
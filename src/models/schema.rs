@@ -0,0 +1,273 @@
+use super::config::Config;
+use super::diagnostics::Diagnostic;
+use super::format::{self, Format, Step};
+use super::preset::Preset;
+use super::types::{
+    Award, Certification, CustomSection, Education, EducationWrapper, EntriesFile, Experience,
+    GroupedEntriesFile, JobTitle, Language, Profile, Project, ProjectsWrapper, Publication,
+    SkillCategory, Volunteering,
+};
+use jsonschema::error::{TypeKind, ValidationErrorKind};
+use jsonschema::paths::LocationSegment;
+use jsonschema::{JsonType, ValidationError};
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+// JSON Schemas for the data files, generated from the serde types so they
+// can't drift from what the loader accepts. Each is written to
+// schemas/<name>.schema.json; `files` says which data files it describes.
+pub struct DataSchema {
+    pub name: &'static str,
+    pub files: &'static str,
+    pub schema: RootSchema,
+}
+
+pub fn all() -> Vec<DataSchema> {
+    let entry = |name, files: &'static str, mut schema: RootSchema| {
+        schema.schema.metadata().title = Some(files.to_string());
+        DataSchema {
+            name,
+            files,
+            schema,
+        }
+    };
+    vec![
+        entry("profile", "profile.yaml", schema_for!(Profile)),
+        entry(
+            "jobtitles",
+            "jobtitles.yaml",
+            schema_for!(EntriesFile<JobTitle>),
+        ),
+        entry(
+            "education",
+            "education.yaml, education/*.yaml",
+            schema_for!(GroupedEntriesFile<Education, EducationWrapper>),
+        ),
+        entry(
+            "experience",
+            "experience.yaml, experience/*.yaml",
+            schema_for!(EntriesFile<Experience>),
+        ),
+        entry(
+            "projects",
+            "projects.yaml, projects/*.yaml",
+            schema_for!(GroupedEntriesFile<Project, ProjectsWrapper>),
+        ),
+        entry(
+            "skills",
+            "skills.yaml",
+            schema_for!(EntriesFile<SkillCategory>),
+        ),
+        entry(
+            "certifications",
+            "certifications.yaml",
+            schema_for!(EntriesFile<Certification>),
+        ),
+        entry("awards", "awards.yaml", schema_for!(EntriesFile<Award>)),
+        entry(
+            "publications",
+            "publications.yaml",
+            schema_for!(EntriesFile<Publication>),
+        ),
+        entry(
+            "languages",
+            "languages.yaml",
+            schema_for!(EntriesFile<Language>),
+        ),
+        entry(
+            "volunteering",
+            "volunteering.yaml",
+            schema_for!(EntriesFile<Volunteering>),
+        ),
+        entry("config", "config.yaml", schema_for!(Config)),
+        entry("section", "sections/*.yaml", schema_for!(CustomSection)),
+        entry("preset", "presets/*.yaml", schema_for!(Preset)),
+    ]
+}
+
+/// Name of the schema describing `relative` (a path inside the data dir)
+pub fn name_for(relative: &Path) -> Option<&'static str> {
+    let parent = relative
+        .parent()
+        .and_then(|p| p.to_str())
+        .unwrap_or_default();
+    match parent {
+        "sections" => Some("section"),
+        "presets" => Some("preset"),
+        "education" => Some("education"),
+        "experience" => Some("experience"),
+        "projects" => Some("projects"),
+        "" => {
+            let file = relative.to_str()?;
+            all()
+                .into_iter()
+                .find(|s| s.files.split(", ").any(|f| f == file))
+                .map(|s| s.name)
+        }
+        _ => None,
+    }
+}
+
+pub fn to_json(schema: &RootSchema) -> String {
+    let mut json = serde_json::to_string_pretty(schema).expect("schema serializes");
+    json.push('\n');
+    json
+}
+
+/// Check a data file against the schema `name` and add each problem at its
+/// position in the file, unless something is already reported there (serde
+/// stops at the first problem, the schema finds them all). A file that
+/// isn't a well-formed document is left to the parse error. `partial` files
+/// only set some fields of a mapping merged across data directories, so
+/// missing required fields aren't theirs to report.
+pub fn validate(
+    name: &str,
+    path: &Path,
+    content: &str,
+    partial: bool,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let Some(schema) = all().into_iter().find(|s| s.name == name) else {
+        return;
+    };
+    let Some(instance) = document(path, content) else {
+        return;
+    };
+    let validator = serde_json::to_value(&schema.schema)
+        .ok()
+        .and_then(|schema| jsonschema::validator_for(&schema).ok())
+        .expect("generated schemas are valid");
+
+    let reported: Vec<(PathBuf, Option<(usize, usize)>)> = diagnostics
+        .iter()
+        .filter(|d| d.is_error())
+        .map(|d| (d.file.clone(), d.location))
+        .collect();
+    let mut found: Vec<Diagnostic> = Vec::new();
+    for error in validator.iter_errors(&instance).flat_map(closest) {
+        let steps = steps(&instance, &error);
+        let problems: Vec<(String, Option<(usize, usize)>)> = match error.kind() {
+            ValidationErrorKind::Required { .. } if partial && steps.is_empty() => continue,
+            // serde_yaml reads a plain `2024` or `true` into a string field
+            ValidationErrorKind::Type { kind }
+                if Format::of(path) == Format::Yaml
+                    && (error.instance().is_number() || error.instance().is_boolean())
+                    && match kind {
+                        TypeKind::Single(ty) => *ty == JsonType::String,
+                        TypeKind::Multiple(types) => types.contains(JsonType::String),
+                    } =>
+            {
+                continue;
+            }
+            // Point at each unknown key rather than its mapping
+            ValidationErrorKind::AdditionalProperties { unexpected } => unexpected
+                .iter()
+                .map(|key| {
+                    (
+                        format!("unknown field `{}`", key),
+                        format::key_position(path, content, &steps, key),
+                    )
+                })
+                .collect(),
+            // Worded like serde's errors
+            ValidationErrorKind::Required { property } => vec![(
+                format!("missing field `{}`", property.as_str().unwrap_or_default()),
+                format::position(path, content, &steps),
+            )],
+            _ => vec![(error.to_string(), format::position(path, content, &steps))],
+        };
+        for (message, position) in problems {
+            if position.is_some() && reported.contains(&(path.to_path_buf(), position)) {
+                continue;
+            }
+            let diagnostic = Diagnostic::error(path, message);
+            let diagnostic = match position {
+                Some((line, column)) => diagnostic.at(content, line, column),
+                None => diagnostic,
+            };
+            if !found.contains(&diagnostic) {
+                found.push(diagnostic);
+            }
+        }
+    }
+    diagnostics.extend(found);
+}
+
+// The file as JSON, with a TOML list file's entries taken out of the key
+// named after the file, as `format::parse` does
+fn document(path: &Path, content: &str) -> Option<Value> {
+    match Format::of(path) {
+        Format::Yaml => serde_yaml::from_str(content).ok(),
+        Format::Json => serde_json::from_str(content).ok(),
+        Format::Toml => {
+            let mut table: toml::Table = toml::from_str(content).ok()?;
+            let stem = path.file_stem()?.to_str()?;
+            if table.len() == 1 && table.get(stem).is_some_and(toml::Value::is_array) {
+                serde_json::to_value(table.remove(stem)?).ok()
+            } else {
+                serde_json::to_value(table).ok()
+            }
+        }
+    }
+}
+
+// An `anyOf` error only says that no alternative matched. Report the errors
+// of the alternative that got furthest into the file instead, e.g. the
+// unknown key of an entry rather than "not an include either".
+fn closest(error: ValidationError<'_>) -> Vec<ValidationError<'static>> {
+    let error = error.to_owned();
+    if !matches!(
+        error.kind(),
+        ValidationErrorKind::AnyOf { .. } | ValidationErrorKind::OneOfNotValid { .. }
+    ) {
+        return vec![error];
+    }
+    let message = error.to_string();
+    let (_, kind, ..) = error.into_parts();
+    let (ValidationErrorKind::AnyOf { context } | ValidationErrorKind::OneOfNotValid { context }) =
+        kind
+    else {
+        unreachable!()
+    };
+    let depth = |errors: &Vec<ValidationError<'static>>| {
+        errors
+            .iter()
+            .map(|e| e.instance_path().into_iter().count())
+            .max()
+            .unwrap_or(0)
+    };
+    // The first of the deepest, since entries are listed before includes
+    let mut best: Option<Vec<ValidationError<'static>>> = None;
+    for branch in context {
+        if best.as_ref().is_none_or(|b| depth(&branch) > depth(b)) {
+            best = Some(branch);
+        }
+    }
+    match best {
+        Some(branch) if !branch.is_empty() => branch.into_iter().flat_map(closest).collect(),
+        _ => vec![ValidationError::custom(message)],
+    }
+}
+
+// The error's place in the file as steps from the top
+fn steps(instance: &Value, error: &ValidationError<'_>) -> Vec<Step> {
+    let mut node = Some(instance);
+    error
+        .instance_path()
+        .into_iter()
+        .map(|segment| {
+            let step = match (segment, node) {
+                (LocationSegment::Index(i), Some(Value::Array(_))) => Step::Index(i),
+                (LocationSegment::Index(i), _) => Step::Key(i.to_string()),
+                (LocationSegment::Property(key), _) => Step::Key(key.to_string()),
+            };
+            node = match &step {
+                Step::Index(i) => node.and_then(|n| n.get(*i)),
+                Step::Key(key) => node.and_then(|n| n.get(key.as_str())),
+            };
+            step
+        })
+        .collect()
+}
@@ -1,4 +1,9 @@
+use super::date::{DateEnd, DateRange, YearMonth};
+use schemars::JsonSchema;
+use schemars::r#gen::SchemaGenerator;
+use schemars::schema::Schema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use typst::foundations::{Dict, IntoValue, Value};
 
 // 1. HELPER: Defaults new items to "Checked" in the UI
@@ -6,9 +11,45 @@ pub fn default_true() -> bool {
     true
 }
 
+// HELPER: Short id derived from an entry's content (64-bit FNV-1a, top 32 bits
+// kept). Implemented by hand because std's hasher is not guaranteed stable
+// across releases. Identical content gives identical ids; `lint` flags those.
+pub fn content_id(parts: &[&str]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            // Separator so ["ab", "c"] and ["a", "bc"] differ
+            hash ^= 0x1f;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+        for byte in part.trim().bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{:08x}", hash >> 32)
+}
+
+// HELPER: Lowercase, dash-separated name, safe to use as a file name
+pub fn slugify(parts: &[&str]) -> String {
+    let joined = parts.join(" ");
+    let mut slug = String::with_capacity(joined.len());
+    for c in joined.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.ends_with('-') && !slug.is_empty() {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
 // 2. DATA STRUCTURES
+// Unknown keys are errors, so a misspelled optional field is reported by
+// `check` instead of being dropped silently.
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct Profile {
     pub name: String,
     pub email: String,
@@ -25,45 +66,96 @@ pub struct Profile {
     pub show_phone: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct JobTitle {
     pub title: String,
     pub professional_summary: String,
 }
 
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct Education {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     pub school: String,
     pub degree: String,
+    #[serde(default)]
     pub status: String,
+    // Free-text date, replaced by the formatted start/end when those are set
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub date: String,
+    // The `date` as written, kept by `to_filtered_data` when it replaces `date`
+    #[serde(default, skip_deserializing, skip_serializing_if = "String::is_empty")]
+    #[schemars(skip)]
+    pub date_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<YearMonth>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<DateEnd>,
 
     // UI STATE
     #[serde(skip, default = "default_true")]
     pub is_visible: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct Experience {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     pub role: String,
     pub company: String,
+    #[serde(default)]
     pub location: String,
+    // Free-text date ("June 2023 - Current"), kept for compatibility.
+    // Replaced by the formatted start/end when those are set.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub date: String,
+    // The `date` as written, kept by `to_filtered_data` when it replaces `date`
+    #[serde(default, skip_deserializing, skip_serializing_if = "String::is_empty")]
+    #[schemars(skip)]
+    pub date_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<YearMonth>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<DateEnd>,
+    #[serde(default)]
     pub summary: String,
-    pub bullets: Vec<String>,
+    #[serde(default)]
+    pub bullets: Vec<Bullet>,
 
     // UI STATE: Not in YAML, only in App
     #[serde(skip, default = "default_true")]
     pub is_visible: bool,
+    // Ids of the bullets toggled off
     #[serde(skip)]
-    pub hidden_bullets: Vec<usize>,
+    pub hidden_bullets: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+// A bullet is either a plain string or `{ id, text }` when it needs a fixed id
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged, deny_unknown_fields)]
+pub enum Bullet {
+    Text(String),
+    Detailed {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        text: String,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct Project {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    #[serde(default)]
     pub description: String,
+    #[serde(default)]
     pub tech_stack: Vec<String>,
 
     // UI STATE
@@ -71,6 +163,459 @@ pub struct Project {
     pub is_visible: bool,
 }
 
+// Stable keys used for visibility state and presets instead of list positions.
+// An explicit `id` in the YAML wins; otherwise it is derived from the content.
+impl Education {
+    pub fn stable_id(&self) -> String {
+        self.id
+            .clone()
+            .unwrap_or_else(|| content_id(&[&self.school, &self.degree]))
+    }
+
+    pub fn dates(&self) -> DateRange {
+        DateRange {
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl Experience {
+    pub fn stable_id(&self) -> String {
+        self.id
+            .clone()
+            .unwrap_or_else(|| content_id(&[&self.role, &self.company]))
+    }
+
+    pub fn dates(&self) -> DateRange {
+        DateRange {
+            start: self.start,
+            end: self.end,
+        }
+    }
+
+    pub fn is_bullet_visible(&self, bullet: &Bullet) -> bool {
+        !self.hidden_bullets.contains(&bullet.stable_id())
+    }
+
+    pub fn toggle_bullet(&mut self, index: usize) {
+        let Some(id) = self.bullets.get(index).map(Bullet::stable_id) else {
+            return;
+        };
+        if self.hidden_bullets.contains(&id) {
+            self.hidden_bullets.retain(|x| x != &id);
+        } else {
+            self.hidden_bullets.push(id);
+        }
+    }
+}
+
+impl Project {
+    pub fn stable_id(&self) -> String {
+        self.id
+            .clone()
+            .unwrap_or_else(|| content_id(&[&self.title]))
+    }
+}
+
+impl SkillCategory {
+    pub fn stable_id(&self) -> String {
+        self.id
+            .clone()
+            .unwrap_or_else(|| content_id(&[&self.category]))
+    }
+
+    pub fn is_skill_visible(&self, skill: &Skill) -> bool {
+        !self.hidden_skills.contains(&skill.stable_id())
+    }
+
+    pub fn toggle_skill(&mut self, index: usize) {
+        let Some(id) = self.skills.get(index).map(Skill::stable_id) else {
+            return;
+        };
+        if self.hidden_skills.contains(&id) {
+            self.hidden_skills.retain(|x| x != &id);
+        } else {
+            self.hidden_skills.push(id);
+        }
+    }
+}
+
+impl Skill {
+    pub fn stable_id(&self) -> String {
+        self.id.clone().unwrap_or_else(|| content_id(&[&self.name]))
+    }
+}
+
+impl Bullet {
+    pub fn text(&self) -> &str {
+        match self {
+            Bullet::Text(text) | Bullet::Detailed { text, .. } => text,
+        }
+    }
+
+    pub fn stable_id(&self) -> String {
+        match self {
+            Bullet::Detailed { id: Some(id), .. } => id.clone(),
+            _ => content_id(&[self.text()]),
+        }
+    }
+}
+
+impl From<&str> for Bullet {
+    fn from(text: &str) -> Self {
+        Bullet::Text(text.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SkillCategory {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub category: String,
+    pub skills: Vec<Skill>,
+
+    // UI STATE
+    #[serde(skip, default = "default_true")]
+    pub is_visible: bool,
+    // Ids of the skills toggled off
+    #[serde(skip)]
+    pub hidden_skills: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Skill {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proficiency: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub years: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Certification {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    pub issuer: String,
+    #[serde(default)]
+    pub date: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credential_url: Option<String>,
+
+    // UI STATE
+    #[serde(skip, default = "default_true")]
+    pub is_visible: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Award {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub title: String,
+    #[serde(default)]
+    pub issuer: String,
+    #[serde(default)]
+    pub date: String,
+    #[serde(default)]
+    pub description: String,
+
+    // UI STATE
+    #[serde(skip, default = "default_true")]
+    pub is_visible: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Publication {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub title: String,
+    pub venue: String,
+    #[serde(default)]
+    pub authors: Vec<String>,
+    #[serde(default)]
+    pub date: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    // UI STATE
+    #[serde(skip, default = "default_true")]
+    pub is_visible: bool,
+}
+
+// A spoken language, e.g. "Swahili" / "Native"
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Language {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub language: String,
+    pub proficiency: String,
+
+    // UI STATE
+    #[serde(skip, default = "default_true")]
+    pub is_visible: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Volunteering {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub role: String,
+    pub organization: String,
+    #[serde(default)]
+    pub location: String,
+    #[serde(default)]
+    pub date: String,
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub bullets: Vec<Bullet>,
+
+    // UI STATE
+    #[serde(skip, default = "default_true")]
+    pub is_visible: bool,
+}
+
+// Common behaviour of the simple toggle-only sections
+// (certifications, awards, publications, languages, volunteering, custom)
+pub trait SectionEntry {
+    fn stable_id(&self) -> String;
+    // One-line description for the TUI list
+    fn label(&self) -> String;
+    fn is_visible(&self) -> bool;
+    fn set_visible(&mut self, visible: bool);
+}
+
+// Appends " (detail)" only when the detail is present
+fn with_detail(main: String, detail: &str) -> String {
+    if detail.trim().is_empty() {
+        main
+    } else {
+        format!("{} ({})", main, detail.trim())
+    }
+}
+
+macro_rules! section_visibility {
+    () => {
+        fn is_visible(&self) -> bool {
+            self.is_visible
+        }
+
+        fn set_visible(&mut self, visible: bool) {
+            self.is_visible = visible;
+        }
+    };
+}
+
+impl SectionEntry for Certification {
+    fn stable_id(&self) -> String {
+        self.id
+            .clone()
+            .unwrap_or_else(|| content_id(&[&self.name, &self.issuer]))
+    }
+
+    fn label(&self) -> String {
+        with_detail(format!("{} - {}", self.name, self.issuer), &self.date)
+    }
+
+    section_visibility!();
+}
+
+impl SectionEntry for Award {
+    fn stable_id(&self) -> String {
+        self.id
+            .clone()
+            .unwrap_or_else(|| content_id(&[&self.title, &self.issuer]))
+    }
+
+    fn label(&self) -> String {
+        let main = if self.issuer.is_empty() {
+            self.title.clone()
+        } else {
+            format!("{} - {}", self.title, self.issuer)
+        };
+        with_detail(main, &self.date)
+    }
+
+    section_visibility!();
+}
+
+impl SectionEntry for Publication {
+    fn stable_id(&self) -> String {
+        self.id
+            .clone()
+            .unwrap_or_else(|| content_id(&[&self.title, &self.venue]))
+    }
+
+    fn label(&self) -> String {
+        with_detail(format!("{} - {}", self.title, self.venue), &self.date)
+    }
+
+    section_visibility!();
+}
+
+impl SectionEntry for Language {
+    fn stable_id(&self) -> String {
+        self.id
+            .clone()
+            .unwrap_or_else(|| content_id(&[&self.language]))
+    }
+
+    fn label(&self) -> String {
+        with_detail(self.language.clone(), &self.proficiency)
+    }
+
+    section_visibility!();
+}
+
+impl SectionEntry for CustomEntry {
+    fn stable_id(&self) -> String {
+        self.id
+            .clone()
+            .unwrap_or_else(|| content_id(&[self.heading()]))
+    }
+
+    fn label(&self) -> String {
+        with_detail(
+            self.heading().to_string(),
+            self.field("date").unwrap_or_default(),
+        )
+    }
+
+    section_visibility!();
+}
+
+impl SectionEntry for Volunteering {
+    fn stable_id(&self) -> String {
+        self.id
+            .clone()
+            .unwrap_or_else(|| content_id(&[&self.role, &self.organization]))
+    }
+
+    fn label(&self) -> String {
+        format!("{} at {}", self.role, self.organization)
+    }
+
+    section_visibility!();
+}
+
+// A user-defined section read from data/sections/<key>.yaml
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomSection {
+    // File stem, filled in by the loader
+    #[serde(skip)]
+    pub key: String,
+    pub title: String,
+    #[serde(default)]
+    pub entries: Vec<CustomEntry>,
+}
+
+// One entry of a custom section: free-form string fields plus optional bullets
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CustomEntry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub bullets: Vec<Bullet>,
+    // Every other key, in file order
+    #[schemars(with = "BTreeMap<String, ScalarField>")]
+    #[serde(
+        flatten,
+        deserialize_with = "scalar_fields",
+        serialize_with = "ordered_fields"
+    )]
+    pub fields: Vec<(String, String)>,
+
+    // UI STATE
+    #[serde(skip, default = "default_true")]
+    pub is_visible: bool,
+}
+
+impl CustomEntry {
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    // "title" or "name" if present, otherwise the first field
+    pub fn heading(&self) -> &str {
+        self.field("title")
+            .or_else(|| self.field("name"))
+            .or_else(|| self.fields.first().map(|(_, value)| value.as_str()))
+            .unwrap_or_default()
+    }
+}
+
+// Schema for a custom field value: any plain (non-nested) YAML value
+struct ScalarField;
+
+impl JsonSchema for ScalarField {
+    fn schema_name() -> String {
+        "ScalarField".to_string()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        serde_json::from_value(serde_json::json!({
+            "type": ["string", "number", "boolean", "null"]
+        }))
+        .expect("valid schema")
+    }
+}
+
+// Numbers and booleans are accepted and kept as text ("year: 2024")
+fn scalar_fields<'de, D>(deserializer: D) -> Result<Vec<(String, String)>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    use serde_yaml::Value as Yaml;
+
+    let mapping = serde_yaml::Mapping::deserialize(deserializer)?;
+    mapping
+        .into_iter()
+        .map(|(key, value)| {
+            let key = match key {
+                Yaml::String(key) => key,
+                other => return Err(D::Error::custom(format!("invalid field name {:?}", other))),
+            };
+            let value = match value {
+                Yaml::String(s) => s,
+                Yaml::Number(n) => n.to_string(),
+                Yaml::Bool(b) => b.to_string(),
+                Yaml::Null => String::new(),
+                _ => {
+                    return Err(D::Error::custom(format!(
+                        "field `{}` must be a plain value",
+                        key
+                    )));
+                }
+            };
+            Ok((key, value))
+        })
+        .collect()
+}
+
+fn ordered_fields<S>(fields: &[(String, String)], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_map(fields.iter().map(|(k, v)| (k, v)))
+}
+
 // Filtered version without UI state fields for Typst
 #[derive(Debug, Clone, Serialize)]
 pub struct FilteredResumeData {
@@ -78,22 +623,74 @@ pub struct FilteredResumeData {
     pub education: Vec<Education>,
     pub experience: Vec<Experience>,
     pub projects: Vec<Project>,
+    pub skills: Vec<SkillCategory>,
+    pub certifications: Vec<Certification>,
+    pub awards: Vec<Award>,
+    pub publications: Vec<Publication>,
+    pub languages: Vec<Language>,
+    pub volunteering: Vec<Volunteering>,
+    pub custom_sections: Vec<CustomSection>,
     pub job_title: String,
     pub professional_summary: String,
 }
 
-// Wrapper for education YAML parsing
-#[derive(Debug, Deserialize)]
+// Older education.yaml layout, still accepted by the loader
+/// One `- education:` item of education.yaml, holding a list of entries
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct EducationWrapper {
     pub education: Vec<Education>,
 }
 
-// Wrapper for projects YAML parsing
-#[derive(Debug, Deserialize)]
+// Older projects.yaml layout, still accepted by the loader
+/// One `- projects:` item of projects.yaml, holding a list of entries
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct ProjectsWrapper {
     pub projects: Vec<Project>,
 }
 
+// What a list file, or one file in its folder (experience/acme.yaml), may
+// hold. The loader picks the layout itself; these only feed the schemas.
+#[derive(JsonSchema)]
+#[serde(untagged)]
+#[allow(dead_code)]
+pub enum EntriesFile<T> {
+    /// A list of entries
+    List(Vec<OrInclude<T>>),
+    /// A single entry
+    One(OrInclude<T>),
+}
+
+/// Education and projects also accept their older grouped layout
+#[derive(JsonSchema)]
+#[serde(untagged)]
+#[allow(dead_code)]
+pub enum GroupedEntriesFile<T, Group> {
+    /// A list of entries
+    List(Vec<OrInclude<T>>),
+    /// A single entry
+    One(OrInclude<T>),
+    /// A list of `- education:` / `- projects:` groups
+    Grouped(Vec<Group>),
+}
+
+#[derive(JsonSchema)]
+#[serde(untagged)]
+#[allow(dead_code)]
+pub enum OrInclude<T> {
+    Entry(T),
+    Include(Include),
+}
+
+/// The entries of another file, in its place
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+pub struct Include {
+    /// Path to the file, relative to this one
+    include: String,
+}
+
 // Manual implementation of IntoValue/IntoDict to resolve version conflicts
 
 impl IntoValue for Profile {
@@ -110,25 +707,46 @@ impl IntoValue for Profile {
     }
 }
 
+// `date` is the display string and `date_text` the free-text `date` as written;
+// `start`/`end` are the raw "2023-06" / "present" values and `duration` the
+// time spent ("2 yrs 3 mos"), or none when unset
+fn insert_dates(dict: &mut Dict, dates: DateRange, date: String, date_text: String) {
+    dict.insert("date".into(), date.into_value());
+    dict.insert("date_text".into(), date_text.into_value());
+    dict.insert("start".into(), dates.start.map(String::from).into_value());
+    dict.insert("end".into(), dates.end.map(String::from).into_value());
+    dict.insert(
+        "duration".into(),
+        dates.duration(YearMonth::today()).into_value(),
+    );
+}
+
 impl IntoValue for Education {
     fn into_value(self) -> Value {
+        let dates = self.dates();
         let mut dict = Dict::new();
+        dict.insert("id".into(), self.stable_id().into_value());
         dict.insert("school".into(), self.school.into_value());
         dict.insert("degree".into(), self.degree.into_value());
         dict.insert("status".into(), self.status.into_value());
+        insert_dates(&mut dict, dates, self.date, self.date_text);
         Value::Dict(dict)
     }
 }
 
 impl IntoValue for Experience {
     fn into_value(self) -> Value {
+        let dates = self.dates();
         let mut dict = Dict::new();
+        dict.insert("id".into(), self.stable_id().into_value());
         dict.insert("role".into(), self.role.into_value());
         dict.insert("company".into(), self.company.into_value());
         dict.insert("location".into(), self.location.into_value());
-        dict.insert("date".into(), self.date.into_value());
+        insert_dates(&mut dict, dates, self.date, self.date_text);
         dict.insert("summary".into(), self.summary.into_value());
-        dict.insert("bullets".into(), self.bullets.into_value());
+        // Templates receive bullets as plain strings
+        let bullets: Vec<String> = self.bullets.iter().map(|b| b.text().to_string()).collect();
+        dict.insert("bullets".into(), bullets.into_value());
         Value::Dict(dict)
     }
 }
@@ -136,6 +754,7 @@ impl IntoValue for Experience {
 impl IntoValue for Project {
     fn into_value(self) -> Value {
         let mut dict = Dict::new();
+        dict.insert("id".into(), self.stable_id().into_value());
         dict.insert("title".into(), self.title.into_value());
         dict.insert("url".into(), self.url.into_value());
         dict.insert("description".into(), self.description.into_value());
@@ -144,6 +763,113 @@ impl IntoValue for Project {
     }
 }
 
+impl IntoValue for SkillCategory {
+    fn into_value(self) -> Value {
+        let mut dict = Dict::new();
+        dict.insert("id".into(), self.stable_id().into_value());
+        dict.insert("category".into(), self.category.into_value());
+        dict.insert("skills".into(), self.skills.into_value());
+        Value::Dict(dict)
+    }
+}
+
+impl IntoValue for Skill {
+    fn into_value(self) -> Value {
+        let mut dict = Dict::new();
+        dict.insert("id".into(), self.stable_id().into_value());
+        dict.insert("name".into(), self.name.into_value());
+        dict.insert("proficiency".into(), self.proficiency.into_value());
+        dict.insert("years".into(), self.years.map(i64::from).into_value());
+        Value::Dict(dict)
+    }
+}
+
+impl IntoValue for Certification {
+    fn into_value(self) -> Value {
+        let mut dict = Dict::new();
+        dict.insert("id".into(), self.stable_id().into_value());
+        dict.insert("name".into(), self.name.into_value());
+        dict.insert("issuer".into(), self.issuer.into_value());
+        dict.insert("date".into(), self.date.into_value());
+        dict.insert("credential_url".into(), self.credential_url.into_value());
+        Value::Dict(dict)
+    }
+}
+
+impl IntoValue for Award {
+    fn into_value(self) -> Value {
+        let mut dict = Dict::new();
+        dict.insert("id".into(), self.stable_id().into_value());
+        dict.insert("title".into(), self.title.into_value());
+        dict.insert("issuer".into(), self.issuer.into_value());
+        dict.insert("date".into(), self.date.into_value());
+        dict.insert("description".into(), self.description.into_value());
+        Value::Dict(dict)
+    }
+}
+
+impl IntoValue for Publication {
+    fn into_value(self) -> Value {
+        let mut dict = Dict::new();
+        dict.insert("id".into(), self.stable_id().into_value());
+        dict.insert("title".into(), self.title.into_value());
+        dict.insert("venue".into(), self.venue.into_value());
+        dict.insert("authors".into(), self.authors.into_value());
+        dict.insert("date".into(), self.date.into_value());
+        dict.insert("doi".into(), self.doi.into_value());
+        dict.insert("url".into(), self.url.into_value());
+        Value::Dict(dict)
+    }
+}
+
+impl IntoValue for Language {
+    fn into_value(self) -> Value {
+        let mut dict = Dict::new();
+        dict.insert("id".into(), self.stable_id().into_value());
+        dict.insert("language".into(), self.language.into_value());
+        dict.insert("proficiency".into(), self.proficiency.into_value());
+        Value::Dict(dict)
+    }
+}
+
+impl IntoValue for Volunteering {
+    fn into_value(self) -> Value {
+        let mut dict = Dict::new();
+        dict.insert("id".into(), self.stable_id().into_value());
+        dict.insert("role".into(), self.role.into_value());
+        dict.insert("organization".into(), self.organization.into_value());
+        dict.insert("location".into(), self.location.into_value());
+        dict.insert("date".into(), self.date.into_value());
+        dict.insert("summary".into(), self.summary.into_value());
+        let bullets: Vec<String> = self.bullets.iter().map(|b| b.text().to_string()).collect();
+        dict.insert("bullets".into(), bullets.into_value());
+        Value::Dict(dict)
+    }
+}
+
+impl IntoValue for CustomEntry {
+    fn into_value(self) -> Value {
+        let mut dict = Dict::new();
+        dict.insert("id".into(), self.stable_id().into_value());
+        for (key, value) in self.fields {
+            dict.insert(key.into(), value.into_value());
+        }
+        let bullets: Vec<String> = self.bullets.iter().map(|b| b.text().to_string()).collect();
+        dict.insert("bullets".into(), bullets.into_value());
+        Value::Dict(dict)
+    }
+}
+
+impl IntoValue for CustomSection {
+    fn into_value(self) -> Value {
+        let mut dict = Dict::new();
+        dict.insert("key".into(), self.key.into_value());
+        dict.insert("title".into(), self.title.into_value());
+        dict.insert("entries".into(), self.entries.into_value());
+        Value::Dict(dict)
+    }
+}
+
 impl From<FilteredResumeData> for Dict {
     fn from(val: FilteredResumeData) -> Self {
         let mut dict = Dict::new();
@@ -151,6 +877,13 @@ impl From<FilteredResumeData> for Dict {
         dict.insert("education".into(), val.education.into_value());
         dict.insert("experience".into(), val.experience.into_value());
         dict.insert("projects".into(), val.projects.into_value());
+        dict.insert("skills".into(), val.skills.into_value());
+        dict.insert("certifications".into(), val.certifications.into_value());
+        dict.insert("awards".into(), val.awards.into_value());
+        dict.insert("publications".into(), val.publications.into_value());
+        dict.insert("languages".into(), val.languages.into_value());
+        dict.insert("volunteering".into(), val.volunteering.into_value());
+        dict.insert("custom_sections".into(), val.custom_sections.into_value());
         dict.insert("job_title".into(), val.job_title.into_value());
         dict.insert(
             "professional_summary".into(),
@@ -159,5 +892,3 @@ impl From<FilteredResumeData> for Dict {
         dict
     }
 }
-
-
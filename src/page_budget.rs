@@ -0,0 +1,90 @@
+use color_eyre::{Result, eyre::eyre};
+
+use crate::data_dir::DataDir;
+use crate::models::ResumeData;
+use crate::pdf::compile;
+
+// An experience bullet the suggest-cuts mode proposes to hide
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cut {
+    // Index into `experience`
+    pub job: usize,
+    pub bullet_id: String,
+    // "Developer at Acme", for display
+    pub owner: String,
+    pub text: String,
+}
+
+/// Number of pages the resume currently compiles to
+pub fn page_count(data: &ResumeData, dir: &DataDir) -> Result<usize> {
+    let (document, _) = compile(data, dir)?;
+    Ok(document.pages.len())
+}
+
+/// Visible experience bullets to hide, from the bottom of the resume up, so
+/// that it fits in `max_pages`. Empty when it already fits. `pages` is what
+/// `data` compiles to now, which the caller already knows.
+pub fn suggest_cuts(
+    data: &ResumeData,
+    dir: &DataDir,
+    max_pages: usize,
+    pages: usize,
+) -> Result<Vec<Cut>> {
+    let candidates: Vec<Cut> = data
+        .experience
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, job)| job.is_visible)
+        .flat_map(|(i, job)| {
+            job.bullets
+                .iter()
+                .rev()
+                .filter(|bullet| job.is_bullet_visible(bullet))
+                .map(move |bullet| Cut {
+                    job: i,
+                    bullet_id: bullet.stable_id(),
+                    owner: format!("{} at {}", job.role, job.company),
+                    text: bullet.text().to_string(),
+                })
+        })
+        .collect();
+
+    let fits = |count: usize| -> Result<bool> {
+        let mut trimmed = data.clone();
+        apply_cuts(&mut trimmed, &candidates[..count]);
+        Ok(page_count(&trimmed, dir)? <= max_pages)
+    };
+    if pages <= max_pages {
+        return Ok(Vec::new());
+    }
+    if !fits(candidates.len())? {
+        return Err(eyre!(
+            "Hiding every bullet is not enough to fit on {} page{}; hide whole entries instead",
+            max_pages,
+            if max_pages == 1 { "" } else { "s" }
+        ));
+    }
+
+    // Fewer bullets never means more pages, so the smallest cut can be bisected
+    let (mut too_few, mut enough) = (0, candidates.len());
+    while enough - too_few > 1 {
+        let mid = (too_few + enough) / 2;
+        if fits(mid)? {
+            enough = mid;
+        } else {
+            too_few = mid;
+        }
+    }
+    Ok(candidates[..enough].to_vec())
+}
+
+pub fn apply_cuts(data: &mut ResumeData, cuts: &[Cut]) {
+    for cut in cuts {
+        if let Some(job) = data.experience.get_mut(cut.job)
+            && !job.hidden_bullets.contains(&cut.bullet_id)
+        {
+            job.hidden_bullets.push(cut.bullet_id.clone());
+        }
+    }
+}
@@ -1,10 +1,20 @@
-use color_eyre::Result;
+use color_eyre::{Result, eyre::eyre};
+use std::fmt;
 use std::fs;
+use std::path::{Path, PathBuf};
 use typst::foundations::Dict;
+use typst::model::Document;
 use typst_pdf::PdfOptions;
 
+use crate::data_dir::DataDir;
+use crate::docx;
+use crate::html;
+use crate::json_resume;
 use crate::models::ResumeData;
-use crate::typst_backend::ResumeWorld;
+use crate::models::diagnostics::{Diagnostic, summary};
+use crate::templates;
+use crate::text_export::{self, DEFAULT_TEXT_WIDTH, TextStyle};
+use crate::typst_backend::{Fonts, ResumeWorld};
 
 // function to get the current year from the system to be used in the output file name
 fn get_current_year() -> u64 {
@@ -31,49 +41,261 @@ fn get_current_year() -> u64 {
     year
 }
 
-// PDF GENERATION
-pub fn generate_pdf(data: &ResumeData) -> Result<String> {
-    let current_dir = std::env::current_dir()?;
-    let output_dir = current_dir.join("data").join("output");
+pub const DEFAULT_PNG_DPI: f32 = 144.0;
+// Below this text is unreadable, above it a page takes hundreds of megabytes
+pub const PNG_DPI_RANGE: std::ops::RangeInclusive<f32> = 36.0..=1200.0;
 
-    if !output_dir.exists() {
-        fs::create_dir_all(&output_dir)?;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    Pdf,
+    Png,
+    Svg,
+    /// Plain text for pasting into web forms
+    Txt,
+    /// Markdown
+    Md,
+    /// Self-contained web page
+    Html,
+    /// Word document
+    Docx,
+    /// JSON Resume (jsonresume.org)
+    Json,
+}
+
+impl OutputFormat {
+    /// Written from the compiled Typst document rather than the data
+    pub fn is_typst(self) -> bool {
+        matches!(self, Self::Pdf | Self::Png | Self::Svg)
     }
+}
 
-    let template_path = current_dir
-        .join("data")
-        .join("templates")
-        .join("default_resume_template.typ");
+// Where the files went, plus anything the user should know about them
+#[derive(Debug)]
+pub struct ExportOutput {
+    pub paths: Vec<String>,
+    pub warnings: Vec<Diagnostic>,
+}
 
-    if !template_path.exists() {
-        return Err(color_eyre::eyre::eyre!(
-            "Template file not found at: {:?}.\nPlease ensure the 'data' folder containing your templates is in the same directory as the executable.",
-            template_path
-        ));
+// The template did not compile. Errors come first, then any warnings.
+#[derive(Debug)]
+pub struct CompileError {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Template failed to compile ({})",
+            summary(&self.diagnostics)
+        )?;
+        for diagnostic in &self.diagnostics {
+            write!(f, "\n\n{}", diagnostic)?;
+        }
+        Ok(())
     }
+}
+
+impl std::error::Error for CompileError {}
 
-    let template_content = fs::read_to_string(&template_path)?;
+// Compile the selected template with the filtered data. Shared by the exports
+// and the TUI preview, so both always see the same document.
+pub fn compile(data: &ResumeData, dir: &DataDir) -> Result<(Document, Vec<Diagnostic>)> {
+    let template_path = templates::resolve(dir, data.template.as_deref())?;
 
     // Convert Data
     let filtered_data = data.to_filtered_data();
     let inputs: Dict = filtered_data.into();
 
     // Create World
-    let world = ResumeWorld::new(template_content, inputs);
+    let fonts = Fonts::load(&dir.fonts_dir(), data.config.system_fonts);
+    let world = ResumeWorld::new(&dir.templates_dir(), &template_path, inputs, fonts);
 
     // Compile
-    let document = typst::compile(&world)
-        .output
-        .map_err(|err| color_eyre::eyre::eyre!("Typst Compile Errors: {:?}", err))?;
+    let compiled = typst::compile(&world);
+    let mut warnings: Vec<Diagnostic> = compiled
+        .warnings
+        .iter()
+        .map(|warning| world.diagnostic(warning))
+        .collect();
+    // Typst silently falls back to another font; say how to get the real one
+    for warning in &mut warnings {
+        if warning.message.starts_with("unknown font family") {
+            warning.notes.push(format!(
+                "hint: add it to {} or run `fonts` to list what is available",
+                dir.fonts_dir().display()
+            ));
+        }
+    }
+    let document = compiled.output.map_err(|errors| CompileError {
+        diagnostics: errors
+            .iter()
+            .map(|error| world.diagnostic(error))
+            .chain(warnings.iter().cloned())
+            .collect(),
+    })?;
+    Ok((document, warnings))
+}
 
-    // It takes 2 arguments: the document and the options.
-    let options = PdfOptions::default();
+// EXPORT
+pub fn export(data: &ResumeData, dir: &DataDir, formats: &[OutputFormat]) -> Result<ExportOutput> {
+    export_compiled(data, dir, formats, None)
+}
 
-    // The ? operator unwraps the Ok(Vec<u8>) or returns the Err.
-    let pdf_data = typst_pdf::pdf(&document, &options)
-        .map_err(|e| color_eyre::eyre::eyre!("PDF Export Error: {:?}", e))?;
+/// `export` with the result of `compile` for this same data, so a caller that
+/// already compiled (to count pages, say) doesn't compile twice. With None,
+/// it compiles here when a Typst format is asked for.
+pub fn export_compiled(
+    data: &ResumeData,
+    dir: &DataDir,
+    formats: &[OutputFormat],
+    compiled: Option<(Document, Vec<Diagnostic>)>,
+) -> Result<ExportOutput> {
+    let dpi = data.config.png_dpi.unwrap_or(DEFAULT_PNG_DPI);
+    if formats.contains(&OutputFormat::Png) && !PNG_DPI_RANGE.contains(&dpi) {
+        return Err(eyre!(
+            "PNG resolution {} dpi is out of range; use {} to {}",
+            dpi,
+            PNG_DPI_RANGE.start(),
+            PNG_DPI_RANGE.end()
+        ));
+    }
+    // Compiled once; every Typst format is written from the same document.
+    // The other formats work from the data alone and skip the compile.
+    let (document, warnings) = match compiled {
+        Some(compiled) => compiled,
+        None if formats.iter().any(|f| f.is_typst()) => compile(data, dir)?,
+        None => (Document::default(), Vec::new()),
+    };
+    let output_dir = dir.output_dir();
 
-    // --- Generate dynamic filename ---
+    if !output_dir.exists() {
+        fs::create_dir_all(&output_dir)?;
+    }
+
+    let stem = output_dir.join(output_stem(data));
+    let text_width = data.config.text_width.unwrap_or(DEFAULT_TEXT_WIDTH);
+
+    let mut paths = Vec::new();
+    for format in formats {
+        match format {
+            OutputFormat::Pdf => {
+                // It takes 2 arguments: the document and the options.
+                let options = PdfOptions::default();
+
+                // The ? operator unwraps the Ok(Vec<u8>) or returns the Err.
+                let pdf_data = typst_pdf::pdf(&document, &options)
+                    .map_err(|e| eyre!("PDF Export Error: {:?}", e))?;
+                let path = with_suffix(&stem, "", "pdf");
+                fs::write(&path, pdf_data)?;
+                paths.push(path);
+            }
+            OutputFormat::Png => {
+                let pixel_per_pt = dpi / 72.0;
+                remove_pages(&stem, "png")?;
+                for (i, page) in document.pages.iter().enumerate() {
+                    let png = typst_render::render(page, pixel_per_pt)
+                        .encode_png()
+                        .map_err(|e| eyre!("PNG Export Error: {}", e))?;
+                    let path = with_suffix(&stem, &page_suffix(&document, i), "png");
+                    fs::write(&path, png)?;
+                    paths.push(path);
+                }
+            }
+            OutputFormat::Svg => {
+                remove_pages(&stem, "svg")?;
+                for (i, page) in document.pages.iter().enumerate() {
+                    let path = with_suffix(&stem, &page_suffix(&document, i), "svg");
+                    fs::write(&path, typst_svg::svg(page))?;
+                    paths.push(path);
+                }
+            }
+            OutputFormat::Html => {
+                let page = html::render(
+                    &data.to_filtered_data(),
+                    dir,
+                    data.config.html_template.as_deref(),
+                )?;
+                let path = with_suffix(&stem, "", "html");
+                fs::write(&path, page)?;
+                paths.push(path);
+            }
+            OutputFormat::Docx => {
+                let docx = docx::render(&data.to_filtered_data())?;
+                let path = with_suffix(&stem, "", "docx");
+                fs::write(&path, docx)?;
+                paths.push(path);
+            }
+            OutputFormat::Json => {
+                let path = with_suffix(&stem, "", "json");
+                fs::write(&path, json_resume::export(data))?;
+                paths.push(path);
+            }
+            OutputFormat::Txt | OutputFormat::Md => {
+                let (style, extension) = match format {
+                    OutputFormat::Md => (TextStyle::Markdown, "md"),
+                    _ => (TextStyle::Plain, "txt"),
+                };
+                let text = text_export::render(&data.to_filtered_data(), style, text_width);
+                let path = with_suffix(&stem, "", extension);
+                fs::write(&path, text)?;
+                paths.push(path);
+            }
+        }
+    }
+
+    Ok(ExportOutput {
+        paths: paths
+            .iter()
+            .map(|p| p.to_string_lossy().to_string())
+            .collect(),
+        warnings,
+    })
+}
+
+// Image formats write one file per page; single-page resumes get no number
+fn page_suffix(document: &Document, index: usize) -> String {
+    if document.pages.len() > 1 {
+        format!("-{}", index + 1)
+    } else {
+        String::new()
+    }
+}
+
+// Page files of an earlier export, so a resume that got shorter leaves no
+// stale `-3.png` behind: `<stem>.<ext>` and `<stem>-<n>.<ext>`
+fn remove_pages(stem: &Path, extension: &str) -> Result<()> {
+    let (Some(dir), Some(name)) = (stem.parent(), stem.file_name()) else {
+        return Ok(());
+    };
+    let name = name.to_string_lossy();
+    for entry in fs::read_dir(dir)?.filter_map(|e| e.ok()) {
+        let file = entry.file_name().to_string_lossy().to_string();
+        let Some(rest) = file
+            .strip_prefix(name.as_ref())
+            .and_then(|rest| rest.strip_suffix(&format!(".{}", extension)))
+        else {
+            continue;
+        };
+        let numbered = rest
+            .strip_prefix('-')
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+        if rest.is_empty() || numbered {
+            fs::remove_file(entry.path())?;
+        }
+    }
+    Ok(())
+}
+
+// Not Path::with_extension: job titles may contain dots
+fn with_suffix(stem: &Path, suffix: &str, extension: &str) -> PathBuf {
+    let mut name = stem.as_os_str().to_owned();
+    name.push(format!("{}.{}", suffix, extension));
+    PathBuf::from(name)
+}
+
+// --- Generate dynamic filename ---
+fn output_stem(data: &ResumeData) -> String {
     let mut filename_parts = Vec::new();
 
     // 1. First and Last Name
@@ -99,7 +321,7 @@ pub fn generate_pdf(data: &ResumeData) -> Result<String> {
         let trimmed_title = title.trim();
         if !trimmed_title.is_empty() && trimmed_title != "N/A" {
             // Sanitize title to remove slashes which break file paths
-            let sanitized_title = trimmed_title.replace('/', "-").replace('\\', "-");
+            let sanitized_title = trimmed_title.replace(['/', '\\'], "-");
             filename_parts.push(sanitized_title);
         }
     }
@@ -116,10 +338,5 @@ pub fn generate_pdf(data: &ResumeData) -> Result<String> {
     }
 
     // Final sanitization of the whole base filename just in case
-    let safe_filename = base_filename.replace('/', "-").replace('\\', "-");
-    let filename = format!("{}.pdf", safe_filename);
-    let output_path = output_dir.join(&filename);
-    fs::write(&output_path, pdf_data)?;
-
-    Ok(output_path.to_string_lossy().to_string())
+    base_filename.replace(['/', '\\'], "-")
 }
@@ -0,0 +1,142 @@
+use typst::layout::{Abs, Frame, FrameItem, Point, Transform};
+use typst::model::Document;
+
+// Plain-text rendering of a compiled document for the TUI preview pane.
+// Text runs are grouped into lines by their baseline and placed in columns
+// proportional to their position on the page, so right-aligned dates and
+// two-column layouts keep roughly their shape.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Preview {
+    pub pages: Vec<PreviewPage>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PreviewPage {
+    pub lines: Vec<PreviewLine>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PreviewLine {
+    // (x as a fraction of the page width, text), left to right
+    pub runs: Vec<(f64, String)>,
+}
+
+// A text run on the page, in points
+struct Run {
+    x: f64,
+    y: f64,
+    width: f64,
+    size: f64,
+    text: String,
+}
+
+impl Preview {
+    pub fn from_document(document: &Document) -> Self {
+        let pages = document
+            .pages
+            .iter()
+            .map(|page| PreviewPage::from_frame(&page.frame))
+            .collect();
+        Self { pages }
+    }
+
+    /// Lines spilling onto the last page when there is more than one,
+    /// i.e. how much would have to go to save a page
+    pub fn overflow(&self) -> Option<usize> {
+        match self.pages.as_slice() {
+            [_, .., last] => Some(last.lines.len()),
+            _ => None,
+        }
+    }
+}
+
+impl PreviewPage {
+    fn from_frame(frame: &Frame) -> Self {
+        let mut runs = Vec::new();
+        collect_runs(frame, Transform::identity(), &mut runs);
+        runs.sort_by(|a, b| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)));
+
+        let page_width = frame.width().to_pt().max(1.0);
+        let mut lines: Vec<(f64, Vec<Run>)> = Vec::new();
+        for run in runs {
+            // Runs whose baselines are within half a font size share a line
+            match lines.last_mut() {
+                Some((y, line)) if (run.y - *y).abs() < run.size / 2.0 => line.push(run),
+                _ => lines.push((run.y, vec![run])),
+            }
+        }
+
+        let lines = lines
+            .into_iter()
+            .map(|(_, mut line)| {
+                line.sort_by(|a, b| a.x.total_cmp(&b.x));
+                PreviewLine::from_runs(line, page_width)
+            })
+            .collect();
+        Self { lines }
+    }
+}
+
+impl PreviewLine {
+    // Neighbouring runs (words typst shaped separately) are joined into one
+    fn from_runs(line: Vec<Run>, page_width: f64) -> Self {
+        let mut runs: Vec<(f64, String)> = Vec::new();
+        let mut end = f64::NEG_INFINITY;
+        for run in line {
+            let gap = run.x - end;
+            match runs.last_mut() {
+                // Touching or a word space apart
+                Some((_, text)) if gap < run.size => {
+                    if gap > run.size * 0.1 && !text.ends_with(' ') {
+                        text.push(' ');
+                    }
+                    text.push_str(&run.text);
+                }
+                _ => runs.push((run.x / page_width, run.text.clone())),
+            }
+            end = run.x + run.width;
+        }
+        Self { runs }
+    }
+
+    /// Lay the runs out in `width` columns; later runs never overwrite earlier ones
+    pub fn render(&self, width: usize) -> String {
+        let mut out = String::new();
+        let mut used = 0;
+        for (x, text) in &self.runs {
+            let column = (x * width as f64).round() as usize;
+            if column > used {
+                out.push_str(&" ".repeat(column - used));
+                used = column;
+            } else if used > 0 {
+                out.push(' ');
+                used += 1;
+            }
+            out.push_str(text);
+            used += text.chars().count();
+        }
+        out
+    }
+}
+
+fn collect_runs(frame: &Frame, ts: Transform, runs: &mut Vec<Run>) {
+    for (pos, item) in frame.items() {
+        let ts = ts.pre_concat(Transform::translate(pos.x, pos.y));
+        match item {
+            FrameItem::Group(group) => {
+                collect_runs(&group.frame, ts.pre_concat(group.transform), runs)
+            }
+            FrameItem::Text(text) => {
+                let origin = Point::zero().transform(ts);
+                runs.push(Run {
+                    x: origin.x.to_pt(),
+                    y: origin.y.to_pt(),
+                    width: Abs::to_pt(text.width()) * ts.sx.get(),
+                    size: text.size.to_pt(),
+                    text: text.text.to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use color_eyre::{Result, eyre::eyre};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::data_dir::DataDir;
+
+// Used when nothing else was chosen, and listed first in the picker
+pub const DEFAULT_TEMPLATE: &str = "default_resume_template.typ";
+
+// A .typ file in data/templates/. Files starting with "_" are partials for
+// other templates to import and are not listed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    // File name inside templates/, also what gets remembered
+    pub file: String,
+    pub name: String,
+    pub description: String,
+}
+
+impl Template {
+    // Reads the optional metadata header at the top of the file:
+    //   // @name: Two Column
+    //   // @description: Sidebar layout for design roles
+    fn from_path(path: &Path) -> Option<Self> {
+        let file = path.file_name()?.to_string_lossy().to_string();
+        let mut template = Template {
+            name: path.file_stem()?.to_string_lossy().replace('_', " "),
+            description: String::new(),
+            file,
+        };
+        let content = fs::read_to_string(path).ok()?;
+        for line in content.lines().map(str::trim) {
+            let Some(comment) = line.strip_prefix("//") else {
+                if line.is_empty() {
+                    continue;
+                }
+                break;
+            };
+            let comment = comment.trim();
+            if let Some(name) = comment.strip_prefix("@name:") {
+                template.name = name.trim().to_string();
+            } else if let Some(description) = comment.strip_prefix("@description:") {
+                template.description = description.trim().to_string();
+            }
+        }
+        Some(template)
+    }
+
+    /// Matches the file name, its stem or the display name (case-insensitive)
+    pub fn matches(&self, wanted: &str) -> bool {
+        let wanted = wanted.trim();
+        self.file.eq_ignore_ascii_case(wanted)
+            || self
+                .file
+                .strip_suffix(".typ")
+                .is_some_and(|stem| stem.eq_ignore_ascii_case(wanted))
+            || self.name.eq_ignore_ascii_case(wanted)
+    }
+}
+
+/// Templates in `data/templates/`, default first then by name
+pub fn discover(dir: &DataDir) -> Vec<Template> {
+    let Ok(entries) = fs::read_dir(dir.templates_dir()) else {
+        return Vec::new();
+    };
+    let mut templates: Vec<Template> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "typ"))
+        .filter(|path| {
+            !path
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('_'))
+        })
+        .filter_map(|path| Template::from_path(&path))
+        .collect();
+    templates.sort_by(|a, b| {
+        (a.file != DEFAULT_TEMPLATE, &a.name).cmp(&(b.file != DEFAULT_TEMPLATE, &b.name))
+    });
+    templates
+}
+
+/// Path of the wanted template. Without one, the only template there is,
+/// or else the default one.
+pub fn resolve(dir: &DataDir, wanted: Option<&str>) -> Result<PathBuf> {
+    let Some(wanted) = wanted else {
+        if let [only] = discover(dir).as_slice() {
+            return Ok(dir.templates_dir().join(&only.file));
+        }
+        let path = dir.templates_dir().join(DEFAULT_TEMPLATE);
+        if !path.exists() {
+            return Err(eyre!(
+                "Template file not found at: {:?}.\nPlease ensure your data folder contains a 'templates' directory.",
+                path
+            ));
+        }
+        return Ok(path);
+    };
+
+    let templates = discover(dir);
+    match templates.iter().find(|t| t.matches(wanted)) {
+        Some(template) => Ok(dir.templates_dir().join(&template.file)),
+        None => {
+            let available: Vec<&str> = templates.iter().map(|t| t.file.as_str()).collect();
+            Err(eyre!(
+                "Unknown template {:?}. Available: {}",
+                wanted,
+                if available.is_empty() {
+                    "none".to_string()
+                } else {
+                    available.join(", ")
+                }
+            ))
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::app::{App, CurrentScreen};
+use crate::models::Section;
 use crossterm::event::KeyCode;
 
 #[test]
@@ -62,3 +63,78 @@ fn test_welcome_to_job_title_transition() {
     assert_eq!(app.data.job_title, Some("Developer".to_string()));
     assert_eq!(app.data.professional_summary, Some("Developer summary".to_string()));
 }
+
+#[test]
+fn test_skills_screens_follow_projects() {
+    let mut app = App {
+        data: crate::tests::sample_data(),
+        current_screen: CurrentScreen::ProjectsSelection,
+        ..Default::default()
+    };
+
+    // Projects -> Skills when skills exist
+    app.handle_key_event(KeyCode::Enter);
+    assert_eq!(app.current_screen, CurrentScreen::SkillsSelection);
+
+    // Drill into the first category and hide "Go"
+    app.handle_key_event(KeyCode::Char('e'));
+    assert_eq!(app.current_screen, CurrentScreen::SkillItemSelection);
+    app.handle_key_event(KeyCode::Char('j'));
+    app.handle_key_event(KeyCode::Char(' '));
+    app.handle_key_event(KeyCode::Backspace);
+    assert_eq!(app.current_screen, CurrentScreen::SkillsSelection);
+
+    // Hide the second category entirely
+    app.handle_key_event(KeyCode::Char('j'));
+    app.handle_key_event(KeyCode::Char(' '));
+
+    let filtered = app.data.to_filtered_data();
+    assert_eq!(filtered.skills.len(), 1);
+    assert_eq!(filtered.skills[0].category, "Languages");
+    assert_eq!(filtered.skills[0].skills.len(), 1);
+    assert_eq!(filtered.skills[0].skills[0].name, "Rust");
+
+    app.handle_key_event(KeyCode::Backspace);
+    assert_eq!(app.current_screen, CurrentScreen::ProjectsSelection);
+}
+
+#[test]
+fn test_optional_sections_follow_skills() {
+    let mut data = crate::tests::sample_data();
+    data.certifications =
+        serde_yaml::from_str("- name: CKAD\n  issuer: CNCF\n- name: AWS SAA\n  issuer: AWS")
+            .unwrap();
+    data.languages = serde_yaml::from_str("- language: English\n  proficiency: Native").unwrap();
+    let mut app = App {
+        data,
+        current_screen: CurrentScreen::SkillsSelection,
+        ..Default::default()
+    };
+
+    // Skills -> Certifications -> Languages; empty sections are skipped
+    app.handle_key_event(KeyCode::Enter);
+    assert_eq!(
+        app.current_screen,
+        CurrentScreen::SectionSelection(Section::Certifications)
+    );
+    app.handle_key_event(KeyCode::Char(' '));
+    assert!(!app.is_last_step());
+
+    app.handle_key_event(KeyCode::Enter);
+    assert_eq!(
+        app.current_screen,
+        CurrentScreen::SectionSelection(Section::Languages)
+    );
+    assert!(app.is_last_step());
+
+    app.handle_key_event(KeyCode::Backspace);
+    assert_eq!(
+        app.current_screen,
+        CurrentScreen::SectionSelection(Section::Certifications)
+    );
+
+    let filtered = app.data.to_filtered_data();
+    assert_eq!(filtered.certifications.len(), 1);
+    assert_eq!(filtered.certifications[0].name, "AWS SAA");
+    assert_eq!(filtered.languages.len(), 1);
+}
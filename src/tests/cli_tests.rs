@@ -0,0 +1,134 @@
+use crate::cli::build::{BuildArgs, apply, check_page_budget};
+use crate::cli::check::{self, CheckArgs};
+use crate::cli::{Cli, Command};
+use crate::data_dir::DataDir;
+use crate::models::ResumeData;
+use crate::models::config::PageOverflow;
+use crate::tests::{TempDir, sample_data};
+use clap::Parser;
+use std::process::ExitCode;
+
+#[test]
+fn test_build_subcommand_parses_flags() {
+    let cli = Cli::parse_from([
+        "resume-pipeline",
+        "build",
+        "--job-title",
+        "Rust Developer",
+        "--hide-phone",
+        "--exclude-project",
+        "Calculator App",
+    ]);
+
+    let Some(Command::Build(args)) = cli.command else {
+        panic!("expected the build subcommand");
+    };
+    assert_eq!(args.job_title.as_deref(), Some("Rust Developer"));
+    assert!(args.hide_phone);
+    assert!(!args.hide_email);
+    assert_eq!(args.exclude_project, vec!["Calculator App".to_string()]);
+}
+
+#[test]
+fn test_build_apply_selects_and_hides() {
+    let mut data = sample_data();
+    let args = BuildArgs {
+        job_title: Some("rust developer".to_string()),
+        hide_phone: true,
+        exclude_project: vec!["Calculator App".to_string()],
+        ..Default::default()
+    };
+
+    apply(&args, &mut data).unwrap();
+
+    let filtered = data.to_filtered_data();
+    assert_eq!(filtered.job_title, "Rust Developer");
+    assert_eq!(filtered.professional_summary, "Rust summary");
+    assert!(filtered.profile.phone.is_empty());
+    assert_eq!(filtered.profile.email, "jane@example.com");
+    assert_eq!(filtered.projects.len(), 1);
+    assert_eq!(filtered.projects[0].title, "Resume TUI");
+}
+
+#[test]
+fn test_build_apply_rejects_unknown_names() {
+    let mut data = sample_data();
+    let args = BuildArgs {
+        job_title: Some("Astronaut".to_string()),
+        ..Default::default()
+    };
+    assert!(apply(&args, &mut data).is_err());
+
+    let args = BuildArgs {
+        exclude_project: vec!["Missing Project".to_string()],
+        ..Default::default()
+    };
+    assert!(apply(&args, &mut data).is_err());
+}
+
+#[test]
+fn test_page_overflow_fails_unless_allowed() {
+    let dir = DataDir::default();
+    let mut data = sample_data();
+    let strict = BuildArgs::default();
+    let lenient = BuildArgs {
+        allow_overflow: true,
+        ..Default::default()
+    };
+
+    let fits = check_page_budget(&strict, &data, &dir, 2, 2);
+    let over = check_page_budget(&strict, &data, &dir, 1, 2);
+    let allowed = check_page_budget(&lenient, &data, &dir, 1, 2);
+    data.config.on_page_overflow = PageOverflow::Warn;
+    let warned = check_page_budget(&strict, &data, &dir, 1, 2);
+
+    assert!(fits.is_ok());
+    assert_eq!(
+        over.unwrap_err().to_string(),
+        "2 pages, over the budget of 1"
+    );
+    assert!(allowed.is_ok());
+    assert!(warned.is_ok());
+}
+
+#[test]
+fn test_data_dir_flag_is_global() {
+    let cli = Cli::parse_from(["resume-pipeline", "build", "--data-dir", "/tmp/alice"]);
+    assert_eq!(cli.data_dir, Some(std::path::PathBuf::from("/tmp/alice")));
+
+    let data_dir = DataDir::resolve(cli.data_dir);
+    assert_eq!(data_dir.root(), std::path::Path::new("/tmp/alice"));
+    assert_eq!(
+        data_dir.templates_dir(),
+        std::path::PathBuf::from("/tmp/alice/templates")
+    );
+}
+
+#[test]
+fn test_load_from_custom_data_dir() {
+    let root = TempDir::new("custom-data-dir");
+    std::fs::write(
+        root.join("jobtitles.yaml"),
+        "- title: Data Engineer\n  professional_summary: Pipelines",
+    )
+    .unwrap();
+
+    let (data, diagnostics) = ResumeData::load(&root.data_dir());
+    assert!(!diagnostics.iter().any(|d| d.is_error()));
+
+    assert_eq!(data.job_titles.len(), 1);
+    assert_eq!(data.job_titles[0].title, "Data Engineer");
+}
+
+#[test]
+fn test_check_fails_on_errors_and_strict_warnings() {
+    // Only warnings: the core files are missing
+    let root = TempDir::new("check-exit-code");
+    let dir = root.data_dir();
+    let strict = |strict| check::run(CheckArgs { strict }, &dir).unwrap();
+    assert_eq!(strict(false), ExitCode::SUCCESS);
+    assert_eq!(strict(true), ExitCode::FAILURE);
+
+    std::fs::write(root.join("jobtitles.yaml"), "- title: [oops").unwrap();
+    assert_eq!(strict(false), ExitCode::FAILURE);
+}
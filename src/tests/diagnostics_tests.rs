@@ -0,0 +1,386 @@
+use super::TempDir;
+use crate::data_dir::DataDir;
+use crate::models::ResumeData;
+use crate::models::diagnostics::Severity;
+use crate::models::preset::Preset;
+
+#[test]
+fn test_parse_errors_report_line_and_column() {
+    let root = TempDir::with_files(
+        "parse-error",
+        &[
+            (
+                "jobtitles.yaml",
+                "- title: Engineer\n  professional_summary: s",
+            ),
+            (
+                "experience.yaml",
+                "- role: Dev\n  company: Acme\n  location: ''\n  date: ''\n  summary: ''\n  bullets: oops",
+            ),
+        ],
+    );
+    let (data, diagnostics) = ResumeData::load(&root.data_dir());
+
+    // The broken file is reported, the others still load
+    assert_eq!(data.job_titles.len(), 1);
+    let error = diagnostics.iter().find(|d| d.is_error()).unwrap();
+    assert!(error.file.ends_with("experience.yaml"));
+    assert_eq!(error.location, Some((6, 12)));
+    assert_eq!(error.snippet.as_deref(), Some("  bullets: oops"));
+    assert!(!error.message.contains("at line"));
+
+    // Missing core files are warnings
+    assert!(
+        diagnostics
+            .iter()
+            .any(|d| d.severity == Severity::Warning && d.file.ends_with("profile.yaml"))
+    );
+}
+
+#[test]
+fn test_unknown_keys_are_errors() {
+    let root = TempDir::with_files(
+        "unknown-key",
+        &[(
+            "certifications.yaml",
+            "- name: CKAD\n  issuer: CNCF\n  credentail_url: https://cncf.io",
+        )],
+    );
+    let (data, diagnostics) = ResumeData::load(&root.data_dir());
+
+    assert!(data.certifications.is_empty());
+    let error = diagnostics.iter().find(|d| d.is_error()).unwrap();
+    assert!(error.file.ends_with("certifications.yaml"));
+    assert!(
+        error.message.contains("unknown field `credentail_url`"),
+        "{}",
+        error.message
+    );
+    assert_eq!(error.location, Some((3, 3)));
+}
+
+#[test]
+fn test_schema_reports_every_problem_in_a_file() {
+    let root = TempDir::with_files(
+        "schema-errors",
+        &[(
+            "certifications.yaml",
+            "- name: CKAD\n  isuer: CNCF\n- name: CKA\n  issuer: CNCF\n  credentail_url: x\n",
+        )],
+    );
+    let (_, diagnostics) = ResumeData::load(&root.data_dir());
+    let errors: Vec<_> = diagnostics.iter().filter(|d| d.is_error()).collect();
+
+    // serde stops at the first entry; the schema finds the second one's key
+    // and the first one's missing field too
+    let at = |line, column| errors.iter().find(|d| d.location == Some((line, column)));
+    assert!(at(2, 3).unwrap().message.contains("unknown field `isuer`"));
+    assert_eq!(at(1, 3).unwrap().message, "missing field `issuer`");
+    assert_eq!(at(5, 3).unwrap().message, "unknown field `credentail_url`");
+    assert_eq!(errors.len(), 3, "{:?}", errors);
+}
+
+#[test]
+fn test_preset_errors_report_line_and_column() {
+    let root = TempDir::new("preset-error");
+    let dir = root.data_dir();
+    std::fs::create_dir_all(dir.presets_dir()).unwrap();
+    let file = dir.presets_dir().join("bad.yaml");
+    std::fs::write(
+        &file,
+        "experience: []
+skils: []
+",
+    )
+    .unwrap();
+
+    let mut diagnostics = Vec::new();
+    assert!(Preset::read(&file, &mut diagnostics).is_none());
+    let [error] = diagnostics.as_slice() else {
+        panic!("{:?}", diagnostics);
+    };
+    assert!(
+        error.message.contains("unknown field `skils`"),
+        "{}",
+        error.message
+    );
+    assert_eq!(error.location, Some((2, 1)));
+    assert_eq!(error.snippet.as_deref(), Some("skils: []"));
+
+    // The path is shown once, in front of the position
+    let shown = Preset::load(&dir, "bad").unwrap_err().to_string();
+    assert_eq!(shown.matches("bad.yaml").count(), 1, "{}", shown);
+    assert!(shown.contains("bad.yaml:2:1: error:"), "{}", shown);
+}
+
+#[test]
+fn test_lints_flag_semantic_problems() {
+    let root = TempDir::with_files(
+        "lints",
+        &[
+            (
+                "profile.yaml",
+                "name: Jane\nemail: ''\nphone: ''\nurl: https://example.com\nwebsite: jane.dev\nlocation: ''\ncitizenship: ''",
+            ),
+            (
+                "jobtitles.yaml",
+                "- title: Engineer\n  professional_summary: a\n- title: Engineer\n  professional_summary: b",
+            ),
+            (
+                "experience.yaml",
+                "- role: Lead\n  company: Gamma\n  location: ''\n  summary: ''\n  bullets: [Shipped it]\n- role: Dev\n  company: Acme\n  location: ''\n  date: ''\n  summary: ''\n  bullets: [Shipped it, '', Shipped it]",
+            ),
+        ],
+    );
+    let (_, diagnostics) = ResumeData::load(&root.data_dir());
+
+    let messages: Vec<String> = diagnostics
+        .iter()
+        .filter(|d| d.location.is_some())
+        .map(|d| d.to_string())
+        .collect();
+    let find = |needle: &str| {
+        messages
+            .iter()
+            .find(|m| m.contains(needle))
+            .unwrap_or_else(|| panic!("no diagnostic for {:?} in {:#?}", needle, messages))
+    };
+
+    assert!(find("`email` is empty").contains("profile.yaml:2:1"));
+    assert!(find("has no scheme").contains("profile.yaml:5:10"));
+    assert!(find("Duplicate job title").contains("jobtitles.yaml:3:10"));
+    // The offending bullet itself, not the first line with the same text
+    assert!(find("Bullet 2 of \"Dev at Acme\" is empty").contains("experience.yaml:11:25"));
+    assert!(
+        find("Duplicate bullet \"Shipped it\"; give one of them an `id`")
+            .contains("experience.yaml:11:29")
+    );
+    assert!(!diagnostics.iter().any(|d| d.is_error()));
+}
+
+#[test]
+fn test_toml_and_json_files_load_like_yaml() {
+    let root = TempDir::with_files(
+        "formats",
+        &[
+            (
+                "profile.toml",
+                "name = \"Jane\"\nemail = \"jane@example.com\"\nphone = \"\"\nurl = \"\"\nwebsite = \"jane.dev\"\nlocation = \"\"\ncitizenship = \"\"",
+            ),
+            (
+                "experience.toml",
+                "[[experience]]\nrole = \"Dev\"\ncompany = \"Acme\"\nlocation = \"\"\nstart = \"2023-06\"\nend = \"present\"\nsummary = \"\"\nbullets = [\"Shipped it\"]",
+            ),
+            (
+                "jobtitles.json",
+                r#"[{ "title": "Engineer", "professional_summary": "s" }]"#,
+            ),
+            (
+                "education.json",
+                r#"[{ "education": [{ "school": "Uni", "degree": "B.Sc" }] }]"#,
+            ),
+        ],
+    );
+    let dir = root.data_dir();
+    let (data, diagnostics) = ResumeData::load(&dir);
+
+    assert_eq!(data.profile.as_ref().unwrap().name, "Jane");
+    assert_eq!(data.experience[0].bullets[0].text(), "Shipped it");
+    assert_eq!(data.job_titles[0].title, "Engineer");
+    assert_eq!(data.education[0].school, "Uni");
+    // Lints point into the TOML file
+    let lint = diagnostics
+        .iter()
+        .find(|d| d.message.contains("website"))
+        .unwrap();
+    assert!(lint.file.ends_with("profile.toml"));
+    assert_eq!(lint.location, Some((5, 12)));
+
+    // Both formats of one file is an error; the file is skipped
+    std::fs::write(root.join("experience.yaml"), "[]").unwrap();
+    let (data, diagnostics) = ResumeData::load(&dir);
+    // And a TOML syntax error has a position
+    std::fs::write(root.join("profile.toml"), "name = \"Jane\"\nemail = ").unwrap();
+    let (_, broken) = ResumeData::load(&dir);
+
+    assert!(data.experience.is_empty());
+    let error = diagnostics.iter().find(|d| d.is_error()).unwrap();
+    assert_eq!(
+        error.message,
+        "experience.yaml and experience.toml both exist; keep only one of them"
+    );
+    let error = broken.iter().find(|d| d.is_error()).unwrap();
+    assert!(error.file.ends_with("profile.toml"));
+    assert_eq!(error.location.map(|(line, _)| line), Some(2));
+}
+
+#[test]
+fn test_entries_can_be_split_into_folders() {
+    let root = TempDir::new("folders");
+    std::fs::create_dir_all(root.join("experience")).unwrap();
+    std::fs::create_dir_all(root.join("projects")).unwrap();
+    let files = [
+        (
+            "experience.yaml",
+            "- role: Lead\n  company: Gamma\n  location: ''\n  summary: ''\n  bullets: []",
+        ),
+        (
+            "experience/2-beta.toml",
+            "[[experience]]\nrole = \"Intern\"\ncompany = \"Beta\"\nlocation = \"\"\nsummary = \"\"\nbullets = [\"\"]",
+        ),
+        (
+            "experience/1-acme.yaml",
+            "role: Dev\ncompany: Acme\nlocation: ''\nsummary: ''\nbullets: [Shipped it]",
+        ),
+        // No `- education:` wrapper needed any more, but it still works
+        ("education.yaml", "- school: Uni\n  degree: B.Sc"),
+        (
+            "projects/tui.yaml",
+            "- projects:\n    - title: Resume TUI\n      description: d\n      tech_stack: []",
+        ),
+    ];
+    for (file, content) in files {
+        std::fs::write(root.join(file), content).unwrap();
+    }
+    let (data, diagnostics) = ResumeData::load(&root.data_dir());
+
+    // The file first, then the folder in file name order
+    let companies: Vec<&str> = data.experience.iter().map(|e| e.company.as_str()).collect();
+    assert_eq!(companies, ["Gamma", "Acme", "Beta"]);
+    assert_eq!(data.education[0].degree, "B.Sc");
+    assert_eq!(data.projects[0].title, "Resume TUI");
+    // A missing projects.yaml is fine when the folder exists
+    assert!(
+        !diagnostics
+            .iter()
+            .any(|d| d.file.ends_with("projects.yaml"))
+    );
+
+    // Lints point into the file the entry came from
+    let lint = diagnostics
+        .iter()
+        .find(|d| d.message.contains("is empty"))
+        .unwrap();
+    assert!(lint.file.ends_with("experience/2-beta.toml"), "{:?}", lint);
+    assert_eq!(lint.location, Some((6, 12)));
+}
+
+#[test]
+fn test_includes_pull_in_shared_entries() {
+    let root = TempDir::new("include");
+    std::fs::create_dir_all(root.join("shared")).unwrap();
+    let files = [
+        (
+            "projects.yaml",
+            "- title: Local\n  description: d\n  tech_stack: []\n- include: shared/payments.yaml",
+        ),
+        (
+            "shared/payments.yaml",
+            "title: Payments\ndescription: d\ntech_stack: []\nurl: payments.dev",
+        ),
+        ("experience.yaml", "include: missing.yaml"),
+        ("education.yaml", "- include: shared/loop.yaml"),
+        ("shared/loop.yaml", "include: ../education.yaml"),
+    ];
+    for (file, content) in files {
+        std::fs::write(root.join(file), content).unwrap();
+    }
+    let (data, diagnostics) = ResumeData::load(&root.data_dir());
+
+    let titles: Vec<&str> = data.projects.iter().map(|p| p.title.as_str()).collect();
+    assert_eq!(titles, ["Local", "Payments"]);
+    // Lints on included entries point into the included file
+    let lint = diagnostics
+        .iter()
+        .find(|d| d.message.contains("has no scheme"))
+        .unwrap();
+    assert!(lint.file.ends_with("shared/payments.yaml"), "{:?}", lint);
+    assert_eq!(lint.location, Some((4, 6)));
+
+    let missing = diagnostics
+        .iter()
+        .find(|d| d.message.starts_with("Included file"))
+        .unwrap();
+    assert_eq!(missing.message, "Included file missing.yaml not found");
+    assert!(missing.file.ends_with("experience.yaml"));
+    assert_eq!(missing.location, Some((1, 10)));
+
+    let cycle = diagnostics
+        .iter()
+        .find(|d| d.message.starts_with("Include cycle"))
+        .unwrap();
+    assert!(cycle.file.ends_with("shared/loop.yaml"), "{:?}", cycle);
+    assert!(
+        cycle.message.ends_with("education.yaml"),
+        "{}",
+        cycle.message
+    );
+}
+
+#[test]
+fn test_extends_reads_the_base_directory_first() {
+    let root = TempDir::new("extends");
+    let (base, child) = (root.join("base"), root.join("child"));
+    std::fs::create_dir_all(&base).unwrap();
+    std::fs::create_dir_all(&child).unwrap();
+    let files = [
+        (
+            "base/profile.yaml",
+            "name: Jane Doe\nemail: jane@example.com\nphone: ''\nurl: ''\nwebsite: ''\nlocation: Berlin\ncitizenship: ''",
+        ),
+        (
+            "base/experience.yaml",
+            "- role: Dev\n  company: Acme\n  location: ''\n  summary: base\n  bullets: [a]\n- role: Lead\n  company: Gamma\n  location: ''\n  summary: ''\n  bullets: [b]",
+        ),
+        ("child/config.yaml", "extends: ../base"),
+        ("child/profile.yaml", "location: Remote"),
+        (
+            "child/experience.yaml",
+            "- role: Dev\n  company: Acme\n  location: ''\n  summary: child\n  bullets: [a]",
+        ),
+    ];
+    for (file, content) in files {
+        std::fs::write(root.join(file), content).unwrap();
+    }
+    let (data, diagnostics) = ResumeData::load(&DataDir::new(&child));
+
+    // Fields override one by one; entries with the same id are replaced in place
+    let profile = data.profile.unwrap();
+    assert_eq!(
+        (profile.name.as_str(), profile.location.as_str()),
+        ("Jane Doe", "Remote")
+    );
+    let summaries: Vec<&str> = data.experience.iter().map(|e| e.summary.as_str()).collect();
+    assert_eq!(summaries, ["child", ""]);
+    assert!(
+        !diagnostics.iter().any(|d| d.is_error()),
+        "{:?}",
+        diagnostics
+    );
+
+    std::fs::write(child.join("config.yaml"), "extends: ../nowhere").unwrap();
+    let (_, diagnostics) = ResumeData::load(&DataDir::new(&child));
+    std::fs::write(base.join("config.yaml"), "extends: ../child").unwrap();
+    std::fs::write(child.join("config.yaml"), "extends: ../base").unwrap();
+    let (_, cycle) = ResumeData::load(&DataDir::new(&child));
+
+    let missing = diagnostics.iter().find(|d| d.is_error()).unwrap();
+    assert!(missing.message.contains("not found"), "{}", missing.message);
+    assert_eq!(missing.location, Some((1, 10)));
+    assert!(
+        cycle
+            .iter()
+            .any(|d| d.message.starts_with("`extends` cycle"))
+    );
+}
+
+#[test]
+fn test_missing_data_dir_is_reported_once() {
+    let root = TempDir::new("missing-data-dir");
+    let dir = DataDir::new(root.join("nowhere"));
+    let (_, diagnostics) = ResumeData::load(&dir);
+
+    assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+    assert_eq!(diagnostics[0].message, "Data directory not found");
+    assert!(diagnostics[0].notes[0].contains("--data-dir"));
+}
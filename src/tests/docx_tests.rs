@@ -0,0 +1,62 @@
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
+
+use super::sample_data;
+use crate::docx::render;
+
+fn part(docx: &[u8], name: &str) -> String {
+    let mut archive = ZipArchive::new(Cursor::new(docx)).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    content
+}
+
+#[test]
+fn test_docx_has_styles_bullets_and_links() {
+    let mut data = sample_data();
+    data.select_job_title(0);
+    data.experience[0].toggle_bullet(1);
+    data.experience[0].company = "R&D <Labs>".to_string();
+    // Pasted from a PDF: a form feed and a vertical tab
+    data.experience[0].role = "Software\u{c} Engineer\u{b}".to_string();
+    data.projects[0].url = Some("github.com/jane/calc".to_string());
+    if let Some(profile) = &mut data.profile {
+        profile.website = "https://jane.dev".to_string();
+    }
+
+    let docx = render(&data.to_filtered_data()).unwrap();
+    let document = part(&docx, "word/document.xml");
+    let rels = part(&docx, "word/_rels/document.xml.rels");
+
+    assert!(document.contains(r#"<w:pStyle w:val="Heading1"/></w:pPr><w:r><w:rPr></w:rPr><w:t xml:space="preserve">Work Experience</w:t>"#));
+    assert!(document.contains("R&amp;D &lt;Labs&gt;"));
+    assert!(document.contains(">Software Engineer<"), "{}", document);
+    assert!(!document.contains(['\u{b}', '\u{c}']));
+    assert_eq!(
+        document
+            .matches(r#"<w:pStyle w:val="ListBullet"/>"#)
+            .count(),
+        3
+    );
+    assert!(!document.contains("Second bullet"));
+    // Dates sit after a tab against the right margin
+    assert!(document.contains("<w:tab/><w:t xml:space=\"preserve\">2023 - Current</w:t>"));
+
+    for target in [
+        "mailto:jane@example.com",
+        "https://jane.dev",
+        "https://github.com/jane/calc",
+    ] {
+        assert!(
+            rels.contains(&format!(r#"Target="{}" TargetMode="External""#, target)),
+            "{}",
+            rels
+        );
+    }
+    assert_eq!(document.matches("<w:hyperlink").count(), 3);
+    assert!(part(&docx, "[Content_Types].xml").contains("/word/numbering.xml"));
+}
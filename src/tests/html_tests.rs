@@ -0,0 +1,96 @@
+use std::fs;
+use std::path::Path;
+
+use super::{TempDir, sample_data};
+use crate::data_dir::DataDir;
+use crate::html::render;
+use crate::models::types::{CustomEntry, CustomSection};
+
+#[test]
+fn test_default_html_template_marks_up_the_selection() {
+    let dir = DataDir::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("data"));
+    let mut data = sample_data();
+    data.select_job_title(0);
+    data.experience[1].is_visible = false;
+    data.experience[0].toggle_bullet(1);
+    data.experience[0].role = "Dev <Lead>".to_string();
+
+    let html = render(&data.to_filtered_data(), &dir, None).unwrap();
+    assert!(html.contains(r#"<main class="h-resume">"#));
+    assert!(html.contains(r#"<h1 class="p-name">Jane Doe</h1>"#));
+    assert!(html.contains(r#"<a class="u-email" href="mailto:jane@example.com">"#));
+    assert!(html.contains(r#"<span class="p-name">Dev &lt;Lead&gt;</span>"#));
+    assert!(html.contains("<li>First bullet</li>"));
+    assert!(!html.contains("Second bullet"));
+    assert!(!html.contains("Intern"));
+    assert!(html.contains(r#"<span class="p-skill">Rust</span> (Advanced, 3 yrs)"#));
+    // Self-contained: no stylesheets, scripts or images to fetch
+    assert!(!html.contains("<link") && !html.contains("<script") && !html.contains("<img"));
+}
+
+#[test]
+fn test_html_templates_are_pluggable() {
+    let root = TempDir::new("html");
+    let dir = root.data_dir();
+    fs::create_dir_all(dir.html_templates_dir()).unwrap();
+    fs::write(
+        dir.html_templates_dir().join("_name.html"),
+        "<b>{{ resume.profile.name }}</b>",
+    )
+    .unwrap();
+    fs::write(
+        dir.html_templates_dir().join("card.html"),
+        "{% include \"_name.html\" %}",
+    )
+    .unwrap();
+
+    let data = sample_data().to_filtered_data();
+    let card = render(&data, &dir, Some("card"));
+    let missing = render(&data, &dir, None);
+
+    assert_eq!(card.unwrap(), "<b>Jane Doe</b>");
+    let err = missing.unwrap_err().to_string();
+    assert!(
+        err.contains("Unknown HTML template \"default.html\""),
+        "{}",
+        err
+    );
+    assert!(err.ends_with("Available: card.html"), "{}", err);
+}
+
+#[test]
+fn test_html_templates_share_the_field_placement() {
+    let root = TempDir::new("html-fields");
+    let dir = root.data_dir();
+    fs::create_dir_all(dir.html_templates_dir()).unwrap();
+    fs::write(
+        dir.html_templates_dir().join("talks.html"),
+        "{% autoescape false %}{% for e in resume.custom_sections[0].entries %}{{ details(e)|join(\", \") }} {{ href(e.url) }}{% endfor %}{% endautoescape %}",
+    )
+    .unwrap();
+    let mut data = sample_data();
+    let fields = [
+        ("title", "Talk"),
+        ("venue", "Zurich"),
+        ("city", "Bern"),
+        ("url", "x.dev"),
+    ];
+    data.custom_sections.push(CustomSection {
+        key: "talks".to_string(),
+        title: "Talks".to_string(),
+        entries: vec![CustomEntry {
+            id: None,
+            bullets: Vec::new(),
+            fields: fields
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            is_visible: true,
+        }],
+    });
+
+    let html = render(&data.to_filtered_data(), &dir, Some("talks")).unwrap();
+
+    // File order, as in the text and DOCX exports (minijinja keeps map order)
+    assert_eq!(html, "Zurich, Bern https://x.dev");
+}
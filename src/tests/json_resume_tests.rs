@@ -0,0 +1,195 @@
+use std::fs;
+
+use super::{TempDir, sample_data};
+use crate::cli::import::write_data_files;
+use crate::json_resume::{JsonResume, export};
+use crate::models::ResumeData;
+use crate::models::date::{DateEnd, YearMonth};
+use crate::models::types::Publication;
+
+const RESUME_JSON: &str = r#"{
+  "basics": {
+    "name": "Jane Doe",
+    "label": "Rust Developer",
+    "email": "jane@example.com",
+    "url": "https://jane.dev",
+    "summary": "Builds things.",
+    "location": { "city": "Nairobi", "countryCode": "KE" },
+    "profiles": [
+      { "network": "GitHub", "url": "https://github.com/jane" },
+      { "network": "LinkedIn", "url": "https://linkedin.com/in/jane" }
+    ]
+  },
+  "work": [{
+    "name": "Acme",
+    "position": "Developer",
+    "startDate": "2023-06-01",
+    "highlights": ["Shipped it"]
+  }],
+  "education": [{
+    "institution": "Uni",
+    "area": "Computer Science",
+    "studyType": "B.Sc",
+    "startDate": "2019",
+    "endDate": "2023-05-31"
+  }],
+  "projects": [{ "name": "Resume TUI", "keywords": ["Rust"], "url": "https://github.com/jane/tui" }],
+  "volunteer": [{ "organization": "Code Club", "position": "Mentor", "startDate": "2020-01" }],
+  "meta": { "ignored": true }
+}"#;
+
+#[test]
+fn test_json_resume_import_maps_onto_data_files() {
+    let resume: JsonResume = serde_json::from_str(RESUME_JSON).unwrap();
+    let data = resume.into_resume_data();
+
+    let profile = data.profile.as_ref().unwrap();
+    assert_eq!(profile.url, "https://linkedin.com/in/jane");
+    assert_eq!(profile.website, "https://jane.dev");
+    assert_eq!(profile.location, "Nairobi, KE");
+    assert_eq!(data.job_titles.len(), 1);
+    assert_eq!(data.job_titles[0].title, "Rust Developer");
+    assert_eq!(data.job_titles[0].professional_summary, "Builds things.");
+
+    let job = &data.experience[0];
+    assert_eq!(
+        (job.role.as_str(), job.company.as_str()),
+        ("Developer", "Acme")
+    );
+    assert_eq!(job.start, YearMonth::parse("2023-06"));
+    assert_eq!(job.end, Some(DateEnd::Present));
+    assert_eq!(job.bullets[0].text(), "Shipped it");
+
+    let edu = &data.education[0];
+    assert_eq!(edu.degree, "B.Sc Computer Science");
+    assert_eq!(edu.end, YearMonth::parse("2023-05").map(DateEnd::Date));
+    assert_eq!(data.projects[0].tech_stack, ["Rust"]);
+    assert_eq!(data.volunteering[0].date, "2020-01 - Present");
+}
+
+#[test]
+fn test_imported_files_load_back_and_are_not_overwritten() {
+    let root = TempDir::new("import");
+    let dir = root.data_dir();
+    let resume: JsonResume = serde_json::from_str(RESUME_JSON).unwrap();
+    let data = resume.into_resume_data();
+
+    let written = write_data_files(&data, &dir, false).unwrap();
+    let again = write_data_files(&data, &dir, false);
+    let (loaded, diagnostics) = ResumeData::load(&dir);
+
+    assert_eq!(written.len(), 6);
+    assert!(again.unwrap_err().to_string().contains("pass --force"));
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    assert_eq!(loaded.experience[0].start, data.experience[0].start);
+    assert_eq!(loaded.education[0].degree, "B.Sc Computer Science");
+    assert_eq!(
+        loaded.projects[0].url.as_deref(),
+        Some("https://github.com/jane/tui")
+    );
+}
+
+#[test]
+fn test_export_writes_the_filtered_selection() {
+    let mut data = sample_data();
+    data.select_job_title(0);
+    data.experience[1].is_visible = false;
+    data.experience[0].toggle_bullet(1);
+    data.experience[0].start = YearMonth::parse("2023-06");
+    data.experience[0].end = Some(DateEnd::Present);
+
+    let json: serde_json::Value = serde_json::from_str(&export(&data)).unwrap();
+    assert_eq!(json["basics"]["label"], "Rust Developer");
+    assert_eq!(json["basics"]["summary"], "Rust summary");
+    let work = json["work"].as_array().unwrap();
+    assert_eq!(work.len(), 1);
+    assert_eq!(work[0]["startDate"], "2023-06");
+    assert!(work[0].get("endDate").is_none());
+    assert_eq!(
+        work[0]["highlights"],
+        serde_json::json!(["First bullet", "Third bullet"])
+    );
+    assert_eq!(
+        json["skills"][0]["keywords"],
+        serde_json::json!(["Rust", "Go"])
+    );
+
+    // And back again
+    let resume: JsonResume = serde_json::from_value(json).unwrap();
+    let imported = resume.into_resume_data();
+    assert_eq!(imported.experience[0].end, Some(DateEnd::Present));
+    assert_eq!(imported.experience[0].bullets.len(), 2);
+}
+
+#[test]
+fn test_volunteering_and_publications_round_trip() {
+    let resume: JsonResume = serde_json::from_str(RESUME_JSON).unwrap();
+    let mut data = resume.into_resume_data();
+    let mut ended = data.volunteering[0].clone();
+    ended.date = "2018-03 \u{2013} 2019-11".to_string();
+    let mut single = ended.clone();
+    single.date = "2017-05".to_string();
+    data.volunteering.extend([ended, single]);
+    data.publications.push(Publication {
+        id: None,
+        title: "Paper".to_string(),
+        venue: "Journal".to_string(),
+        authors: vec!["J. Doe".to_string()],
+        date: "2022-02".to_string(),
+        doi: None,
+        url: None,
+        is_visible: true,
+    });
+
+    let json: serde_json::Value = serde_json::from_str(&export(&data)).unwrap();
+    let volunteer = json["volunteer"].as_array().unwrap();
+    let dates: Vec<(&str, &str)> = volunteer
+        .iter()
+        .map(|v| {
+            (
+                v["startDate"].as_str().unwrap_or_default(),
+                v["endDate"].as_str().unwrap_or_default(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        dates,
+        [
+            ("2020-01", ""),
+            ("2018-03", "2019-11"),
+            ("2017-05", "2017-05")
+        ]
+    );
+    assert!(json["publications"][0].get("summary").is_none());
+
+    let resume: JsonResume = serde_json::from_value(json).unwrap();
+    let imported = resume.into_resume_data();
+    let dates: Vec<&str> = imported
+        .volunteering
+        .iter()
+        .map(|v| v.date.as_str())
+        .collect();
+    assert_eq!(dates, ["2020-01 - Present", "2018-03 - 2019-11", "2017-05"]);
+}
+
+#[test]
+fn test_overwritten_files_keep_their_format() {
+    let root = TempDir::new("import-toml");
+    let dir = root.data_dir();
+    fs::write(root.join("experience.toml"), "").unwrap();
+    fs::write(root.join("profile.json"), "{}").unwrap();
+    let resume: JsonResume = serde_json::from_str(RESUME_JSON).unwrap();
+    let data = resume.into_resume_data();
+
+    let written = write_data_files(&data, &dir, true).unwrap();
+    let (loaded, diagnostics) = ResumeData::load(&dir);
+    let toml = fs::read_to_string(root.join("experience.toml")).unwrap();
+
+    assert!(written.contains(&root.join("experience.toml")));
+    assert!(written.contains(&root.join("profile.json")));
+    assert!(!root.join("experience.yaml").exists());
+    assert!(toml.starts_with("[[experience]]"), "{}", toml);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    assert_eq!(loaded.experience[0].end, Some(DateEnd::Present));
+    assert_eq!(loaded.profile.unwrap().name, "Jane Doe");
+}
@@ -0,0 +1,306 @@
+use std::fs;
+use std::io::Write;
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+use super::{TempDir, sample_data};
+use crate::cli::import::{check_writable, diff, plan_merge};
+use crate::linkedin::{Archive, merge, split_description};
+use crate::models::date::{DateEnd, YearMonth};
+use crate::models::types::Bullet;
+
+const POSITIONS: &str = "\u{feff}Company Name,Title,Description,Location,Started On,Finished On
+Acme,Developer,\"Built the thing.
+- First bullet
+- Shipped v2, on time\",Remote,Jun 2023,
+Gamma,Engineer,Led the migration. Cut costs by 20%.,Berlin,Jan 2020,May 2023
+";
+
+const PROFILE: &str = "First Name,Last Name,Headline,Summary,Geo Location,Websites
+Jane,Doe,Rust Developer,Builds things.,\"Nairobi, Kenya\",\"[PORTFOLIO:https://jane.dev]\"
+";
+
+const EDUCATION: &str = "School Name,Start Date,End Date,Notes,Degree Name,Activities
+Uni,2019,2023,,B.Sc,
+";
+
+#[test]
+fn test_descriptions_split_into_bullets() {
+    let (summary, bullets) = split_description("Built the thing.\n- One\n• Two\n3. Three");
+    assert_eq!(summary, "Built the thing.");
+    assert_eq!(bullets, ["One", "Two", "Three"]);
+
+    // Inline markers, and a leading minus that is not a marker
+    let (_, bullets) = split_description("• Did this • Did that");
+    assert_eq!(bullets, ["Did this", "Did that"]);
+    assert_eq!(split_description("-5% churn").0, "-5% churn");
+
+    // One paragraph: sentences, but not on abbreviations
+    let (summary, bullets) = split_description("Led it, e.g. Rust. Cut costs by 20%.");
+    assert!(summary.is_empty());
+    assert_eq!(bullets, ["Led it, e.g. Rust.", "Cut costs by 20%."]);
+    assert_eq!(split_description("Just one.").0, "Just one.");
+}
+
+#[test]
+fn test_archive_reads_a_folder_or_a_zip() {
+    let root = TempDir::new("linkedin-read");
+    fs::write(root.join("Positions.csv"), POSITIONS).unwrap();
+    fs::write(root.join("Profile.csv"), PROFILE).unwrap();
+    let from_dir = Archive::open(&root).and_then(Archive::into_resume_data);
+
+    let zip_path = root.join("export.zip");
+    let mut zip = ZipWriter::new(fs::File::create(&zip_path).unwrap());
+    zip.start_file(
+        "Basic_LinkedInDataExport/Education.csv",
+        SimpleFileOptions::default(),
+    )
+    .unwrap();
+    zip.write_all(EDUCATION.as_bytes()).unwrap();
+    zip.finish().unwrap();
+    let from_zip = Archive::open(&zip_path).and_then(Archive::into_resume_data);
+    let empty = Archive::open(&root.join("missing"));
+
+    let data = from_dir.unwrap();
+    let profile = data.profile.unwrap();
+    assert_eq!(profile.name, "Jane Doe");
+    assert_eq!(profile.location, "Nairobi, Kenya");
+    assert_eq!(profile.website, "https://jane.dev");
+
+    let job = &data.experience[0];
+    assert_eq!(job.summary, "Built the thing.");
+    assert_eq!(job.bullets[1].text(), "Shipped v2, on time");
+    assert_eq!(job.start, YearMonth::parse("2023-06"));
+    assert_eq!(job.end, Some(DateEnd::Present));
+    assert_eq!(
+        data.experience[1].end,
+        YearMonth::parse("2023-05").map(DateEnd::Date)
+    );
+
+    let edu = &from_zip.unwrap().education[0];
+    assert_eq!((edu.school.as_str(), edu.degree.as_str()), ("Uni", "B.Sc"));
+    assert_eq!(edu.start, YearMonth::parse("2019"));
+    assert!(empty.is_err());
+}
+
+#[test]
+fn test_merge_fills_gaps_and_never_overwrites() {
+    let root = TempDir::new("linkedin-merge");
+    fs::write(root.join("Positions.csv"), POSITIONS).unwrap();
+    fs::write(root.join("Education.csv"), EDUCATION).unwrap();
+    let imported = Archive::open(&root).and_then(Archive::into_resume_data);
+    let mut imported = imported.unwrap();
+    imported.experience[0].bullets[0] = Bullet::Text("first  BULLET".into());
+
+    let mut data = sample_data();
+    let changes = merge(&mut data, imported.clone());
+
+    // Acme: summary and free-text date kept, one bullet already there
+    let acme = &data.experience[0];
+    assert_eq!(acme.summary, "s");
+    assert_eq!(acme.bullets.len(), 4);
+    assert_eq!(acme.start, None);
+    assert_eq!(data.experience[2].company, "Gamma");
+    assert_eq!(data.education[0].start, YearMonth::parse("2019"));
+
+    let lines: Vec<String> = changes
+        .iter()
+        .map(|c| format!("{} {}", c.file, c.description))
+        .collect();
+    assert_eq!(
+        lines,
+        [
+            "experience.yaml ~ Developer at Acme: 1 new bullet",
+            "experience.yaml + Engineer at Gamma (2 bullets)",
+            "education.yaml ~ B.Sc at Uni: dates",
+        ]
+    );
+
+    // Importing the same archive twice changes nothing
+    assert!(merge(&mut data, imported).is_empty());
+}
+
+// Written the way data/ is: quoted strings, comments, blank lines between
+// entries, oldest first (which sort_by_date would reverse)
+const EXPERIENCE_YAML: &str = r#"# yaml-language-server: $schema=schemas/experience.schema.json
+# Oldest first
+- id: "intern"
+  role: "Technical Support Intern"
+  company: "Old Co"
+  location: "Nairobi"
+  date: "Feb 2015 - May 2015"
+  summary: "Provided technical support."
+  bullets:
+    - "Managed software installation." # for 3 departments
+
+- role: "Developer"
+  company: "Acme"
+  location: "Remote"
+  start: 2023-06
+  end: present
+  summary: "Built the thing."
+  bullets:
+    - id: "first"
+      text: "First bullet"
+"#;
+
+const EDUCATION_YAML: &str = r#"- education:
+    - degree: "M.Sc"
+      school: "Tech"
+      status: "Expected"
+- education:
+    - degree: "B.Sc"
+      school: "Uni"
+      status: "Graduated"
+"#;
+
+// The files the import leaves alone
+const REST: &[(&str, &str)] = &[
+    (
+        "profile.yaml",
+        "name: \"Jane\"\nemail: \"jane@x.dev\"\nphone: \"1\"\nurl: \"https://jane.dev\"\nwebsite: \"https://jane.dev\"\nlocation: \"Nairobi\"\ncitizenship: \"KE\"\n",
+    ),
+    (
+        "jobtitles.yaml",
+        "- title: \"Dev\"\n  professional_summary: \"Builds things.\"\n",
+    ),
+    ("projects.yaml", "[]\n"),
+];
+
+#[test]
+fn test_merge_plan_appends_new_entries_and_bullets() {
+    let root = TempDir::with_files(
+        "linkedin-plan",
+        &[
+            REST,
+            &[
+                ("experience.yaml", EXPERIENCE_YAML),
+                ("education.yaml", EDUCATION_YAML),
+            ],
+        ]
+        .concat(),
+    );
+    let dir = root.data_dir();
+    let archive = TempDir::with_files("linkedin-plan-archive", &[("Positions.csv", POSITIONS)]);
+    let imported = || Archive::open(&archive).and_then(Archive::into_resume_data);
+
+    let rewrites = plan_merge(imported().unwrap(), &dir).unwrap();
+    assert_eq!(rewrites.len(), 1);
+    assert!(rewrites[0].patched);
+    assert_eq!(
+        rewrites[0].changes,
+        [
+            "~ Developer at Acme: 1 new bullet",
+            "+ Engineer at Gamma (2 bullets)"
+        ]
+    );
+    // Every existing line survives; the new ones are added in between
+    let lines = diff(&rewrites[0].old, &rewrites[0].new);
+    assert!(!lines.iter().any(|l| l.starts_with('-')), "{:?}", lines);
+    let experience = &rewrites[0].new;
+    assert!(
+        experience.starts_with(&EXPERIENCE_YAML[..EXPERIENCE_YAML.len() - 1]),
+        "{}",
+        experience
+    );
+    assert!(
+        experience.contains("      text: \"First bullet\"\n    - Shipped v2, on time\n\n- role: Engineer\n  company: Gamma\n"),
+        "{}",
+        experience
+    );
+    assert!(
+        experience.ends_with("  bullets:\n    - Led the migration.\n    - Cut costs by 20%.\n"),
+        "{}",
+        experience
+    );
+    assert!(!experience.contains("summary: ''"), "{}", experience);
+    assert!(check_writable(&rewrites, &dir, false).is_ok());
+
+    // What was planned loads back as the merge
+    fs::write(&rewrites[0].path, &rewrites[0].new).unwrap();
+    let (loaded, diagnostics) = crate::models::ResumeData::load_in_file_order(&dir);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    assert_eq!(loaded.experience[1].bullets.len(), 2);
+    assert_eq!(loaded.experience[2].company, "Gamma");
+    assert!(plan_merge(imported().unwrap(), &dir).unwrap().is_empty());
+}
+
+#[test]
+fn test_merge_plan_keeps_comments_next_to_new_bullets() {
+    let experience =
+        EXPERIENCE_YAML.replace("  bullets:\n    - id", "  bullets: # best first\n    - id");
+    let root = TempDir::with_files(
+        "linkedin-comments",
+        &[
+            REST,
+            &[
+                ("experience.yaml", &experience),
+                ("education.yaml", EDUCATION_YAML),
+            ],
+        ]
+        .concat(),
+    );
+    let dir = root.data_dir();
+    let archive = TempDir::with_files("linkedin-comments-archive", &[("Positions.csv", POSITIONS)]);
+    let imported = Archive::open(&archive).and_then(Archive::into_resume_data);
+
+    let rewrites = plan_merge(imported.unwrap(), &dir).unwrap();
+    assert!(rewrites[0].patched);
+    assert!(
+        rewrites[0].new.starts_with(&experience),
+        "{}",
+        rewrites[0].new
+    );
+    assert!(check_writable(&rewrites, &dir, false).is_ok());
+}
+
+#[test]
+fn test_merge_plan_needs_force_to_change_fields() {
+    let root = TempDir::with_files(
+        "linkedin-force",
+        &[
+            REST,
+            &[
+                ("experience.yaml", EXPERIENCE_YAML),
+                ("education.yaml", EDUCATION_YAML),
+            ],
+        ]
+        .concat(),
+    );
+    let dir = root.data_dir();
+    let archive = TempDir::with_files("linkedin-force-archive", &[("Education.csv", EDUCATION)]);
+    let imported = || Archive::open(&archive).and_then(Archive::into_resume_data);
+
+    // Filling in the dates of an existing entry is more than an append
+    let rewrites = plan_merge(imported().unwrap(), &dir).unwrap();
+    assert_eq!(rewrites[0].changes, ["~ B.Sc at Uni: dates"]);
+    assert!(!rewrites[0].patched);
+    let refused = check_writable(&rewrites, &dir, false)
+        .unwrap_err()
+        .to_string();
+    assert!(refused.contains("--force"), "{}", refused);
+    assert!(check_writable(&rewrites, &dir, true).is_ok());
+
+    // --force doesn't drop comments
+    fs::write(
+        root.join("education.yaml"),
+        format!("# Newest first\n{}", EDUCATION_YAML),
+    )
+    .unwrap();
+    let rewrites = plan_merge(imported().unwrap(), &dir).unwrap();
+    let refused = check_writable(&rewrites, &dir, true)
+        .unwrap_err()
+        .to_string();
+    assert!(refused.contains("has comments"), "{}", refused);
+}
+
+#[test]
+fn test_diff_shows_changes_in_context() {
+    let old = "a\nb\nc\nd\ne\nf\ng\n";
+    let new = "a\nb\nc\nd\ne\nF\ng\nh\n";
+    assert_eq!(
+        diff(old, new),
+        ["...", "  d", "  e", "- f", "+ F", "  g", "+ h"]
+    );
+    assert!(diff(old, old).is_empty());
+}
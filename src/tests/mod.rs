@@ -1 +1,96 @@
+use crate::data_dir::DataDir;
+use crate::models::ResumeData;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
 mod app_tests;
+mod cli_tests;
+mod diagnostics_tests;
+mod docx_tests;
+mod html_tests;
+mod json_resume_tests;
+mod linkedin_tests;
+mod model_tests;
+mod patch_tests;
+mod preset_tests;
+mod preview_tests;
+mod schema_tests;
+mod template_tests;
+mod text_export_tests;
+mod typst_tests;
+
+// Small dataset shared by the tests, parsed from YAML like the real loader
+pub fn sample_data() -> ResumeData {
+    ResumeData {
+        profile: Some(
+            serde_yaml::from_str(
+                "name: Jane Doe\nemail: jane@example.com\nphone: '123'\nurl: ''\nwebsite: ''\nlocation: ''\ncitizenship: ''",
+            )
+            .unwrap(),
+        ),
+        job_titles: serde_yaml::from_str(
+            "- title: Rust Developer\n  professional_summary: Rust summary\n- title: Frontend Specialist\n  professional_summary: Frontend summary",
+        )
+        .unwrap(),
+        education: serde_yaml::from_str(
+            "- school: Uni\n  degree: B.Sc\n  status: Graduated 2025\n- school: Uni\n  degree: M.Sc\n  status: Expected 2026",
+        )
+        .unwrap(),
+        experience: serde_yaml::from_str(
+            "- role: Developer\n  company: Acme\n  location: Remote\n  date: 2023 - Current\n  summary: s\n  bullets: [First bullet, Second bullet, Third bullet]\n- role: Intern\n  company: Beta\n  location: Nairobi\n  date: 2023\n  summary: s\n  bullets: [Only bullet]",
+        )
+        .unwrap(),
+        projects: serde_yaml::from_str(
+            "- title: Calculator App\n  description: d\n  tech_stack: []\n- title: Resume TUI\n  description: d\n  tech_stack: []",
+        )
+        .unwrap(),
+        skills: serde_yaml::from_str(
+            "- category: Languages\n  skills:\n    - name: Rust\n      proficiency: Advanced\n      years: 3\n    - name: Go\n- category: Tools\n  skills:\n    - name: Docker",
+        )
+        .unwrap(),
+        ..Default::default()
+    }
+}
+
+// Scratch directory under the system temp dir, named after the test and the
+// process. Removed on drop, so a failing assertion doesn't leave it behind.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new(name: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("resume-pipeline-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    /// A scratch directory holding `files`, each a path relative to it and
+    /// its contents. Parent directories are created as needed.
+    pub fn with_files(name: &str, files: &[(&str, &str)]) -> Self {
+        let root = Self::new(name);
+        for (file, content) in files {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        root
+    }
+
+    pub fn data_dir(&self) -> DataDir {
+        DataDir::new(&self.0)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
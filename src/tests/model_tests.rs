@@ -0,0 +1,147 @@
+use super::TempDir;
+use crate::models::date::{DateEnd, DateFormat, DateRange, YearMonth};
+use crate::models::types::{Bullet, Experience, Project};
+use crate::models::{ResumeData, Section};
+use crate::tests::sample_data;
+
+#[test]
+fn test_bullets_accept_strings_and_objects() {
+    let job: Experience = serde_yaml::from_str(
+        "role: Dev\ncompany: Acme\nlocation: ''\ndate: ''\nsummary: ''\nbullets:\n  - Plain bullet\n  - id: perf\n    text: Cut latency by 40%\n  - text: Object without id",
+    )
+    .unwrap();
+
+    assert_eq!(job.bullets[0], Bullet::Text("Plain bullet".to_string()));
+    assert_eq!(job.bullets[1].text(), "Cut latency by 40%");
+    assert_eq!(job.bullets[1].stable_id(), "perf");
+    // Derived ids only depend on the text, not on the YAML shape
+    assert_eq!(
+        job.bullets[2].stable_id(),
+        Bullet::from("Object without id").stable_id()
+    );
+}
+
+#[test]
+fn test_explicit_ids_override_derived_ones() {
+    let with_id: Project =
+        serde_yaml::from_str("id: calc\ntitle: Calculator App\ndescription: d\ntech_stack: []")
+            .unwrap();
+    let without_id: Project =
+        serde_yaml::from_str("title: Calculator App\ndescription: d\ntech_stack: []").unwrap();
+
+    assert_eq!(with_id.stable_id(), "calc");
+    assert_eq!(without_id.stable_id().len(), 8);
+    assert_eq!(without_id.stable_id(), without_id.clone().stable_id());
+}
+
+#[test]
+fn test_hidden_bullets_survive_insertions() {
+    let mut data = sample_data();
+    data.experience[0].toggle_bullet(1); // "Second bullet"
+
+    // Someone adds a bullet at the top of experience.yaml
+    data.experience[0]
+        .bullets
+        .insert(0, "Brand new bullet".into());
+
+    let filtered = data.to_filtered_data();
+    let bullets: Vec<&str> = filtered.experience[0]
+        .bullets
+        .iter()
+        .map(|b| b.text())
+        .collect();
+    assert_eq!(
+        bullets,
+        vec!["Brand new bullet", "First bullet", "Third bullet"]
+    );
+}
+
+#[test]
+fn test_custom_sections_load_from_sections_dir() {
+    let root = TempDir::new("sections");
+    std::fs::create_dir_all(root.join("sections")).unwrap();
+    std::fs::write(
+        root.join("sections/patents.yaml"),
+        "title: Patents\nentries:\n  - title: Widget\n    number: 12345\n    date: 2021\n  - name: Gadget\n    bullets: [Filed in two regions]",
+    )
+    .unwrap();
+    std::fs::write(root.join("sections/notes.txt"), "ignored").unwrap();
+
+    let (mut data, diagnostics) = ResumeData::load(&root.data_dir());
+    assert!(!diagnostics.iter().any(|d| d.is_error()));
+
+    assert_eq!(data.custom_sections.len(), 1);
+    let section = Section::Custom(0);
+    assert_eq!(data.section_title(section), "Patents");
+    assert_eq!(data.section_key(section), "sections/patents");
+
+    // Numbers are kept as text, in file order
+    let widget = &data.custom_sections[0].entries[0];
+    assert_eq!(widget.field("number"), Some("12345"));
+    assert_eq!(widget.fields[0].0, "title");
+
+    let labels: Vec<String> = data
+        .section_entries(section)
+        .iter()
+        .map(|e| e.label())
+        .collect();
+    assert_eq!(labels, vec!["Widget (2021)", "Gadget"]);
+
+    data.toggle_section_entry(section, 0);
+    let filtered = data.to_filtered_data();
+    assert_eq!(filtered.custom_sections[0].entries.len(), 1);
+    assert_eq!(
+        filtered.custom_sections[0].entries[0].field("name"),
+        Some("Gadget")
+    );
+
+    // Sections whose entries are all hidden are dropped
+    data.toggle_section_entry(section, 1);
+    assert!(data.to_filtered_data().custom_sections.is_empty());
+}
+
+#[test]
+fn test_structured_dates_format_and_sort() {
+    let mut data = sample_data();
+    data.experience = serde_yaml::from_str(
+        "- role: Intern\n  company: Beta\n  location: ''\n  date: Summer 2019\n  summary: ''\n  bullets: []\n- role: Engineer\n  company: Gamma\n  location: ''\n  start: 2020-01\n  end: 2021-03\n  summary: ''\n  bullets: []\n- role: Lead\n  company: Acme\n  location: ''\n  date: Since 2021\n  start: 2021-04\n  end: present\n  summary: ''\n  bullets: []",
+    )
+    .unwrap();
+
+    let gamma = data.experience[1].dates();
+    assert_eq!(
+        gamma.format(&DateFormat::default()).unwrap(),
+        "Jan 2020 \u{2013} Mar 2021"
+    );
+    assert_eq!(gamma.duration(YearMonth::today()).unwrap(), "1 yr 3 mos");
+    assert_eq!(data.experience[2].end, Some(DateEnd::Present));
+
+    data.config.date_format = DateFormat {
+        pattern: "%Y-%m".to_string(),
+        present: "now".to_string(),
+        ..Default::default()
+    };
+    let filtered = data.to_filtered_data();
+    assert_eq!(filtered.experience[0].date, "Summer 2019");
+    assert_eq!(filtered.experience[2].date, "2021-04 \u{2013} now");
+    // The free text still reaches templates next to the formatted dates
+    assert_eq!(filtered.experience[2].date_text, "Since 2021");
+    assert_eq!(filtered.experience[0].date_text, "Summer 2019");
+
+    // Newest first; entries with only a free-text date go last
+    data.sort_by_date();
+    let roles: Vec<&str> = data.experience.iter().map(|e| e.role.as_str()).collect();
+    assert_eq!(roles, vec!["Lead", "Engineer", "Intern"]);
+
+    assert!(serde_yaml::from_str::<YearMonth>("2020-13").is_err());
+
+    // Imported dates: a start with a blank end is ongoing, an unreadable
+    // end is left unset
+    let parse = |s: &str| YearMonth::parse(s.trim());
+    assert_eq!(
+        DateRange::from_strs("2020-01", "  ", parse).end,
+        Some(DateEnd::Present)
+    );
+    assert_eq!(DateRange::from_strs("2020-01", "soon", parse).end, None);
+    assert!(DateRange::from_strs("", "", parse).is_empty());
+}
@@ -0,0 +1,121 @@
+use std::path::Path;
+
+use crate::models::patch;
+use crate::models::types::{Bullet, Experience};
+
+fn experience(content: &str) -> Vec<Experience> {
+    serde_yaml::from_str(content).unwrap()
+}
+
+fn with_bullet(entries: &[Experience], index: usize, text: &str) -> Vec<Experience> {
+    let mut entries = entries.to_vec();
+    entries[index].bullets.push(Bullet::Text(text.to_string()));
+    entries
+}
+
+const BLOCK_SCALARS: &str = "- role: Dev
+  company: Acme
+  summary: |
+    Line one
+    # not a comment
+  bullets:
+    - |
+      Multi
+      \tline
+    # trailing note
+    - >-
+      folded
+      text
+
+      para
+
+- role: Ops
+  company: Beta
+  bullets:
+    - Ran things
+";
+
+#[test]
+fn test_new_bullets_go_after_block_scalars() {
+    let path = Path::new("experience.yaml");
+    let old = experience(BLOCK_SCALARS);
+    let new = with_bullet(&old, 0, "New bullet");
+
+    let patched = patch::entries(path, BLOCK_SCALARS, "experience", &old, &new).unwrap();
+    assert_eq!(
+        patched,
+        BLOCK_SCALARS.replace("      para\n", "      para\n    - New bullet\n")
+    );
+}
+
+const QUOTED_KEYS: &str = r#"- "role": "Dev"
+  'company': "Acme: Labs"
+  location: &where Remote
+  "bullets":
+    - "a: b"
+
+- role: Ops
+  company: Beta
+  location: *where
+"#;
+
+#[test]
+fn test_quoted_keys_and_anchors_are_kept() {
+    let path = Path::new("experience.yaml");
+    let old = experience(QUOTED_KEYS);
+    let mut new = with_bullet(&old, 0, "c: d");
+    let mut globex = old[1].clone();
+    globex.company = "Globex".to_string();
+    globex.location = String::new();
+    new.push(globex);
+
+    let patched = patch::entries(path, QUOTED_KEYS, "experience", &old, &new).unwrap();
+    let expected = QUOTED_KEYS.replace("    - \"a: b\"\n", "    - \"a: b\"\n    - 'c: d'\n")
+        + "\n- role: Ops\n  company: Globex\n";
+    assert_eq!(patched, expected);
+}
+
+#[test]
+fn test_new_entries_leave_out_empty_fields() {
+    let content = "- role: Dev\n  company: Acme\n  summary: Built it\n  bullets:\n    - One\n";
+    let old = experience(content);
+    let mut new = old.clone();
+    new.push(
+        experience(
+            "- role: Engineer\n  company: Globex\n  location: ''\n  summary: ''\n  bullets: []\n",
+        )
+        .remove(0),
+    );
+
+    let patched = patch::entries(
+        Path::new("experience.yaml"),
+        content,
+        "experience",
+        &old,
+        &new,
+    );
+    assert_eq!(
+        patched.unwrap(),
+        format!("{}- role: Engineer\n  company: Globex\n", content)
+    );
+}
+
+#[test]
+fn test_only_appends_are_patched() {
+    let path = Path::new("experience.yaml");
+    let content = "- role: Dev\n  company: Acme\n  summary: ''\n  bullets: [One]\n";
+    let old = experience(content);
+
+    // A changed field needs the whole file rewritten
+    let mut filled = old.clone();
+    filled[0].summary = "Built it".to_string();
+    assert!(patch::entries(path, content, "experience", &old, &filled).is_none());
+
+    // So does adding to a flow list
+    let added = with_bullet(&old, 0, "Two");
+    assert!(patch::entries(path, content, "experience", &old, &added).is_none());
+
+    // And anything that isn't YAML
+    let json = Path::new("experience.json");
+    assert!(patch::entries(json, content, "experience", &old, &old).is_none());
+}
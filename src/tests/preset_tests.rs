@@ -0,0 +1,174 @@
+use crate::app::{App, CurrentScreen};
+use crate::models::preset::Preset;
+use crate::tests::{TempDir, sample_data};
+use crossterm::event::KeyCode;
+use std::collections::BTreeMap;
+
+#[test]
+fn test_preset_roundtrip_survives_reordering() {
+    let mut data = sample_data();
+    data.select_job_title(1);
+    data.profile.as_mut().unwrap().show_phone = false;
+    data.education[0].is_visible = false;
+    data.projects[0].is_visible = false;
+    data.experience[0].toggle_bullet(1);
+
+    let preset = Preset::capture(&data);
+    assert_eq!(preset.job_title.as_deref(), Some("Frontend Specialist"));
+    assert_eq!(
+        preset.hidden.get("projects"),
+        Some(&vec![data.projects[0].stable_id()])
+    );
+    assert_eq!(
+        preset.hidden_bullets.get(&data.experience[0].stable_id()),
+        Some(&vec![data.experience[0].bullets[1].stable_id()])
+    );
+
+    // Fresh data with the lists in a different order
+    let mut fresh = sample_data();
+    fresh.education.reverse();
+    fresh.projects.reverse();
+    fresh.experience[0]
+        .bullets
+        .insert(0, "New bullet".into());
+
+    let warnings = preset.apply(&mut fresh);
+    assert!(warnings.is_empty(), "{:?}", warnings);
+    assert_eq!(fresh.job_title.as_deref(), Some("Frontend Specialist"));
+    assert!(!fresh.profile.as_ref().unwrap().show_phone);
+
+    let filtered = fresh.to_filtered_data();
+    assert_eq!(filtered.education.len(), 1);
+    assert_eq!(filtered.education[0].degree, "M.Sc");
+    assert_eq!(filtered.projects[0].title, "Resume TUI");
+    let bullets: Vec<&str> = filtered.experience[0]
+        .bullets
+        .iter()
+        .map(|b| b.text())
+        .collect();
+    assert_eq!(bullets, vec!["New bullet", "First bullet", "Third bullet"]);
+}
+
+#[test]
+fn test_preset_reports_stale_entries() {
+    let preset = Preset {
+        job_title: Some("Astronaut".to_string()),
+        hidden: BTreeMap::from([("projects".to_string(), vec!["deleted-project".to_string()])]),
+        ..Preset::capture(&sample_data())
+    };
+    let warnings = preset.apply(&mut sample_data());
+    assert_eq!(warnings.len(), 2);
+}
+
+#[test]
+fn test_entries_added_after_saving_are_shown() {
+    let mut data = sample_data();
+    data.projects[0].is_visible = false;
+    let preset = Preset::capture(&data);
+
+    let mut fresh = sample_data();
+    let mut added = fresh.projects[1].clone();
+    added.title = "Added Later".to_string();
+    fresh.projects.push(added);
+    fresh.certifications = serde_yaml::from_str("- name: CKAD\n  issuer: CNCF").unwrap();
+    fresh.certifications[0].is_visible = false;
+
+    let warnings = preset.apply(&mut fresh);
+    assert!(warnings.is_empty(), "{:?}", warnings);
+    let visible: Vec<bool> = fresh.projects.iter().map(|p| p.is_visible).collect();
+    assert_eq!(visible, vec![false, true, true]);
+    // Every list follows the same rule, optional sections included
+    assert!(fresh.certifications[0].is_visible);
+}
+
+#[test]
+fn test_older_presets_list_visible_entries() {
+    let data = sample_data();
+    let preset: Preset = serde_yaml::from_str(&format!(
+        "education: []\nexperience: []\nprojects: [{}]\n",
+        data.projects[1].stable_id()
+    ))
+    .unwrap();
+
+    let mut fresh = sample_data();
+    let warnings = preset.apply(&mut fresh);
+    assert!(!fresh.projects[0].is_visible);
+    assert!(fresh.projects[1].is_visible);
+    assert!(fresh.education.iter().all(|e| !e.is_visible));
+    // Saved before skills existed: left alone
+    assert!(fresh.skills.iter().all(|c| c.is_visible));
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("save the preset again"));
+
+    // Saving it again writes the hidden entries
+    let saved = serde_yaml::to_string(&Preset::capture(&fresh)).unwrap();
+    assert!(saved.contains("hidden:"), "{}", saved);
+    assert!(!saved.contains("\nprojects:"), "{}", saved);
+}
+
+#[test]
+fn test_save_preset_screen_accepts_text() {
+    let root = TempDir::new("presets");
+    let mut app = App {
+        data: sample_data(),
+        data_dir: root.data_dir(),
+        current_screen: CurrentScreen::ProjectsSelection,
+        ..Default::default()
+    };
+
+    app.handle_key_event(KeyCode::Char('s'));
+    assert_eq!(app.current_screen, CurrentScreen::SavePreset);
+
+    // 'q' is part of the name here, not quit
+    for c in "quick win".chars() {
+        app.handle_key_event(KeyCode::Char(c));
+    }
+    app.handle_key_event(KeyCode::Enter);
+    assert_eq!(app.current_screen, CurrentScreen::ProjectsSelection);
+    assert_eq!(app.presets, vec!["quick-win".to_string()]);
+
+    let loaded = Preset::load(&app.data_dir, "Quick Win").unwrap();
+    assert_eq!(loaded, Preset::capture(&app.data));
+}
+
+#[test]
+fn test_listed_presets_load_and_saving_asks_before_replacing() {
+    let root = TempDir::new("presets-replace");
+    let dir = root.data_dir();
+    let preset = Preset::capture(&sample_data());
+    std::fs::create_dir_all(dir.presets_dir()).unwrap();
+    std::fs::write(
+        dir.presets_dir().join("Senior_Backend.yaml"),
+        serde_yaml::to_string(&preset).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(Preset::list(&dir), ["Senior_Backend"]);
+    assert_eq!(Preset::load(&dir, "Senior_Backend").unwrap(), preset);
+    assert!(preset.save(&dir, "Senior_Backend", false).is_err());
+
+    let mut app = App {
+        data: sample_data(),
+        data_dir: dir,
+        current_screen: CurrentScreen::ProjectsSelection,
+        ..Default::default()
+    };
+    app.data.projects[0].is_visible = false;
+    app.handle_key_event(KeyCode::Char('s'));
+    for c in "Senior_Backend".chars() {
+        app.handle_key_event(KeyCode::Char(c));
+    }
+    app.handle_key_event(KeyCode::Enter);
+    assert_eq!(app.current_screen, CurrentScreen::SavePreset);
+    assert!(app.notice.as_deref().unwrap().contains("already exists"));
+    assert_eq!(
+        Preset::load(&app.data_dir, "Senior_Backend").unwrap(),
+        preset
+    );
+
+    app.handle_key_event(KeyCode::Enter);
+    assert_eq!(app.current_screen, CurrentScreen::ProjectsSelection);
+    assert_eq!(app.presets, ["Senior_Backend"]);
+    let saved = Preset::load(&app.data_dir, "Senior_Backend").unwrap();
+    assert_eq!(saved, Preset::capture(&app.data));
+}
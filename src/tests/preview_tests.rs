@@ -0,0 +1,200 @@
+use super::{TempDir, sample_data};
+use crate::app::{App, CurrentScreen};
+use crate::pdf::{OutputFormat, compile};
+use crate::preview::Preview;
+use crossterm::event::KeyCode;
+
+#[test]
+fn test_preview_keeps_lines_columns_and_pages() {
+    let root = TempDir::with_files(
+        "preview-layout",
+        &[(
+            "templates/default_resume_template.typ",
+            "#set page(width: 200pt, height: 100pt, margin: 10pt)\n\
+             #grid(columns: (1fr, auto), [Developer], [2023])\n\
+             Second line\n#pagebreak()\nOverflow\n",
+        )],
+    );
+    let dir = root.data_dir();
+    let (document, _) = compile(&sample_data(), &dir).unwrap();
+
+    let preview = Preview::from_document(&document);
+    assert_eq!(preview.pages.len(), 2);
+    let first: Vec<String> = preview.pages[0]
+        .lines
+        .iter()
+        .map(|l| l.render(40))
+        .collect();
+    assert_eq!(first.len(), 2);
+    assert!(first[0].starts_with("  Developer"), "{:?}", first);
+    assert!(first[0].trim_end().ends_with("2023"), "{:?}", first);
+    assert_eq!(first[1].trim(), "Second line");
+    assert_eq!(preview.overflow(), Some(1));
+}
+
+#[test]
+fn test_preview_follows_toggles() {
+    let root = TempDir::with_files(
+        "preview-toggle",
+        &[(
+            "templates/default_resume_template.typ",
+            "#for job in sys.inputs.experience [#for b in job.bullets [#b \\ ]]\n",
+        )],
+    );
+    let dir = root.data_dir();
+    let mut app = App {
+        data: sample_data(),
+        data_dir: dir.clone(),
+        current_screen: CurrentScreen::ExperienceBulletSelection,
+        ..Default::default()
+    };
+    app.experience_list_state.select(Some(0));
+    app.experience_bullet_list_state.select(Some(0));
+
+    app.handle_key_event(KeyCode::Char('v'));
+    app.wait_for_preview();
+    let before = app.preview.clone().unwrap().unwrap();
+    app.handle_key_event(KeyCode::Char(' '));
+    app.wait_for_preview();
+    let after = app.preview.clone().unwrap().unwrap();
+
+    let text = |p: &Preview| -> Vec<String> {
+        p.pages[0]
+            .lines
+            .iter()
+            .map(|l| l.render(80).trim().to_string())
+            .collect()
+    };
+    assert!(app.preview_enabled);
+    assert_eq!(text(&before)[0], "First bullet");
+    assert_eq!(text(&after)[0], "Second bullet");
+}
+
+#[test]
+fn test_rapid_toggles_queue_one_compile() {
+    let root = TempDir::with_files(
+        "preview-coalesce",
+        &[(
+            "templates/default_resume_template.typ",
+            "#for job in sys.inputs.experience [#for b in job.bullets [#b \\ ]]\n",
+        )],
+    );
+    let mut app = App {
+        data: sample_data(),
+        data_dir: root.data_dir(),
+        current_screen: CurrentScreen::ExperienceBulletSelection,
+        preview_enabled: true,
+        ..Default::default()
+    };
+    app.experience_list_state.select(Some(0));
+    app.experience_bullet_list_state.select(Some(0));
+
+    // The first toggle starts a compile, the rest only mark one as queued
+    app.handle_key_event(KeyCode::Char(' '));
+    assert!(app.pending_compile.is_some());
+    assert!(!app.preview_queued);
+    for _ in 0..4 {
+        app.handle_key_event(KeyCode::Char(' '));
+    }
+    assert!(app.pending_compile.is_some());
+    assert!(app.preview_queued);
+
+    // The queued compile runs once and sees the last state: bullet hidden
+    app.wait_for_preview();
+    assert!(app.pending_compile.is_none());
+    assert!(!app.preview_queued);
+    let preview = app.preview.clone().unwrap().unwrap();
+    assert_eq!(preview.pages[0].lines[0].render(80).trim(), "Second bullet");
+}
+
+#[test]
+fn test_no_compile_without_preview_or_budget() {
+    let root = TempDir::with_files(
+        "preview-idle",
+        &[("templates/default_resume_template.typ", "Hello\n")],
+    );
+    let mut app = App {
+        data: sample_data(),
+        data_dir: root.data_dir(),
+        current_screen: CurrentScreen::ExperienceSelection,
+        ..Default::default()
+    };
+    app.handle_key_event(KeyCode::Char(' '));
+    assert!(app.pending_compile.is_none());
+    assert_eq!(app.page_status(), None);
+}
+
+#[test]
+fn test_suggested_cuts_fit_the_budget() {
+    // Four lines per page; a job with three bullets is five lines
+    let root = TempDir::with_files(
+        "preview-budget",
+        &[(
+            "templates/default_resume_template.typ",
+            "#set page(width: 200pt, height: 80pt, margin: 10pt)\n\
+             #set text(size: 10pt)\n\
+             #for job in sys.inputs.experience [#job.role \\ #for b in job.bullets [#b \\ ]]\n",
+        )],
+    );
+    let dir = root.data_dir();
+    let mut app = App {
+        data: sample_data(),
+        data_dir: dir.clone(),
+        current_screen: CurrentScreen::ExperienceSelection,
+        ..Default::default()
+    };
+    app.data.config.max_pages = Some(1);
+
+    app.handle_key_event(KeyCode::Char('j'));
+    app.wait_for_preview();
+    assert_eq!(app.page_status(), Some((2, true)));
+
+    // Worked out in the background; Enter waits for them
+    app.handle_key_event(KeyCode::Char('c'));
+    assert_eq!(app.current_screen, CurrentScreen::PageBudget);
+    assert!(app.cuts.is_none());
+    app.handle_key_event(KeyCode::Enter);
+    assert_eq!(app.current_screen, CurrentScreen::PageBudget);
+    app.wait_for_preview();
+    let cuts = app.cuts.clone().unwrap().unwrap();
+    app.handle_key_event(KeyCode::Enter);
+    app.wait_for_preview();
+
+    // Cut from the bottom up: the intern's only bullet goes first
+    let texts: Vec<&str> = cuts.iter().map(|c| c.text.as_str()).collect();
+    assert_eq!(texts[0], "Only bullet");
+    assert_eq!(app.current_screen, CurrentScreen::ExperienceSelection);
+    assert_eq!(app.page_status(), Some((1, false)));
+}
+
+#[test]
+fn test_exports_keep_their_warnings_once() {
+    let root = TempDir::with_files(
+        "preview-export-warnings",
+        &[(
+            "templates/default_resume_template.typ",
+            "#text(font: \"No Such Font\")[Jane]\n",
+        )],
+    );
+    let mut app = App {
+        data: sample_data(),
+        data_dir: root.data_dir(),
+        current_screen: CurrentScreen::Success("resume.pdf".to_string()),
+        ..Default::default()
+    };
+
+    app.export_as(OutputFormat::Svg);
+    assert_eq!(app.exports.len(), 1);
+    assert_eq!(
+        app.output_diagnostics.len(),
+        1,
+        "{:?}",
+        app.output_diagnostics
+    );
+    assert!(app.output_diagnostics[0].message.contains("font"));
+
+    // A second format compiles again; its warnings are the same ones
+    app.export_as(OutputFormat::Png);
+    assert_eq!(app.exports.len(), 2);
+    assert_eq!(app.output_diagnostics.len(), 1);
+}
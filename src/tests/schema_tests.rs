@@ -0,0 +1,71 @@
+use super::TempDir;
+use crate::cli::schema::write_modeline;
+use crate::models::schema;
+use std::path::Path;
+
+#[test]
+fn test_committed_schemas_match_types() {
+    // Regenerate with `cargo run -- --data-dir data schema` after changing models/types.rs
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("data/schemas");
+    for entry in schema::all() {
+        let path = dir.join(format!("{}.schema.json", entry.name));
+        let committed = std::fs::read_to_string(&path).unwrap_or_default();
+        assert_eq!(
+            committed,
+            schema::to_json(&entry.schema),
+            "{} is out of date; run `cargo run -- --data-dir data schema`",
+            path.display()
+        );
+    }
+}
+
+#[test]
+fn test_schema_names_follow_data_files() {
+    assert_eq!(
+        schema::name_for(Path::new("experience.yaml")),
+        Some("experience")
+    );
+    assert_eq!(
+        schema::name_for(Path::new("sections/talks.yaml")),
+        Some("section")
+    );
+    assert_eq!(
+        schema::name_for(Path::new("presets/backend.yaml")),
+        Some("preset")
+    );
+    assert_eq!(
+        schema::name_for(Path::new("education/uni.yaml")),
+        Some("education")
+    );
+    assert_eq!(schema::name_for(Path::new("notes.yaml")), None);
+}
+
+#[test]
+fn test_modeline_is_added_once_and_updated() {
+    let root = TempDir::new("modeline");
+    let path = root.join("jobtitles.yaml");
+    std::fs::write(&path, "- title: Engineer\n").unwrap();
+
+    assert!(write_modeline(&path, "schemas/jobtitles.schema.json").unwrap());
+    assert!(!write_modeline(&path, "schemas/jobtitles.schema.json").unwrap());
+    assert!(write_modeline(&path, "../schemas/jobtitles.schema.json").unwrap());
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(
+        content,
+        "# yaml-language-server: $schema=../schemas/jobtitles.schema.json\n- title: Engineer\n"
+    );
+}
+
+#[test]
+fn test_list_schemas_accept_includes() {
+    // Every list file goes through the same loader, so each takes `include:`
+    let single = ["profile", "config", "section", "preset"];
+    for entry in schema::all() {
+        if !single.contains(&entry.name) {
+            let json = schema::to_json(&entry.schema);
+            assert!(json.contains("\"include\""), "{}", entry.name);
+            assert!(json.contains("A single entry"), "{}", entry.name);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use super::TempDir;
+use crate::app::{App, CurrentScreen};
+use crate::templates::{self, Template};
+use crossterm::event::KeyCode;
+use std::fs;
+
+// The default template, a described one and a partial
+const TEMPLATES: &[(&str, &str)] = &[
+    (
+        "templates/default_resume_template.typ",
+        "// 1. DATA & CONFIG\n",
+    ),
+    (
+        "templates/academic_cv.typ",
+        "// @name: Academic CV\n// @description: Publications first\n\n#let x = 1\n// @name: ignored\n",
+    ),
+    ("templates/_shared.typ", "#let y = 2\n"),
+];
+
+#[test]
+fn test_discover_reads_metadata_and_skips_partials() {
+    let root = TempDir::with_files("templates-discover", TEMPLATES);
+    let dir = root.data_dir();
+    let found = templates::discover(&dir);
+    let resolved = templates::resolve(&dir, Some("academic cv"));
+    let unknown = templates::resolve(&dir, Some("two_column"));
+
+    assert_eq!(
+        found,
+        vec![
+            Template {
+                file: templates::DEFAULT_TEMPLATE.to_string(),
+                name: "default resume template".to_string(),
+                description: String::new(),
+            },
+            Template {
+                file: "academic_cv.typ".to_string(),
+                name: "Academic CV".to_string(),
+                description: "Publications first".to_string(),
+            },
+        ]
+    );
+    assert!(resolved.unwrap().ends_with("academic_cv.typ"));
+    let message = unknown.unwrap_err().to_string();
+    assert!(message.contains("academic_cv.typ"), "{}", message);
+}
+
+#[test]
+fn test_template_picker_remembers_choice() {
+    let root = TempDir::with_files("templates-picker", TEMPLATES);
+    let dir = root.data_dir();
+    let mut app = App::new(dir.clone());
+    app.data.template = Some("academic_cv.typ".to_string());
+    app.current_screen = CurrentScreen::ProjectsSelection;
+
+    // Past the last selection step, the picker opens on the previous pick
+    app.advance();
+    assert_eq!(app.current_screen, CurrentScreen::TemplateSelection);
+    assert_eq!(app.template_list_state.selected(), Some(1));
+
+    app.handle_key_event(KeyCode::Char('j'));
+    assert_eq!(app.template_list_state.selected(), Some(0));
+
+    app.handle_key_event(KeyCode::Backspace);
+    assert_eq!(app.current_screen, CurrentScreen::ProjectsSelection);
+}
+
+#[test]
+fn test_only_template_is_used_without_a_default() {
+    let root = TempDir::with_files("templates-only", TEMPLATES);
+    let dir = root.data_dir();
+    fs::remove_file(dir.templates_dir().join(templates::DEFAULT_TEMPLATE)).unwrap();
+
+    assert!(
+        templates::resolve(&dir, None)
+            .unwrap()
+            .ends_with("academic_cv.typ")
+    );
+
+    fs::remove_file(dir.templates_dir().join("academic_cv.typ")).unwrap();
+    assert!(templates::resolve(&dir, None).is_err());
+}
@@ -0,0 +1,149 @@
+use super::sample_data;
+use crate::layout::{skill_details, skill_line};
+use crate::text_export::{TextStyle, render, wrap};
+
+#[test]
+fn test_plain_text_follows_the_pdf_selection() {
+    let mut data = sample_data();
+    data.select_job_title(0);
+    data.experience[1].is_visible = false;
+    data.experience[0].toggle_bullet(1);
+    data.skills[0].toggle_skill(1);
+
+    let text = render(&data.to_filtered_data(), TextStyle::Plain, 80);
+    assert_eq!(
+        text,
+        "JANE DOE
+123 | jane@example.com
+
+Rust Developer
+
+Rust summary
+
+EDUCATION
+---------
+
+Uni | B.Sc
+Graduated 2025
+
+Uni | M.Sc
+Expected 2026
+
+WORK EXPERIENCE
+---------------
+
+Developer | Acme, Remote
+2023 - Current
+s
+- First bullet
+- Third bullet
+
+PROJECTS
+--------
+
+Calculator App
+d
+
+Resume TUI
+d
+
+SKILLS
+------
+
+Languages: Rust (Advanced, 3 yrs)
+Tools: Docker
+"
+    );
+}
+
+#[test]
+fn test_markdown_separates_blocks() {
+    let mut data = sample_data();
+    data.education.clear();
+    data.projects.clear();
+    data.skills.clear();
+    data.experience.truncate(1);
+
+    let text = render(&data.to_filtered_data(), TextStyle::Markdown, 80);
+    assert_eq!(
+        text,
+        "Jane Doe
+========
+
+123 | jane@example.com
+
+Work Experience
+---------------
+
+**Developer** | Acme, Remote
+
+*2023 - Current*
+
+s
+
+- First bullet
+- Second bullet
+- Third bullet
+"
+    );
+}
+
+#[test]
+fn test_headings_wrap_and_markdown_is_escaped() {
+    let mut data = sample_data();
+    data.education.clear();
+    data.projects.clear();
+    data.skills.clear();
+    data.experience.truncate(1);
+    data.experience[0].role = "Senior_Staff *Platform* Engineer".to_string();
+    data.experience[0].company = "[Acme] #1 Widgets".to_string();
+    data.experience[0].summary = "Cut build times from 40 - 12 minutes".to_string();
+
+    let plain = render(&data.to_filtered_data(), TextStyle::Plain, 24);
+    let markdown = render(&data.to_filtered_data(), TextStyle::Markdown, 24);
+
+    assert!(plain.lines().all(|l| l.chars().count() <= 24), "{}", plain);
+    assert!(
+        plain.contains(
+            "WORK EXPERIENCE\n---------------\n\nSenior_Staff *Platform*\nEngineer | [Acme] #1\n"
+        ),
+        "{}",
+        plain
+    );
+    assert!(
+        markdown.lines().all(|l| l.chars().count() <= 24),
+        "{}",
+        markdown
+    );
+    assert!(
+        markdown
+            .contains("**Senior\\_Staff\n\\*Platform\\* Engineer**\n| \\[Acme\\] \\#1 Widgets,\n"),
+        "{}",
+        markdown
+    );
+    // A wrapped line starting with "- " would become a list item
+    assert!(markdown.contains("\n\\- 12 minutes\n"), "{}", markdown);
+}
+
+#[test]
+fn test_wrap_hangs_continuation_lines() {
+    assert_eq!(
+        wrap("one two three four", 10, "- ", "  "),
+        ["- one two", "  three", "  four"]
+    );
+    // Words longer than the width are never split
+    assert_eq!(wrap("abcdefghijkl x", 5, "", ""), ["abcdefghijkl", "x"]);
+    assert!(wrap("   ", 10, "- ", "  ").is_empty());
+}
+
+#[test]
+fn test_skill_years_are_pluralised() {
+    let mut data = sample_data();
+    data.skills[0].skills[0].years = Some(1);
+    assert_eq!(
+        skill_line(&data.skills[0].skills[0]),
+        "Rust (Advanced, 1 yr)"
+    );
+    data.skills[0].skills[0].proficiency = None;
+    assert_eq!(skill_details(&data.skills[0].skills[0]), "1 yr");
+}
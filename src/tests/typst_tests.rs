@@ -0,0 +1,224 @@
+use super::TempDir;
+use crate::models::ResumeData;
+use crate::pdf::{CompileError, OutputFormat, export};
+use crate::templates::DEFAULT_TEMPLATE;
+use crate::typst_backend::{FontOrigin, Fonts, ResumeWorld};
+use std::fs;
+use typst::World;
+use typst::diag::FileError;
+use typst::foundations::Dict;
+use typst::syntax::{FileId, VirtualPath};
+
+#[test]
+fn test_world_resolves_imports_and_files() {
+    let root = TempDir::with_files(
+        "world-imports",
+        &[
+            (
+                "templates/parts/_heading.typ",
+                "#let heading(body) = text(weight: \"bold\", body)\n",
+            ),
+            ("templates/motto.txt", "Ship it"),
+            (
+                "templates/main.typ",
+                "#import \"parts/_heading.typ\": heading\n#heading(read(\"motto.txt\"))\n",
+            ),
+        ],
+    );
+    let templates = root.join("templates");
+
+    let world = ResumeWorld::new(
+        &templates,
+        &templates.join("main.typ"),
+        Dict::new(),
+        Fonts::load(&root.join("fonts"), false),
+    );
+    let result = typst::compile(&world).output;
+
+    assert!(result.is_ok(), "{:?}", result.err());
+}
+
+#[test]
+fn test_world_cannot_escape_root() {
+    let root = TempDir::with_files(
+        "world-sandbox",
+        &[("secret.txt", "nope"), ("templates/main.typ", "")],
+    );
+    let templates = root.join("templates");
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(root.join("secret.txt"), templates.join("link.txt")).unwrap();
+
+    let world = ResumeWorld::new(
+        &templates,
+        &templates.join("main.typ"),
+        Dict::new(),
+        Fonts::load(&root.join("fonts"), false),
+    );
+    let file = |path: &str| world.file(FileId::new(None, VirtualPath::new(path)));
+    let parent = file("../secret.txt");
+    #[cfg(unix)]
+    let link = file("link.txt");
+    let missing = file("missing.png");
+
+    assert_eq!(parent, Err(FileError::AccessDenied));
+    #[cfg(unix)]
+    assert_eq!(link, Err(FileError::AccessDenied));
+    assert!(matches!(missing, Err(FileError::NotFound(_))));
+}
+
+#[test]
+fn test_fonts_scans_user_dir_recursively() {
+    let root = TempDir::new("world-fonts");
+    fs::create_dir_all(root.join("fonts/liberation")).unwrap();
+    fs::copy(
+        "assets/fonts/LiberationSans-Bold.ttf",
+        root.join("fonts/liberation/Extra.TTF"),
+    )
+    .unwrap();
+    fs::write(root.join("fonts/readme.txt"), "not a font").unwrap();
+    // A link back up the tree is not followed round and round
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(root.join("fonts"), root.join("fonts/liberation/loop")).unwrap();
+
+    let families = Fonts::load(&root.join("fonts"), false).families();
+
+    // The embedded faces plus the copied one, still reported as built-in
+    assert_eq!(
+        families,
+        vec![("Liberation Sans".to_string(), 5, FontOrigin::Embedded)]
+    );
+}
+
+#[test]
+fn test_fonts_added_later_are_picked_up() {
+    let root = TempDir::new("world-fonts-added");
+    let fonts = root.join("fonts");
+    fs::create_dir_all(&fonts).unwrap();
+    let faces = |fonts: &std::path::Path| Fonts::load(fonts, false).families()[0].1;
+    assert_eq!(faces(&fonts), 4);
+
+    fs::copy(
+        "assets/fonts/LiberationSans-Bold.ttf",
+        fonts.join("Extra.ttf"),
+    )
+    .unwrap();
+    assert_eq!(faces(&fonts), 5);
+
+    fs::remove_file(fonts.join("Extra.ttf")).unwrap();
+    assert_eq!(faces(&fonts), 4);
+}
+
+#[test]
+fn test_unknown_font_family_is_reported() {
+    let root = TempDir::with_files(
+        "world-unknown-font",
+        &[(
+            "templates/default_resume_template.typ",
+            "#set text(font: \"Nope Sans\")\nHello\n",
+        )],
+    );
+    let dir = root.data_dir();
+
+    let output = export(&ResumeData::default(), &dir, &[OutputFormat::Pdf]);
+
+    let warnings = output.unwrap().warnings;
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert_eq!(warnings[0].message, "unknown font family: nope sans");
+    assert_eq!(warnings[0].location, Some((1, 17)));
+    assert!(warnings[0].notes[0].contains("run `fonts`"));
+}
+
+#[test]
+fn test_compile_errors_point_into_the_template() {
+    let root = TempDir::with_files(
+        "world-errors",
+        &[
+            ("templates/_parts.typ", "#let entry(x) = {\n  x + 1\n}\n"),
+            (
+                "templates/default_resume_template.typ",
+                "#import \"_parts.typ\": entry\n\n#entry(\"text\")\n",
+            ),
+        ],
+    );
+    let dir = root.data_dir();
+
+    let result = export(&ResumeData::default(), &dir, &[OutputFormat::Pdf]);
+
+    let err = result.unwrap_err();
+    let diagnostics = &err.downcast_ref::<CompileError>().unwrap().diagnostics;
+    assert!(!dir.output_dir().exists());
+    assert_eq!(diagnostics.len(), 1);
+    let error = &diagnostics[0];
+    assert!(error.file.ends_with("templates/_parts.typ"));
+    assert_eq!(error.location, Some((2, 3)));
+    assert_eq!(error.snippet.as_deref(), Some("  x + 1"));
+    assert!(
+        error
+            .notes
+            .iter()
+            .any(|n| n.contains("call of function `entry`")
+                && n.contains(&format!("{}:3:2", DEFAULT_TEMPLATE))),
+        "{:?}",
+        error.notes
+    );
+}
+
+#[test]
+fn test_png_and_svg_are_written_per_page() {
+    let root = TempDir::with_files(
+        "world-formats",
+        &[(
+            "templates/default_resume_template.typ",
+            "#set page(width: 100pt, height: 50pt)\nA\n#pagebreak()\nB\n",
+        )],
+    );
+    let dir = root.data_dir();
+    let mut data = ResumeData::default();
+    data.config.png_dpi = Some(144.0);
+
+    let formats = [OutputFormat::Png, OutputFormat::Svg, OutputFormat::Pdf];
+    let output = export(&data, &dir, &formats).unwrap();
+    let names: Vec<String> = output
+        .paths
+        .iter()
+        .map(|p| p.rsplit('/').next().unwrap().to_string())
+        .collect();
+    let png = fs::read(&output.paths[0]).unwrap();
+    let svg = fs::read_to_string(&output.paths[2]).unwrap();
+
+    assert_eq!(
+        names,
+        [
+            "resume-1.png",
+            "resume-2.png",
+            "resume-1.svg",
+            "resume-2.svg",
+            "resume.pdf"
+        ]
+    );
+    // 100pt x 50pt at 144 dpi; width and height sit in the IHDR chunk
+    assert_eq!(&png[1..4], b"PNG");
+    assert_eq!(u32::from_be_bytes(png[16..20].try_into().unwrap()), 200);
+    assert_eq!(u32::from_be_bytes(png[20..24].try_into().unwrap()), 100);
+    assert!(svg.starts_with("<svg"));
+
+    // Down to one page: the numbered files of the last export go away
+    fs::write(
+        dir.templates_dir().join(DEFAULT_TEMPLATE),
+        "#set page(width: 100pt, height: 50pt)\nA\n",
+    )
+    .unwrap();
+    fs::write(dir.output_dir().join("resume-notes.png"), "kept").unwrap();
+    export(&data, &dir, &[OutputFormat::Png]).unwrap();
+    let mut left: Vec<String> = fs::read_dir(dir.output_dir())
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .filter(|name| name.ends_with(".png"))
+        .collect();
+    left.sort();
+    assert_eq!(left, ["resume-notes.png", "resume.png"]);
+
+    data.config.png_dpi = Some(5000.0);
+    let error = export(&data, &dir, &[OutputFormat::Png]).unwrap_err();
+    assert!(error.to_string().contains("out of range"), "{}", error);
+}
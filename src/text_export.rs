@@ -0,0 +1,360 @@
+use crate::layout;
+use crate::models::types::{Bullet, FilteredResumeData};
+
+pub const DEFAULT_TEXT_WIDTH: usize = 80;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextStyle {
+    Plain,
+    Markdown,
+}
+
+// Renders the same sections, in the same order, as the default template.
+// Everything is wrapped at `width` and laid out the same way every time,
+// so two exports of similar selections diff cleanly.
+pub fn render(data: &FilteredResumeData, style: TextStyle, width: usize) -> String {
+    let mut w = Writer {
+        style,
+        width: width.max(20),
+        out: String::new(),
+    };
+
+    // Header
+    let profile = &data.profile;
+    w.title(&profile.name);
+    w.header_line(
+        &present(&[
+            &profile.phone,
+            &profile.email,
+            &profile.url,
+            &profile.website,
+        ])
+        .join(" | "),
+    );
+    w.header_line(&present(&[&profile.citizenship, &profile.location]).join(", "));
+    if is_present(&data.job_title) {
+        w.block();
+        let job_title = w.strong(&data.job_title);
+        w.wrapped(&job_title, "", "");
+    }
+    if is_present(&data.professional_summary) {
+        w.block();
+        w.paragraph(&data.professional_summary);
+    }
+
+    if !data.education.is_empty() {
+        w.section("Education");
+        for edu in &data.education {
+            w.entry(
+                &edu.school,
+                &[&edu.degree],
+                &present(&[&edu.status, &edu.date]).join(", "),
+            );
+        }
+    }
+
+    if !data.experience.is_empty() {
+        w.section("Work Experience");
+        for job in &data.experience {
+            w.entry(&job.role, &[&job.company, &job.location], &job.date);
+            w.paragraph(&job.summary);
+            w.bullets(&job.bullets);
+        }
+    }
+
+    if !data.projects.is_empty() {
+        w.section("Projects");
+        for project in &data.projects {
+            let url = project.url.as_deref().unwrap_or_default();
+            w.entry(&project.title, &[url], "");
+            w.paragraph(&project.description);
+            if !project.tech_stack.is_empty() {
+                w.paragraph(&format!("Tech: {}", project.tech_stack.join(", ")));
+            }
+        }
+    }
+
+    if !data.skills.is_empty() {
+        w.section("Skills");
+        for category in &data.skills {
+            let skills: Vec<String> = category.skills.iter().map(layout::skill_line).collect();
+            let line = format!(
+                "{}: {}",
+                w.strong(&category.category),
+                w.text(&skills.join(", "))
+            );
+            w.formatted(&line);
+        }
+    }
+
+    if !data.certifications.is_empty() {
+        w.section("Certifications");
+        for cert in &data.certifications {
+            let url = cert.credential_url.as_deref().unwrap_or_default();
+            w.entry(&cert.name, &[&cert.issuer, url], &cert.date);
+        }
+    }
+
+    if !data.awards.is_empty() {
+        w.section("Awards");
+        for award in &data.awards {
+            w.entry(&award.title, &[&award.issuer], &award.date);
+            w.paragraph(&award.description);
+        }
+    }
+
+    if !data.publications.is_empty() {
+        w.section("Publications");
+        for publication in &data.publications {
+            w.entry(&publication.title, &[&publication.venue], &publication.date);
+            w.paragraph(&publication.authors.join(", "));
+            if let Some(ref doi) = publication.doi {
+                w.paragraph(&format!("DOI: {}", doi));
+            }
+        }
+    }
+
+    if !data.languages.is_empty() {
+        w.section("Languages");
+        let languages: Vec<String> = data
+            .languages
+            .iter()
+            .map(|l| format!("{} ({})", l.language, l.proficiency))
+            .collect();
+        w.paragraph(&languages.join(", "));
+    }
+
+    if !data.volunteering.is_empty() {
+        w.section("Volunteering");
+        for vol in &data.volunteering {
+            w.entry(&vol.role, &[&vol.organization, &vol.location], &vol.date);
+            w.paragraph(&vol.summary);
+            w.bullets(&vol.bullets);
+        }
+    }
+
+    for section in &data.custom_sections {
+        w.section(&section.title);
+        for entry in &section.entries {
+            let mut details = layout::details(entry);
+            details.extend(entry.field("url"));
+            w.entry(
+                entry.heading(),
+                &details,
+                entry.field("date").unwrap_or_default(),
+            );
+            w.paragraph(entry.field("description").unwrap_or_default());
+            w.bullets(&entry.bullets);
+        }
+    }
+
+    w.out
+}
+
+// "N/A" is the placeholder the loader uses for a missing profile
+pub fn is_present(value: &str) -> bool {
+    let value = value.trim();
+    !value.is_empty() && value != "N/A"
+}
+
+pub fn present<'a>(values: &[&'a str]) -> Vec<&'a str> {
+    values
+        .iter()
+        .map(|v| v.trim())
+        .filter(|v| is_present(v))
+        .collect()
+}
+
+struct Writer {
+    style: TextStyle,
+    width: usize,
+    out: String,
+}
+
+impl Writer {
+    // Blank line between blocks, never at the top
+    fn block(&mut self) {
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    fn line(&mut self, text: &str) {
+        if !text.is_empty() {
+            self.out.push_str(text);
+            self.out.push('\n');
+        }
+    }
+
+    // Every line goes through here. Markdown keeps a column free for the
+    // backslash that stops a line starting with "- " or "1." being a list.
+    fn wrapped(&mut self, text: &str, first: &str, rest: &str) {
+        let width = match self.style {
+            TextStyle::Plain => self.width,
+            TextStyle::Markdown => self.width - 1,
+        };
+        for (i, line) in wrap(text, width, first, rest).into_iter().enumerate() {
+            let line = match self.style {
+                TextStyle::Plain => line,
+                TextStyle::Markdown => {
+                    let prefix = if i == 0 { first } else { rest };
+                    format!("{}{}", prefix, escape_block_start(&line[prefix.len()..]))
+                }
+            };
+            self.line(&line);
+        }
+    }
+
+    // User text with Markdown's inline markup escaped
+    fn text(&self, text: &str) -> String {
+        match self.style {
+            TextStyle::Plain => text.to_string(),
+            TextStyle::Markdown => {
+                let mut out = String::new();
+                for c in text.chars() {
+                    if "\\`*_#[]<>".contains(c) {
+                        out.push('\\');
+                    }
+                    out.push(c);
+                }
+                out
+            }
+        }
+    }
+
+    fn strong(&self, text: &str) -> String {
+        match self.style {
+            TextStyle::Plain => text.to_string(),
+            TextStyle::Markdown => format!("**{}**", self.text(text)),
+        }
+    }
+
+    // Wrapped and underlined, so a long title doesn't run past the width:
+    // in Markdown the underline makes a (setext) heading of all its lines
+    fn heading(&mut self, text: &str, rule: char) {
+        let text = match self.style {
+            TextStyle::Plain => text.to_uppercase(),
+            TextStyle::Markdown => self.text(text),
+        };
+        let before = self.out.len();
+        self.wrapped(&text, "", "");
+        let longest = self.out[before..]
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        if longest > 0 {
+            self.line(&rule.to_string().repeat(longest));
+        }
+    }
+
+    fn title(&mut self, name: &str) {
+        match self.style {
+            TextStyle::Plain => self.wrapped(&name.to_uppercase(), "", ""),
+            TextStyle::Markdown => self.heading(name, '='),
+        }
+    }
+
+    // Markdown would run consecutive lines together
+    fn header_line(&mut self, text: &str) {
+        if self.style == TextStyle::Markdown && !text.is_empty() {
+            self.block();
+        }
+        let text = self.text(text);
+        self.wrapped(&text, "", "");
+    }
+
+    fn section(&mut self, title: &str) {
+        self.block();
+        self.heading(title, '-');
+        self.block();
+    }
+
+    // "Heading | detail, detail" with the date on its own line
+    fn entry(&mut self, heading: &str, details: &[&str], date: &str) {
+        self.block();
+        let heading = self.strong(heading);
+        let details = self.text(&present(details).join(", "));
+        if details.is_empty() {
+            self.wrapped(&heading, "", "");
+        } else {
+            self.wrapped(&format!("{} | {}", heading, details), "", "");
+        }
+        if is_present(date) {
+            let date = match self.style {
+                TextStyle::Plain => date.to_string(),
+                TextStyle::Markdown => format!("*{}*", self.text(date)),
+            };
+            // Markdown needs the blank line to keep the date off the heading
+            if self.style == TextStyle::Markdown {
+                self.block();
+            }
+            self.wrapped(&date, "", "");
+        }
+    }
+
+    fn paragraph(&mut self, text: &str) {
+        if is_present(text) {
+            let text = self.text(text);
+            self.formatted(&text);
+        }
+    }
+
+    // A paragraph whose markup is already in place
+    fn formatted(&mut self, text: &str) {
+        if self.style == TextStyle::Markdown {
+            self.block();
+        }
+        self.wrapped(text, "", "");
+    }
+
+    fn bullets(&mut self, bullets: &[Bullet]) {
+        if bullets.is_empty() {
+            return;
+        }
+        if self.style == TextStyle::Markdown {
+            self.block();
+        }
+        for bullet in bullets {
+            let text = self.text(bullet.text());
+            self.wrapped(&text, "- ", "  ");
+        }
+    }
+}
+
+// A backslash before what would start a list item, quote or heading
+// underline at the beginning of a Markdown line
+fn escape_block_start(line: &str) -> String {
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if line.starts_with(['-', '+', '=', '>']) {
+        format!("\\{}", line)
+    } else if digits > 0 && line[digits..].starts_with(['.', ')']) {
+        format!("{}\\{}", &line[..digits], &line[digits..])
+    } else {
+        line.to_string()
+    }
+}
+
+/// Greedy word wrap. The first line starts with `first`, the rest with
+/// `rest`; a word longer than the width gets a line of its own.
+pub fn wrap(text: &str, width: usize, first: &str, rest: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = first.to_string();
+    let mut empty = true;
+    for word in text.split_whitespace() {
+        let len = current.chars().count();
+        if !empty && len + 1 + word.chars().count() > width {
+            lines.push(std::mem::replace(&mut current, rest.to_string()));
+            empty = true;
+        }
+        if !empty {
+            current.push(' ');
+        }
+        current.push_str(word);
+        empty = false;
+    }
+    if !empty {
+        lines.push(current);
+    }
+    lines
+}
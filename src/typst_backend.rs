@@ -1,63 +1,355 @@
-use std::sync::OnceLock;
-use typst::diag::{FileError, FileResult};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
+use typst::diag::{FileError, FileResult, PackageError, Severity, SourceDiagnostic};
 use typst::foundations::{Bytes, Datetime, Dict};
-use typst::syntax::{FileId, Source};
-use typst::text::{Font, FontBook};
+use typst::syntax::{FileId, Source, Span, VirtualPath};
+use typst::text::{Font, FontBook, FontInfo};
 use typst::utils::LazyHash;
 use typst::{Library, World};
 
-// 1. EMBED FONTS PERMANENTLY
-static FONTS: OnceLock<(FontBook, Vec<Font>)> = OnceLock::new();
-
-fn get_fonts() -> &'static (FontBook, Vec<Font>) {
-    FONTS.get_or_init(|| {
-        // Adjust these filenames to match exactly what you put in assets/fonts/
-        let font_payloads = vec![
-            include_bytes!("../assets/fonts/LiberationSans-Regular.ttf").as_slice(),
-            include_bytes!("../assets/fonts/LiberationSans-Bold.ttf").as_slice(),
-            include_bytes!("../assets/fonts/LiberationSans-Italic.ttf").as_slice(),
-            include_bytes!("../assets/fonts/LiberationSans-BoldItalic.ttf").as_slice(),
-        ];
-
-        let mut book = FontBook::new();
-        let mut fonts = Vec::new();
-
-        for data in font_payloads {
-            let buffer = Bytes::from_static(data);
-            for font in Font::iter(buffer) {
-                book.push(font.info().clone());
-                fonts.push(font);
+use crate::models::diagnostics::Diagnostic;
+
+// 1. FONTS
+// The Liberation Sans faces are embedded so the default template always works.
+// More come from data/fonts/ and, when enabled, the system font directories.
+const EMBEDDED_FONTS: [&[u8]; 4] = [
+    include_bytes!("../assets/fonts/LiberationSans-Regular.ttf"),
+    include_bytes!("../assets/fonts/LiberationSans-Bold.ttf"),
+    include_bytes!("../assets/fonts/LiberationSans-Italic.ttf"),
+    include_bytes!("../assets/fonts/LiberationSans-BoldItalic.ttf"),
+];
+
+const FONT_EXTENSIONS: [&str; 4] = ["ttf", "otf", "ttc", "otc"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FontOrigin {
+    Embedded,
+    User,
+    System,
+}
+
+// One face in the book. File-backed faces are only read once Typst asks for them.
+struct FontSlot {
+    family: String,
+    origin: FontOrigin,
+    path: Option<PathBuf>,
+    index: u32,
+    font: OnceLock<Option<Font>>,
+}
+
+pub struct Fonts {
+    book: FontBook,
+    slots: Vec<FontSlot>,
+}
+
+// A face found by a scan, before Typst asks for it
+#[derive(Clone)]
+struct Face {
+    info: FontInfo,
+    path: PathBuf,
+    index: u32,
+}
+
+// The fonts built for each font folder and `system` flag, with the
+// path, modification time and size of the folder's font files when built
+type FontStamp = Vec<(PathBuf, Option<(SystemTime, u64)>)>;
+type FontCache = Mutex<HashMap<(PathBuf, bool), (FontStamp, Arc<Fonts>)>>;
+static FONT_CACHE: OnceLock<FontCache> = OnceLock::new();
+
+impl Fonts {
+    /// Embedded fonts plus every font file under `dir`, and the system fonts
+    /// when `system` is set. `dir` is looked at on every call, so fonts added
+    /// to it while the TUI runs are picked up; the system scan is done once.
+    pub fn load(dir: &Path, system: bool) -> Arc<Fonts> {
+        let mut files = Vec::new();
+        font_files(dir, &mut HashSet::new(), &mut files);
+        let stamp: FontStamp = files
+            .iter()
+            .map(|path| {
+                let metadata = fs::metadata(path).ok();
+                let modified = metadata.as_ref().and_then(|m| m.modified().ok());
+                (path.clone(), modified.zip(metadata.map(|m| m.len())))
+            })
+            .collect();
+
+        let cache = FONT_CACHE.get_or_init(Default::default);
+        let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+        let key = (dir.to_path_buf(), system);
+        if let Some((scanned, fonts)) = cache.get(&key)
+            && *scanned == stamp
+        {
+            return fonts.clone();
+        }
+        let fonts = Arc::new(Self::scan(&files, system));
+        cache.insert(key, (stamp, fonts.clone()));
+        fonts
+    }
+
+    fn scan(user_files: &[PathBuf], system: bool) -> Self {
+        let mut fonts = Fonts {
+            book: FontBook::new(),
+            slots: Vec::new(),
+        };
+        for data in EMBEDDED_FONTS {
+            for font in Font::iter(Bytes::from_static(data)) {
+                fonts.book.push(font.info().clone());
+                fonts.slots.push(FontSlot {
+                    family: font.info().family.clone(),
+                    origin: FontOrigin::Embedded,
+                    path: None,
+                    index: font.index(),
+                    font: OnceLock::from(Some(font)),
+                });
             }
         }
-        (book, fonts)
+        fonts.add(faces(user_files), FontOrigin::User);
+        if system {
+            fonts.add(system_faces().to_vec(), FontOrigin::System);
+        }
+        fonts
+    }
+
+    fn add(&mut self, faces: Vec<Face>, origin: FontOrigin) {
+        for face in faces {
+            self.slots.push(FontSlot {
+                family: face.info.family.clone(),
+                origin,
+                path: Some(face.path),
+                index: face.index,
+                font: OnceLock::new(),
+            });
+            self.book.push(face.info);
+        }
+    }
+
+    fn font(&self, index: usize) -> Option<Font> {
+        let slot = self.slots.get(index)?;
+        slot.font
+            .get_or_init(|| {
+                let data = fs::read(slot.path.as_ref()?).ok()?;
+                Font::new(Bytes::from(data), slot.index)
+            })
+            .clone()
+    }
+
+    /// Family names with their number of faces and where they come from,
+    /// sorted by name
+    pub fn families(&self) -> Vec<(String, usize, FontOrigin)> {
+        let mut families: BTreeMap<String, (usize, FontOrigin)> = BTreeMap::new();
+        for slot in &self.slots {
+            let entry = families
+                .entry(slot.family.clone())
+                .or_insert((0, slot.origin));
+            entry.0 += 1;
+            entry.1 = entry.1.min(slot.origin);
+        }
+        families
+            .into_iter()
+            .map(|(family, (faces, origin))| (family, faces, origin))
+            .collect()
+    }
+}
+
+// Font files under `dir`, in name order with each folder's files where the
+// folder sorts. `seen` holds the canonical folders already walked, so a
+// symlink back up the tree (or to a folder walked before) is skipped.
+fn font_files(dir: &Path, seen: &mut HashSet<PathBuf>, files: &mut Vec<PathBuf>) {
+    let Ok(canonical) = dir.canonicalize() else {
+        return;
+    };
+    if !seen.insert(canonical) {
+        return;
+    }
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut paths: Vec<PathBuf> = entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {
+            font_files(&path, seen, files);
+        } else if path.extension().is_some_and(|ext| {
+            FONT_EXTENSIONS.contains(&ext.to_string_lossy().to_lowercase().as_str())
+        }) {
+            files.push(path);
+        }
+    }
+}
+
+// The faces in each file. The data is only read for their descriptions and
+// dropped; it is read again once Typst asks for the font.
+fn faces(files: &[PathBuf]) -> Vec<Face> {
+    let mut faces = Vec::new();
+    for path in files {
+        let Ok(data) = fs::read(path) else {
+            continue;
+        };
+        for (index, info) in FontInfo::iter(&data).enumerate() {
+            faces.push(Face {
+                info,
+                path: path.clone(),
+                index: index as u32,
+            });
+        }
+    }
+    faces
+}
+
+// Scanning the system directories is slow, so it is done once
+fn system_faces() -> &'static [Face] {
+    static SYSTEM_FACES: OnceLock<Vec<Face>> = OnceLock::new();
+    SYSTEM_FACES.get_or_init(|| {
+        let (mut seen, mut files) = (HashSet::new(), Vec::new());
+        for dir in system_font_dirs() {
+            font_files(&dir, &mut seen, &mut files);
+        }
+        faces(&files)
     })
 }
 
+// The usual per-platform font folders; missing ones are skipped
+fn system_font_dirs() -> Vec<PathBuf> {
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    let mut dirs = Vec::new();
+    if cfg!(target_os = "macos") {
+        dirs.push(PathBuf::from("/Library/Fonts"));
+        dirs.push(PathBuf::from("/System/Library/Fonts"));
+        dirs.extend(home.map(|h| h.join("Library/Fonts")));
+    } else if cfg!(windows) {
+        let windir = std::env::var_os("WINDIR").unwrap_or_else(|| "C:\\Windows".into());
+        dirs.push(PathBuf::from(windir).join("Fonts"));
+        if let Some(local) = std::env::var_os("LOCALAPPDATA") {
+            dirs.push(PathBuf::from(local).join("Microsoft\\Windows\\Fonts"));
+        }
+    } else {
+        dirs.push(PathBuf::from("/usr/share/fonts"));
+        dirs.push(PathBuf::from("/usr/local/share/fonts"));
+        if let Some(home) = home {
+            dirs.push(home.join(".local/share/fonts"));
+            dirs.push(home.join(".fonts"));
+        }
+    }
+    dirs
+}
+
 // 2. THE WORLD STRUCT - this is necessary as the typst library is pure so
 pub struct ResumeWorld {
     library: LazyHash<Library>,
     book: LazyHash<FontBook>, // Wrapped in LazyHash for 0.12 compatibility
-    fonts: &'static [Font],
-    source: Source,
+    fonts: Arc<Fonts>,
+    // Imports, images and other files are read relative to this directory
+    // and may not escape it
+    root: PathBuf,
+    // The same directory as given, for paths shown to the user
+    display_root: PathBuf,
+    main: FileId,
+    // Parsed sources, so every import is read from disk only once
+    sources: Mutex<HashMap<FileId, Source>>,
     current_time: time::OffsetDateTime,
 }
 
 impl ResumeWorld {
-    pub fn new(template_source: String, inputs: Dict) -> Self {
-        let (book, fonts) = get_fonts();
-
+    // `template` is the main file and must live inside `root`
+    pub fn new(root: &Path, template: &Path, inputs: Dict, fonts: Arc<Fonts>) -> Self {
         // Using builder pattern to inject inputs (typst 0.12+)
         let library = Library::builder().with_inputs(inputs).build();
 
+        // Canonical paths so symlinks can be checked against the root
+        let display_root = root.to_path_buf();
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        let template = template
+            .canonicalize()
+            .unwrap_or_else(|_| template.to_path_buf());
+        let main = VirtualPath::within_root(&template, &root)
+            .unwrap_or_else(|| VirtualPath::new(template.file_name().unwrap_or_default()));
+
         Self {
             library: LazyHash::new(library),
-            // Clone the book from static and wrap in LazyHash
-            book: LazyHash::new(book.clone()),
+            // Clone the shared book and wrap in LazyHash
+            book: LazyHash::new(fonts.book.clone()),
             fonts,
-            source: Source::detached(template_source),
+            root,
+            display_root,
+            main: FileId::new(None, main),
+            sources: Mutex::new(HashMap::new()),
             current_time: time::OffsetDateTime::now_utc(),
         }
     }
+
+    // Map a file id onto the disk, refusing anything outside the root
+    fn path(&self, id: FileId) -> FileResult<PathBuf> {
+        if let Some(spec) = id.package() {
+            // No package manager here; templates must vendor what they use
+            return Err(FileError::Package(PackageError::NotFound(spec.clone())));
+        }
+        let path = id
+            .vpath()
+            .resolve(&self.root)
+            .ok_or(FileError::AccessDenied)?;
+        // `resolve` only catches `..`; a symlink could still point elsewhere
+        let path = path
+            .canonicalize()
+            .map_err(|err| FileError::from_io(err, &path))?;
+        if !path.starts_with(&self.root) {
+            return Err(FileError::AccessDenied);
+        }
+        if path.is_dir() {
+            return Err(FileError::IsDirectory);
+        }
+        Ok(path)
+    }
+
+    fn read(&self, id: FileId) -> FileResult<Vec<u8>> {
+        let path = self.path(id)?;
+        fs::read(&path).map_err(|err| FileError::from_io(err, &path))
+    }
+
+    /// Resolve a Typst diagnostic to the template file and line it points at,
+    /// keeping its hints and the trace of calls that led there
+    pub fn diagnostic(&self, diag: &SourceDiagnostic) -> Diagnostic {
+        let file = self.display_path(self.main);
+        let message = diag.message.to_string();
+        let mut diagnostic = match diag.severity {
+            Severity::Error => Diagnostic::error(file, message),
+            Severity::Warning => Diagnostic::warning(file, message),
+        };
+        if let Some((file, source, line, column)) = self.locate(diag.span) {
+            diagnostic.file = file;
+            diagnostic = diagnostic.at(source.text(), line, column);
+        }
+        for hint in &diag.hints {
+            diagnostic.notes.push(format!("hint: {}", hint));
+        }
+        for point in &diag.trace {
+            let position = match self.locate(point.span) {
+                Some((file, _, line, column)) => {
+                    format!(" ({}:{}:{})", file.display(), line, column)
+                }
+                None => String::new(),
+            };
+            diagnostic
+                .notes
+                .push(format!("note: {}{}", point.v, position));
+        }
+        diagnostic
+    }
+
+    // File and 1-based line/column of a span; detached spans have none
+    fn locate(&self, span: Span) -> Option<(PathBuf, Source, usize, usize)> {
+        let id = span.id()?;
+        let source = self.source(id).ok()?;
+        let start = source.range(span)?.start;
+        let line = source.byte_to_line(start)? + 1;
+        let column = source.byte_to_column(start)? + 1;
+        Some((self.display_path(id), source, line, column))
+    }
+
+    fn display_path(&self, id: FileId) -> PathBuf {
+        self.display_root.join(id.vpath().as_rootless_path())
+    }
 }
 
 // 3. IMPLEMENT THE TRAIT
@@ -72,24 +364,27 @@ impl World for ResumeWorld {
     }
 
     fn main(&self) -> FileId {
-        self.source.id()
+        self.main
     }
 
     fn source(&self, id: FileId) -> FileResult<Source> {
-        if id == self.source.id() {
-            Ok(self.source.clone())
-        } else {
-            Err(FileError::NotFound(id.vpath().as_rooted_path().into()))
+        let mut sources = self.sources.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(source) = sources.get(&id) {
+            return Ok(source.clone());
         }
+        let text = String::from_utf8(self.read(id)?).map_err(|_| FileError::InvalidUtf8)?;
+        let source = Source::new(id, text);
+        sources.insert(id, source.clone());
+        Ok(source)
     }
 
-    // if we wanted to load custom files directly instead of embedding
+    // Images, data files (.bib, .csv, ...) and anything else `read` can load
     fn file(&self, id: FileId) -> FileResult<Bytes> {
-        Err(FileError::NotFound(id.vpath().as_rooted_path().into()))
+        self.read(id).map(Bytes::from)
     }
 
     fn font(&self, index: usize) -> Option<Font> {
-        self.fonts.get(index).cloned()
+        self.fonts.font(index)
     }
 
     // excessive but if you wanted to adapt this into a github action important to have
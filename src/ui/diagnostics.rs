@@ -0,0 +1,97 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+};
+
+use crate::app::App;
+use crate::models::diagnostics::{Diagnostic, Severity, summary};
+
+// Startup panel listing the problems found in the data files
+pub fn render_diagnostics_screen(frame: &mut Frame, app: &App) {
+    let chunks = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(3),
+    ])
+    .split(frame.area());
+
+    let header_text = format!(
+        "{} in {} | Scroll: j/k",
+        summary(&app.diagnostics),
+        app.data_dir.root().display()
+    );
+    let header = Paragraph::new(header_text).block(Block::bordered().title(" Data Check "));
+    frame.render_widget(header, chunks[0]);
+
+    let lines = diagnostic_lines(&app.diagnostics);
+
+    let body = Paragraph::new(lines)
+        .block(Block::bordered().title(" Diagnostics "))
+        .scroll((app.diagnostics_scroll, 0));
+    frame.render_widget(body, chunks[1]);
+
+    let footer = Paragraph::new(Line::from(vec![
+        Span::styled(
+            " <Enter> ",
+            Style::default().bg(Color::Green).fg(Color::Black),
+        ),
+        Span::raw(" Continue    "),
+        Span::styled(" <q> ", Style::default().bg(Color::Red).fg(Color::Black)),
+        Span::raw(" Quit "),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(footer, chunks[2]);
+}
+
+// rustc-style listing shared with the build report on the Success/Error screens
+pub fn diagnostic_lines(diagnostics: &[Diagnostic]) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for diagnostic in diagnostics {
+        let (label, color) = match diagnostic.severity {
+            Severity::Error => ("error", Color::Red),
+            Severity::Warning => ("warning", Color::Yellow),
+        };
+        let mut position = diagnostic.file.display().to_string();
+        if let Some((line, column)) = diagnostic.location {
+            position.push_str(&format!(":{}:{}", line, column));
+        }
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{}: ", label),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(diagnostic.message.clone()),
+        ]));
+        lines.push(Line::from(Span::styled(
+            format!("  --> {}", position),
+            Style::default().fg(Color::Cyan),
+        )));
+        let mut gutter = 0;
+        if let (Some((line, column)), Some(snippet)) = (diagnostic.location, &diagnostic.snippet) {
+            gutter = line.to_string().len();
+            lines.push(Line::from(Span::styled(
+                format!("  {} | {}", line, snippet),
+                Style::default().fg(Color::Gray),
+            )));
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  {} | {}^",
+                    " ".repeat(gutter),
+                    " ".repeat(column.saturating_sub(1))
+                ),
+                Style::default().fg(color),
+            )));
+        }
+        for note in &diagnostic.notes {
+            lines.push(Line::from(Span::styled(
+                format!("  {} = {}", " ".repeat(gutter), note),
+                Style::default().fg(Color::Gray),
+            )));
+        }
+        lines.push(Line::from(""));
+    }
+    lines
+}
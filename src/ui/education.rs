@@ -1,5 +1,5 @@
 use ratatui::{
-    layout::{Alignment, Constraint, Layout},
+    layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
     widgets::{Block, List, ListItem, Paragraph},
@@ -8,13 +8,13 @@ use ratatui::{
 
 use crate::app::App;
 
-pub fn render_education_screen(frame: &mut Frame, app: &mut App) {
+pub fn render_education_screen(frame: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::vertical([
         Constraint::Length(3),
         Constraint::Fill(1),
         Constraint::Length(3),
     ])
-    .split(frame.area());
+    .split(area);
 
     let header_text = "Step 2: Select Education | Navigate: j/k | Toggle: <Space>";
     let header = Paragraph::new(header_text).block(Block::bordered().title(" Education "));
@@ -26,13 +26,21 @@ pub fn render_education_screen(frame: &mut Frame, app: &mut App) {
             Style::default().fg(Color::Yellow),
         )))]
     } else {
+        let format = &app.data.config.date_format;
         app.data
             .education
             .iter()
             .map(|edu| {
                 let status = if edu.is_visible { "[x] " } else { "[ ] " };
                 let content = format!("{}{} - {}", status, edu.degree, edu.school);
-                ListItem::new(Line::from(content))
+                let when = edu
+                    .dates()
+                    .format(format)
+                    .unwrap_or_else(|| edu.date.clone());
+                ListItem::new(Line::from(vec![
+                    Span::raw(content),
+                    Span::styled(format!("  {}", when), Style::default().fg(Color::DarkGray)),
+                ]))
             })
             .collect()
     };
@@ -45,6 +53,8 @@ pub fn render_education_screen(frame: &mut Frame, app: &mut App) {
     frame.render_stateful_widget(list, chunks[1], &mut app.education_list_state);
 
     let footer = Paragraph::new(Line::from(vec![
+        Span::styled(" <o> ", Style::default().bg(Color::Blue).fg(Color::Black)),
+        Span::raw(" Sort by Date    "),
         Span::styled(
             " <Enter> ",
             Style::default().bg(Color::Green).fg(Color::Black),
@@ -1,20 +1,21 @@
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Layout},
+    layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
     widgets::{Block, List, ListItem, Paragraph},
 };
 
 use crate::app::App;
+use crate::models::date::YearMonth;
 
-pub fn render_experience_screen(frame: &mut Frame, app: &mut App) {
+pub fn render_experience_screen(frame: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::vertical([
         Constraint::Length(3),
         Constraint::Fill(1),
         Constraint::Length(3),
     ])
-    .split(frame.area());
+    .split(area);
 
     let header_text = "Step 3: Select Experience | Navigate: j/k | Toggle: <Space>";
     let header = Paragraph::new(header_text).block(Block::bordered().title(" Experience "));
@@ -26,13 +27,25 @@ pub fn render_experience_screen(frame: &mut Frame, app: &mut App) {
             Style::default().fg(Color::Yellow),
         )))]
     } else {
+        let format = &app.data.config.date_format;
+        let today = YearMonth::today();
         app.data
             .experience
             .iter()
             .map(|job| {
                 let status = if job.is_visible { "[x] " } else { "[ ] " };
                 let content = format!("{}{} at {}", status, job.role, job.company);
-                ListItem::new(Line::from(content))
+                // "  Jun 2023 – Present (2 yrs 3 mos)", falling back to the free-text date
+                let dates = job.dates();
+                let when = dates.format(format).unwrap_or_else(|| job.date.clone());
+                let detail = match dates.duration(today) {
+                    Some(duration) => format!("  {} ({})", when, duration),
+                    None => format!("  {}", when),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(content),
+                    Span::styled(detail, Style::default().fg(Color::DarkGray)),
+                ]))
             })
             .collect()
     };
@@ -52,6 +65,8 @@ pub fn render_experience_screen(frame: &mut Frame, app: &mut App) {
         Span::raw(" Back    "),
         Span::styled(" <e> ", Style::default().bg(Color::Cyan).fg(Color::Black)),
         Span::raw(" Edit Bullets    "),
+        Span::styled(" <o> ", Style::default().bg(Color::Blue).fg(Color::Black)),
+        Span::raw(" Sort by Date    "),
         Span::styled(
             " <Enter> ",
             Style::default().bg(Color::Green).fg(Color::Black),
@@ -1,6 +1,6 @@
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Layout},
+    layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
     widgets::{Block, List, ListItem, Paragraph},
@@ -8,13 +8,13 @@ use ratatui::{
 
 use crate::app::App;
 
-pub fn render_experience_bullet_screen(frame: &mut Frame, app: &mut App) {
+pub fn render_experience_bullet_screen(frame: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::vertical([
         Constraint::Length(3),
         Constraint::Fill(1),
         Constraint::Length(3),
     ])
-    .split(frame.area());
+    .split(area);
 
     let header_text = "Step 3a: Select Bullet Points | Navigate: j/k | Toggle: <Space>";
     let header = Paragraph::new(header_text).block(Block::bordered().title(" Experience Bullets "));
@@ -30,14 +30,13 @@ pub fn render_experience_bullet_screen(frame: &mut Frame, app: &mut App) {
             } else {
                 job.bullets
                     .iter()
-                    .enumerate()
-                    .map(|(i, bullet)| {
-                        let status = if job.hidden_bullets.contains(&i) {
-                            "[ ] "
-                        } else {
+                    .map(|bullet| {
+                        let status = if job.is_bullet_visible(bullet) {
                             "[x] "
+                        } else {
+                            "[ ] "
                         };
-                        let content = format!("{}{}", status, bullet);
+                        let content = format!("{}{}", status, bullet.text());
                         ListItem::new(Line::from(content))
                     })
                     .collect()
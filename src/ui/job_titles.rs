@@ -1,5 +1,5 @@
 use ratatui::{
-    layout::{Alignment, Constraint, Layout},
+    layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, List, ListItem, Paragraph},
@@ -8,13 +8,13 @@ use ratatui::{
 
 use crate::app::App;
 
-pub fn render_job_title_screen(frame: &mut Frame, app: &mut App) {
+pub fn render_job_title_screen(frame: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::vertical([
         Constraint::Length(3),
         Constraint::Fill(1),
         Constraint::Length(3),
     ])
-    .split(frame.area());
+    .split(area);
 
     // Header
     let header_text = "Step 1: Select Job Title | Navigate: j/k | Confirm: <Enter>";
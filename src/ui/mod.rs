@@ -1,31 +1,90 @@
-use ratatui::Frame;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Style},
+    text::{Line, Span},
+};
 
 use crate::app::{App, CurrentScreen};
 
+mod diagnostics;
 mod education;
 mod experience;
 mod experience_bullets;
 mod job_titles;
+mod page_budget;
+mod presets;
+mod preview;
 mod profile;
 mod projects;
+mod sections;
+mod skills;
 mod status;
+mod templates;
 mod welcome;
 
 // 5. RENDERING LOGIC
 pub fn render_ui(frame: &mut Frame, app: &mut App) {
+    // Selection screens get a status bar, and share the terminal with the
+    // preview when it is on
+    let mut area = frame.area();
+    if app.has_preview_pane() {
+        let rows = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).split(area);
+        area = rows[0];
+        preview::render_page_status(frame, app, rows[1]);
+    }
+    if app.preview_enabled && app.has_preview_pane() {
+        let columns = Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+        area = columns[0];
+        preview::render_preview_pane(frame, app, columns[1]);
+    }
+
     match &app.current_screen {
         CurrentScreen::Welcome => welcome::render_welcome_screen(frame, app),
-        CurrentScreen::ProfileView => profile::render_profile_screen(frame, app),
-        CurrentScreen::JobTitleSelection => job_titles::render_job_title_screen(frame, app),
-        CurrentScreen::EducationSelection => education::render_education_screen(frame, app),
-        CurrentScreen::ExperienceSelection => experience::render_experience_screen(frame, app),
+        CurrentScreen::Diagnostics => diagnostics::render_diagnostics_screen(frame, app),
+        CurrentScreen::PresetSelection => presets::render_preset_screen(frame, app),
+        CurrentScreen::ProfileView => profile::render_profile_screen(frame, app, area),
+        CurrentScreen::JobTitleSelection => job_titles::render_job_title_screen(frame, app, area),
+        CurrentScreen::EducationSelection => education::render_education_screen(frame, app, area),
+        CurrentScreen::ExperienceSelection => {
+            experience::render_experience_screen(frame, app, area)
+        }
         CurrentScreen::ExperienceBulletSelection => {
-            experience_bullets::render_experience_bullet_screen(frame, app)
+            experience_bullets::render_experience_bullet_screen(frame, app, area)
+        }
+        CurrentScreen::ProjectsSelection => projects::render_projects_screen(frame, app, area),
+        CurrentScreen::SkillsSelection => skills::render_skills_screen(frame, app, area),
+        CurrentScreen::SkillItemSelection => skills::render_skill_item_screen(frame, app, area),
+        CurrentScreen::SectionSelection(section) => {
+            let section = *section;
+            sections::render_section_screen(frame, app, section, area)
         }
-        CurrentScreen::ProjectsSelection => projects::render_projects_screen(frame, app),
+        CurrentScreen::SavePreset => presets::render_save_preset_screen(frame, app),
+        CurrentScreen::TemplateSelection => templates::render_template_screen(frame, app),
+        CurrentScreen::PageBudget => page_budget::render_page_budget_screen(frame, app),
         CurrentScreen::Generating => status::render_generating_screen(frame),
-        CurrentScreen::Success(path) => status::render_success_screen(frame, path),
-        CurrentScreen::Error(msg) => status::render_error_screen(frame, msg),
+        CurrentScreen::Success(path) if app.output_diagnostics.is_empty() => {
+            status::render_success_screen(frame, app, path)
+        }
+        CurrentScreen::Error(msg) if app.output_diagnostics.is_empty() => {
+            status::render_error_screen(frame, msg)
+        }
+        // Typst had something to say; show it in full
+        CurrentScreen::Success(path) => {
+            let headline = Line::from(vec![
+                Span::styled("✓ PDF generated: ", Style::default().fg(Color::Green)),
+                Span::raw(path.clone()),
+            ]);
+            status::render_build_report_screen(frame, app, "Success", headline)
+        }
+        CurrentScreen::Error(msg) => {
+            let headline = Line::from(Span::styled(
+                format!("✗ {}", msg),
+                Style::default().fg(Color::Red),
+            ));
+            status::render_build_report_screen(frame, app, "Error", headline)
+        }
         CurrentScreen::Exiting => {}
     }
 }
@@ -0,0 +1,89 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Wrap},
+};
+
+use crate::app::App;
+
+// Bullets suggested for hiding to get back under `max_pages`
+pub fn render_page_budget_screen(frame: &mut Frame, app: &App) {
+    let chunks = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(3),
+    ])
+    .split(frame.area());
+
+    let (pages, _) = app.page_status().unwrap_or_default();
+    let header_text = format!(
+        "{} pages, budget {} | Hidden from the bottom of the resume up",
+        pages,
+        app.data.config.max_pages.unwrap_or_default()
+    );
+    let header = Paragraph::new(header_text).block(Block::bordered().title(" Page Budget "));
+    frame.render_widget(header, chunks[0]);
+
+    let mut lines = Vec::new();
+    let mut can_apply = false;
+    match &app.cuts {
+        Some(Ok(cuts)) if cuts.is_empty() => {
+            lines.push(Line::from("The resume already fits; nothing to hide."));
+        }
+        Some(Ok(cuts)) => {
+            can_apply = true;
+            lines.push(Line::from(format!(
+                "Hiding these {} bullets makes it fit:",
+                cuts.len()
+            )));
+            lines.push(Line::from(""));
+            for cut in cuts {
+                lines.push(Line::from(Span::styled(
+                    cut.owner.clone(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )));
+                lines.push(Line::from(format!("  • {}", cut.text)));
+            }
+        }
+        Some(Err(error)) => {
+            lines.push(Line::from(Span::styled(
+                error.clone(),
+                Style::default().fg(Color::Red),
+            )));
+        }
+        None => {
+            lines.push(Line::from(Span::styled(
+                "Computing which bullets to hide…",
+                Style::default().fg(Color::Gray),
+            )));
+        }
+    }
+    let body = Paragraph::new(lines)
+        .block(Block::bordered().title(" Suggested Cuts "))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(body, chunks[1]);
+
+    let mut footer = vec![
+        Span::styled(
+            " <Backspace> ",
+            Style::default().bg(Color::Yellow).fg(Color::Black),
+        ),
+        Span::raw(" Back    "),
+    ];
+    if can_apply {
+        footer.push(Span::styled(
+            " <Enter> ",
+            Style::default().bg(Color::Green).fg(Color::Black),
+        ));
+        footer.push(Span::raw(" Hide These    "));
+    }
+    footer.push(Span::styled(
+        " <q> ",
+        Style::default().bg(Color::Red).fg(Color::Black),
+    ));
+    footer.push(Span::raw(" Quit "));
+    let footer = Paragraph::new(Line::from(footer)).alignment(Alignment::Center);
+    frame.render_widget(footer, chunks[2]);
+}
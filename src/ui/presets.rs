@@ -0,0 +1,104 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, Paragraph, Wrap},
+};
+
+use crate::app::App;
+
+pub fn render_preset_screen(frame: &mut Frame, app: &mut App) {
+    let chunks = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(3),
+    ])
+    .split(frame.area());
+
+    // Loading errors are reported in the header so the list stays visible
+    let header_text = match app.notice {
+        Some(ref notice) => notice.clone(),
+        None => "Load a saved selection | Navigate: j/k | Load: <Enter>".to_string(),
+    };
+    let header = Paragraph::new(header_text).block(Block::bordered().title(" Presets "));
+    frame.render_widget(header, chunks[0]);
+
+    let items: Vec<ListItem> = app
+        .presets
+        .iter()
+        .map(|name| ListItem::new(Line::from(name.as_str())))
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::bordered().title(" Saved Presets "))
+        .highlight_style(Style::default().bg(Color::Cyan).fg(Color::Black))
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(list, chunks[1], &mut app.preset_list_state);
+
+    let footer = Paragraph::new(Line::from(vec![
+        Span::styled(
+            " <Backspace> ",
+            Style::default().bg(Color::Yellow).fg(Color::Black),
+        ),
+        Span::raw(" Back    "),
+        Span::styled(
+            " <Enter> ",
+            Style::default().bg(Color::Green).fg(Color::Black),
+        ),
+        Span::raw(" Load & Continue    "),
+        Span::styled(" <q> ", Style::default().bg(Color::Red).fg(Color::Black)),
+        Span::raw(" Quit "),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(footer, chunks[2]);
+}
+
+pub fn render_save_preset_screen(frame: &mut Frame, app: &App) {
+    let vertical_layout = Layout::vertical([
+        Constraint::Fill(1),
+        Constraint::Length(9),
+        Constraint::Fill(1),
+    ])
+    .split(frame.area());
+
+    let area_centered = Layout::horizontal([
+        Constraint::Fill(1),
+        Constraint::Length(60),
+        Constraint::Fill(1),
+    ])
+    .split(vertical_layout[1])[1];
+
+    let mut text = vec![
+        Line::from(""),
+        Line::from("Name this selection (e.g. backend-fintech):"),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("> {}_", app.preset_name_input),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    if let Some(ref notice) = app.notice {
+        text.push(Line::from(Span::styled(
+            notice.as_str(),
+            Style::default().fg(Color::Red),
+        )));
+    } else {
+        text.push(Line::from(Span::styled(
+            "<Enter> Save    <Esc> Cancel",
+            Style::default().fg(Color::Gray),
+        )));
+    }
+
+    let paragraph = Paragraph::new(text)
+        .block(Block::bordered().title(" Save Preset "))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(paragraph, area_centered);
+}
@@ -0,0 +1,115 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Wrap},
+};
+
+use crate::app::App;
+
+// Text rendering of the compiled resume, beside the selection screens
+pub fn render_preview_pane(frame: &mut Frame, app: &App, area: Rect) {
+    let preview = match &app.preview {
+        Some(Ok(preview)) => preview,
+        Some(Err(error)) => {
+            let message = Paragraph::new(error.as_str())
+                .style(Style::default().fg(Color::Red))
+                .block(Block::bordered().title(" Preview "))
+                .wrap(Wrap { trim: false });
+            frame.render_widget(message, area);
+            return;
+        }
+        None => {
+            frame.render_widget(Block::bordered().title(" Preview "), area);
+            return;
+        }
+    };
+
+    // Borders take two columns
+    let width = usize::from(area.width.saturating_sub(2));
+    let mut lines = Vec::new();
+    for (i, page) in preview.pages.iter().enumerate() {
+        if i > 0 {
+            let label = format!(" page {} ", i + 1);
+            let rule = "─".repeat(width.saturating_sub(label.len()) / 2);
+            lines.push(Line::from(Span::styled(
+                format!("{}{}{}", rule, label, rule),
+                Style::default().fg(Color::Yellow),
+            )));
+        }
+        for line in &page.lines {
+            lines.push(Line::from(line.render(width)));
+        }
+    }
+
+    let pages = preview.pages.len();
+    let title = format!(
+        " Preview | {} page{} | Scroll: J/K ",
+        pages,
+        if pages == 1 { "" } else { "s" }
+    );
+    let body = Paragraph::new(lines)
+        .block(Block::bordered().title(title))
+        .scroll((app.preview_scroll, 0));
+    frame.render_widget(body, area);
+}
+
+// One-line bar under the selection screens: page count against the budget,
+// overflow onto the last page, and whether a compile is still running
+pub fn render_page_status(frame: &mut Frame, app: &App, area: Rect) {
+    let mut spans = Vec::new();
+    match (&app.preview, app.page_status()) {
+        (_, Some((pages, over))) => {
+            let budget = match app.data.config.max_pages {
+                Some(max) => format!(" Pages: {} / {} ", pages, max),
+                None => format!(" Pages: {} ", pages),
+            };
+            let style = if over {
+                Style::default().bg(Color::Red).fg(Color::Black)
+            } else {
+                Style::default().bg(Color::Green).fg(Color::Black)
+            };
+            spans.push(Span::styled(budget, style.add_modifier(Modifier::BOLD)));
+            if let Some(Some(lines)) = app
+                .preview
+                .as_ref()
+                .and_then(|p| p.as_ref().ok())
+                .map(|p| p.overflow())
+            {
+                spans.push(Span::raw(format!(
+                    "  {} line{} on page {}",
+                    lines,
+                    if lines == 1 { "" } else { "s" },
+                    pages
+                )));
+            }
+            if over {
+                spans.push(Span::raw("    "));
+                spans.push(Span::styled(
+                    " <c> ",
+                    Style::default().bg(Color::Blue).fg(Color::Black),
+                ));
+                spans.push(Span::raw(" Suggest cuts"));
+            }
+        }
+        (Some(Err(_)), _) => spans.push(Span::styled(
+            " Template failed to compile ",
+            Style::default().bg(Color::Red).fg(Color::Black),
+        )),
+        _ => {}
+    }
+    if app.pending_compile.is_some() {
+        spans.push(Span::styled(
+            "  compiling…",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    if !app.preview_enabled {
+        spans.push(Span::styled(
+            "  <v> preview",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
@@ -1,6 +1,6 @@
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Layout},
+    layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
     widgets::{Block, Paragraph},
@@ -8,13 +8,13 @@ use ratatui::{
 
 use crate::app::App;
 
-pub fn render_profile_screen(frame: &mut Frame, app: &App) {
+pub fn render_profile_screen(frame: &mut Frame, app: &App, area: Rect) {
     let chunks = Layout::vertical([
         Constraint::Length(3),
         Constraint::Fill(1),
         Constraint::Length(3),
     ])
-    .split(frame.area());
+    .split(area);
 
     let header =
         Paragraph::new("Step 1: Profile Information").block(Block::bordered().title(" Profile "));
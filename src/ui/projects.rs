@@ -1,5 +1,5 @@
 use ratatui::{
-    layout::{Alignment, Constraint, Layout},
+    layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
     widgets::{Block, List, ListItem, Paragraph},
@@ -8,15 +8,18 @@ use ratatui::{
 
 use crate::app::App;
 
-pub fn render_projects_screen(frame: &mut Frame, app: &mut App) {
+pub fn render_projects_screen(frame: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::vertical([
         Constraint::Length(3),
         Constraint::Fill(1),
         Constraint::Length(3),
     ])
-    .split(frame.area());
+    .split(area);
 
-    let header_text = "Step 4: Select Projects | Navigate: j/k | Toggle: <Space>";
+    let header_text = match app.notice {
+        Some(ref notice) => notice.clone(),
+        None => "Step 4: Select Projects | Navigate: j/k | Toggle: <Space>".to_string(),
+    };
     let header = Paragraph::new(header_text).block(Block::bordered().title(" Projects "));
     frame.render_widget(header, chunks[0]);
 
@@ -50,11 +53,18 @@ pub fn render_projects_screen(frame: &mut Frame, app: &mut App) {
             Style::default().bg(Color::Yellow).fg(Color::Black),
         ),
         Span::raw(" Back    "),
+        Span::styled(" <s> ", Style::default().bg(Color::Blue).fg(Color::Black)),
+        Span::raw(" Save Preset    "),
         Span::styled(
             " <Enter> ",
             Style::default().bg(Color::Magenta).fg(Color::White),
         ),
-        Span::raw(" Generate PDF    "),
+        // Optional sections come next when there are any
+        Span::raw(if app.is_last_step() {
+            " Generate PDF    "
+        } else {
+            " Continue    "
+        }),
         Span::styled(" <q> ", Style::default().bg(Color::Red).fg(Color::Black)),
         Span::raw(" Quit "),
     ]))
@@ -0,0 +1,68 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, Paragraph},
+};
+
+use crate::app::App;
+use crate::models::Section;
+
+// Toggle screen shared by the optional and custom sections, following the Projects screen
+pub fn render_section_screen(frame: &mut Frame, app: &mut App, section: Section, area: Rect) {
+    let chunks = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(3),
+    ])
+    .split(area);
+
+    let title = app.data.section_title(section).to_string();
+    let header_text = match app.notice {
+        Some(ref notice) => notice.clone(),
+        None => format!("Select {} | Navigate: j/k | Toggle: <Space>", title),
+    };
+    let header = Paragraph::new(header_text).block(Block::bordered().title(format!(" {} ", title)));
+    frame.render_widget(header, chunks[0]);
+
+    let items: Vec<ListItem> = app
+        .data
+        .section_entries(section)
+        .iter()
+        .map(|entry| {
+            let status = if entry.is_visible() { "[x] " } else { "[ ] " };
+            ListItem::new(Line::from(format!("{}{}", status, entry.label())))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::bordered().title(format!(" {} List ", title)))
+        .highlight_style(Style::default().bg(Color::Cyan).fg(Color::Black))
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(list, chunks[1], &mut app.section_list_state);
+
+    let footer = Paragraph::new(Line::from(vec![
+        Span::styled(
+            " <Backspace> ",
+            Style::default().bg(Color::Yellow).fg(Color::Black),
+        ),
+        Span::raw(" Back    "),
+        Span::styled(" <s> ", Style::default().bg(Color::Blue).fg(Color::Black)),
+        Span::raw(" Save Preset    "),
+        Span::styled(
+            " <Enter> ",
+            Style::default().bg(Color::Magenta).fg(Color::White),
+        ),
+        Span::raw(if app.is_last_step() {
+            " Generate PDF    "
+        } else {
+            " Continue    "
+        }),
+        Span::styled(" <q> ", Style::default().bg(Color::Red).fg(Color::Black)),
+        Span::raw(" Quit "),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(footer, chunks[2]);
+}
@@ -0,0 +1,144 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, Paragraph},
+};
+
+use crate::app::App;
+use crate::layout::skill_line;
+
+pub fn render_skills_screen(frame: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(3),
+    ])
+    .split(area);
+
+    let header_text = match app.notice {
+        Some(ref notice) => notice.clone(),
+        None => "Step 5: Select Skill Categories | Navigate: j/k | Toggle: <Space>".to_string(),
+    };
+    let header = Paragraph::new(header_text).block(Block::bordered().title(" Skills "));
+    frame.render_widget(header, chunks[0]);
+
+    let items: Vec<ListItem> = app
+        .data
+        .skills
+        .iter()
+        .map(|category| {
+            let status = if category.is_visible { "[x] " } else { "[ ] " };
+            let shown = category
+                .skills
+                .iter()
+                .filter(|s| category.is_skill_visible(s))
+                .count();
+            let content = format!(
+                "{}{} ({}/{})",
+                status,
+                category.category,
+                shown,
+                category.skills.len()
+            );
+            ListItem::new(Line::from(content))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::bordered().title(" Categories "))
+        .highlight_style(Style::default().bg(Color::Cyan).fg(Color::Black))
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(list, chunks[1], &mut app.skills_list_state);
+
+    let footer = Paragraph::new(Line::from(vec![
+        Span::styled(
+            " <Backspace> ",
+            Style::default().bg(Color::Yellow).fg(Color::Black),
+        ),
+        Span::raw(" Back    "),
+        Span::styled(" <e> ", Style::default().bg(Color::Cyan).fg(Color::Black)),
+        Span::raw(" Edit Skills    "),
+        Span::styled(" <s> ", Style::default().bg(Color::Blue).fg(Color::Black)),
+        Span::raw(" Save Preset    "),
+        Span::styled(
+            " <Enter> ",
+            Style::default().bg(Color::Magenta).fg(Color::White),
+        ),
+        Span::raw(if app.is_last_step() {
+            " Generate PDF    "
+        } else {
+            " Continue    "
+        }),
+        Span::styled(" <q> ", Style::default().bg(Color::Red).fg(Color::Black)),
+        Span::raw(" Quit "),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(footer, chunks[2]);
+}
+
+pub fn render_skill_item_screen(frame: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(3),
+    ])
+    .split(area);
+
+    let header_text = "Step 5a: Select Skills | Navigate: j/k | Toggle: <Space>";
+    let header = Paragraph::new(header_text).block(Block::bordered().title(" Skills "));
+    frame.render_widget(header, chunks[0]);
+
+    let selected = app
+        .skills_list_state
+        .selected()
+        .and_then(|i| app.data.skills.get(i));
+
+    let items: Vec<ListItem> = match selected {
+        Some(category) if category.skills.is_empty() => {
+            vec![ListItem::new(Line::from(Span::styled(
+                "No skills found in this category",
+                Style::default().fg(Color::Yellow),
+            )))]
+        }
+        Some(category) => category
+            .skills
+            .iter()
+            .map(|skill| {
+                let status = if category.is_skill_visible(skill) {
+                    "[x] "
+                } else {
+                    "[ ] "
+                };
+                ListItem::new(Line::from(format!("{}{}", status, skill_line(skill))))
+            })
+            .collect(),
+        None => vec![ListItem::new(Line::from("No category selected"))],
+    };
+
+    let title = match selected {
+        Some(category) => format!(" {} ", category.category),
+        None => " Skills ".to_string(),
+    };
+
+    let list = List::new(items)
+        .block(Block::bordered().title(title))
+        .highlight_style(Style::default().bg(Color::Cyan).fg(Color::Black))
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(list, chunks[1], &mut app.skill_item_list_state);
+
+    let footer = Paragraph::new(Line::from(vec![
+        Span::styled(
+            " <Backspace> / <Enter> ",
+            Style::default().bg(Color::Yellow).fg(Color::Black),
+        ),
+        Span::raw(" Back    "),
+        Span::styled(" <q> ", Style::default().bg(Color::Red).fg(Color::Black)),
+        Span::raw(" Quit "),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(footer, chunks[2]);
+}
@@ -1,11 +1,15 @@
 use ratatui::{
+    Frame,
     layout::{Alignment, Constraint, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Paragraph, Wrap},
-    Frame,
 };
 
+use super::diagnostics::diagnostic_lines;
+use crate::app::{App, CurrentScreen};
+use crate::models::diagnostics::summary;
+
 pub fn render_generating_screen(frame: &mut Frame) {
     let vertical_layout = Layout::vertical([
         Constraint::Fill(1),
@@ -38,22 +42,23 @@ pub fn render_generating_screen(frame: &mut Frame) {
     frame.render_widget(paragraph, area_centered);
 }
 
-pub fn render_success_screen(frame: &mut Frame, path: &str) {
+pub fn render_success_screen(frame: &mut Frame, app: &App, path: &str) {
+    let exports = export_lines(app);
     let vertical_layout = Layout::vertical([
         Constraint::Fill(1),
-        Constraint::Length(9), // Increased height for buttons
+        Constraint::Length(11 + exports.len() as u16), // Fixed lines and borders, plus one per export
         Constraint::Fill(1),
     ])
     .split(frame.area());
 
     let area_centered = Layout::horizontal([
         Constraint::Fill(1),
-        Constraint::Length(60),
+        Constraint::Length(70),
         Constraint::Fill(1),
     ])
     .split(vertical_layout[1])[1];
 
-    let text = vec![
+    let mut text = vec![
         Line::from(""),
         Line::from(Span::styled(
             "✓ PDF Generated Successfully!",
@@ -66,17 +71,27 @@ pub fn render_success_screen(frame: &mut Frame, path: &str) {
             Span::styled("Output: ", Style::default().fg(Color::Cyan)),
             Span::raw(path),
         ]),
+    ];
+    text.extend(exports);
+    text.extend([
+        Line::from(""),
+        export_hint(),
         Line::from(""),
         Line::from(vec![
             Span::styled(" <q> ", Style::default().bg(Color::Red).fg(Color::Black)),
             Span::raw(" Quit    "),
+            Span::styled(
+                " <Backspace> ",
+                Style::default().bg(Color::Yellow).fg(Color::Black),
+            ),
+            Span::raw(" Back    "),
             Span::styled(
                 " <Enter> ",
                 Style::default().bg(Color::Green).fg(Color::Black),
             ),
             Span::raw(" Done "),
         ]),
-    ];
+    ]);
 
     let paragraph = Paragraph::new(text)
         .block(Block::bordered().title(" Success "))
@@ -85,6 +100,30 @@ pub fn render_success_screen(frame: &mut Frame, path: &str) {
     frame.render_widget(paragraph, area_centered);
 }
 
+// Files written from the Success screen, in the order they were asked for
+fn export_lines(app: &App) -> Vec<Line<'static>> {
+    app.exports
+        .iter()
+        .map(|export| match export {
+            Ok(path) => Line::from(vec![
+                Span::styled("Output: ", Style::default().fg(Color::Cyan)),
+                Span::raw(path.clone()),
+            ]),
+            Err(e) => Line::from(Span::styled(
+                format!("✗ {}", e),
+                Style::default().fg(Color::Red),
+            )),
+        })
+        .collect()
+}
+
+fn export_hint() -> Line<'static> {
+    Line::from(Span::styled(
+        "Export: <w> DOCX <p> PNG <s> SVG <t> TXT <m> MD <h> HTML <r> JSON",
+        Style::default().fg(Color::Gray),
+    ))
+}
+
 pub fn render_error_screen(frame: &mut Frame, error: &str) {
     let vertical_layout = Layout::vertical([
         Constraint::Fill(1),
@@ -110,7 +149,7 @@ pub fn render_error_screen(frame: &mut Frame, error: &str) {
         Line::from(error),
         Line::from(""),
         Line::from(Span::styled(
-            "Press <Enter> or <q> to exit",
+            "Press <Enter> or <q> to exit, <Backspace> to go back",
             Style::default().fg(Color::Gray),
         )),
     ];
@@ -122,3 +161,47 @@ pub fn render_error_screen(frame: &mut Frame, error: &str) {
 
     frame.render_widget(paragraph, area_centered);
 }
+
+// Full-screen variant of Success/Error used when Typst reported diagnostics
+pub fn render_build_report_screen(frame: &mut Frame, app: &App, title: &str, headline: Line) {
+    let mut header_lines = vec![headline];
+    if matches!(app.current_screen, CurrentScreen::Success(_)) {
+        header_lines.extend(export_lines(app));
+        header_lines.push(export_hint());
+    }
+    let chunks = Layout::vertical([
+        Constraint::Length(header_lines.len() as u16 + 2),
+        Constraint::Fill(1),
+        Constraint::Length(3),
+    ])
+    .split(frame.area());
+
+    let header =
+        Paragraph::new(header_lines).block(Block::bordered().title(format!(" {} ", title)));
+    frame.render_widget(header, chunks[0]);
+
+    let body = Paragraph::new(diagnostic_lines(&app.output_diagnostics))
+        .block(Block::bordered().title(format!(
+            " {} | Scroll: j/k ",
+            summary(&app.output_diagnostics)
+        )))
+        .scroll((app.output_scroll, 0));
+    frame.render_widget(body, chunks[1]);
+
+    let footer = Paragraph::new(Line::from(vec![
+        Span::styled(
+            " <Backspace> ",
+            Style::default().bg(Color::Yellow).fg(Color::Black),
+        ),
+        Span::raw(" Back    "),
+        Span::styled(
+            " <Enter> ",
+            Style::default().bg(Color::Green).fg(Color::Black),
+        ),
+        Span::raw(" Done    "),
+        Span::styled(" <q> ", Style::default().bg(Color::Red).fg(Color::Black)),
+        Span::raw(" Quit "),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(footer, chunks[2]);
+}
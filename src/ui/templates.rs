@@ -0,0 +1,67 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, Paragraph},
+};
+
+use crate::app::App;
+
+pub fn render_template_screen(frame: &mut Frame, app: &mut App) {
+    let chunks = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(3),
+    ])
+    .split(frame.area());
+
+    let header = Paragraph::new("Choose a layout | Navigate: j/k | Generate: <Enter>")
+        .block(Block::bordered().title(" Template "));
+    frame.render_widget(header, chunks[0]);
+
+    let items: Vec<ListItem> = app
+        .templates
+        .iter()
+        .map(|template| {
+            let mut lines = vec![Line::from(vec![
+                Span::styled(
+                    template.name.clone(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("  {}", template.file),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])];
+            if !template.description.is_empty() {
+                lines.push(Line::from(format!("   {}", template.description)));
+            }
+            ListItem::new(lines)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::bordered().title(" Templates "))
+        .highlight_style(Style::default().bg(Color::Cyan).fg(Color::Black))
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(list, chunks[1], &mut app.template_list_state);
+
+    let footer = Paragraph::new(Line::from(vec![
+        Span::styled(
+            " <Backspace> ",
+            Style::default().bg(Color::Yellow).fg(Color::Black),
+        ),
+        Span::raw(" Back    "),
+        Span::styled(
+            " <Enter> ",
+            Style::default().bg(Color::Green).fg(Color::Black),
+        ),
+        Span::raw(" Generate PDF    "),
+        Span::styled(" <q> ", Style::default().bg(Color::Red).fg(Color::Black)),
+        Span::raw(" Quit "),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(footer, chunks[2]);
+}
@@ -50,6 +50,19 @@ pub fn render_welcome_screen(frame: &mut Frame, app: &App) {
         Line::from(""),
         Line::from("This tool will help you generate targeted resumes"),
         Line::from("based on your YAML data source."),
+        Line::from(Span::styled(
+            "Press <v> on any selection screen for a live preview.",
+            Style::default().fg(Color::Gray),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            if app.data_dir.root().is_dir() {
+                format!("Data: {}", app.data_dir.root().display())
+            } else {
+                format!("Data: {} (not found)", app.data_dir.root().display())
+            },
+            Style::default().fg(Color::DarkGray),
+        )),
     ];
 
     let paragraph = Paragraph::new(welcome_text)
@@ -58,15 +71,39 @@ pub fn render_welcome_screen(frame: &mut Frame, app: &App) {
 
     frame.render_widget(paragraph, area_centered);
 
-    let footer_text = Line::from(vec![
+    let mut footer_spans = vec![
         Span::styled(
             " <Enter> ",
             Style::default().bg(Color::Cyan).fg(Color::Black),
         ),
         Span::raw(" Start Builder    "),
-        Span::styled(" <q> ", Style::default().bg(Color::Red).fg(Color::Black)),
-        Span::raw(" Quit "),
-    ]);
+    ];
+    if !app.presets.is_empty() {
+        footer_spans.push(Span::styled(
+            " <l> ",
+            Style::default().bg(Color::Blue).fg(Color::Black),
+        ));
+        footer_spans.push(Span::raw(format!(
+            " Load Preset ({})    ",
+            app.presets.len()
+        )));
+    }
+    if !app.diagnostics.is_empty() {
+        footer_spans.push(Span::styled(
+            " <d> ",
+            Style::default().bg(Color::Yellow).fg(Color::Black),
+        ));
+        footer_spans.push(Span::raw(format!(
+            " Diagnostics ({})    ",
+            app.diagnostics.len()
+        )));
+    }
+    footer_spans.push(Span::styled(
+        " <q> ",
+        Style::default().bg(Color::Red).fg(Color::Black),
+    ));
+    footer_spans.push(Span::raw(" Quit "));
+    let footer_text = Line::from(footer_spans);
 
     let footer = Paragraph::new(footer_text)
         .alignment(Alignment::Center)